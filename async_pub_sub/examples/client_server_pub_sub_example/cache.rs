@@ -1,10 +1,11 @@
 use async_pub_sub::{
-    DebuggingPublisherLayer, Publisher, PublisherBuilder, PublisherImpl, SubscriberImpl,
+    DebuggingPublisherLayer, Publisher, PublisherBuilder, PublisherImpl, ShutdownSignal,
+    SubscriberImpl,
 };
 use async_pub_sub_macros::{rpc_interface, DerivePublisher, DeriveSubscriber};
 
 use crate::{
-    persistency::{PersistencyClient, PersistencyInterface, PersistencyInterfaceMessage},
+    persistency::{PersistencyClient, PersistencyInterfaceClient, PersistencyInterfaceMessage},
     timer::CacheTimerNotification,
 };
 
@@ -13,6 +14,7 @@ const NAME: &str = "Cache";
 #[rpc_interface]
 pub trait CacheInterface {
     async fn get_data(&self) -> String;
+    #[notify]
     async fn set_data(&mut self, data: String);
 }
 
@@ -37,19 +39,27 @@ impl CacheService {
         }
     }
 
-    pub async fn run(mut self) {
+    pub async fn run(mut self, mut shutdown: ShutdownSignal) {
         log::info!("Starting {}", NAME);
         loop {
             tokio::select! {
-                request = self.rpc_subscriber.receive() => {
+                biased;
+                Some((source, request)) = self.rpc_subscriber.recv_with_source() => {
+                    log::debug!("[{}] handling request from {}", NAME, source);
                     CacheInterfaceServer::handle_request(&mut self, request).await
                 }
-                _ = self.timer_notification_subscriber.receive() => {
+                Some(_) = self.timer_notification_subscriber.recv() => {
                     log::info!("[{}] store data in persistency", NAME);
-                    self.persistency_rpc_client.store_data(self.data.clone().unwrap_or_default().into_bytes()).await
+                    let data = self.data.clone().unwrap_or_default().into_bytes();
+                    if let Err(error) = self.persistency_rpc_client.try_store_data(data).await {
+                        log::warn!("[{}] data not persisted: {}", NAME, error);
+                    }
                 }
+                _ = &mut shutdown => break,
+                else => break,
             }
         }
+        log::info!("Stopping {}", NAME);
     }
 }
 
@@ -1,4 +1,4 @@
-use async_pub_sub::SubscriberImpl;
+use async_pub_sub::{ShutdownSignal, SubscriberImpl};
 use async_pub_sub_macros::{DerivePublisher, DeriveSubscriber};
 
 use crate::{
@@ -24,13 +24,16 @@ impl DataConsumerService {
         }
     }
 
-    pub async fn run(mut self) {
+    pub async fn run(mut self, shutdown: ShutdownSignal) {
         log::info!("Starting {}", NAME);
-        loop {
-            let _ = self.timer_notification_subscriber.receive().await;
+        while let Some(Some(_)) = shutdown
+            .until_shutdown(self.timer_notification_subscriber.recv())
+            .await
+        {
             let data = self.cache_rpc_client.get_data().await;
 
             log::info!("[{}] data: {:?}", NAME, data)
         }
+        log::info!("Stopping {}", NAME);
     }
 }
@@ -1,4 +1,4 @@
-use async_pub_sub::SubscriberImpl;
+use async_pub_sub::{ShutdownSignal, SubscriberImpl};
 use async_pub_sub_macros::{DerivePublisher, DeriveSubscriber};
 
 use crate::{
@@ -26,15 +26,18 @@ impl DataProducerService {
         }
     }
 
-    pub async fn run(mut self) {
+    pub async fn run(mut self, shutdown: ShutdownSignal) {
         log::info!("Starting {}", NAME);
-        loop {
-            let _ = self.timer_notification_subscriber.receive().await;
+        while let Some(Some(_)) = shutdown
+            .until_shutdown(self.timer_notification_subscriber.recv())
+            .await
+        {
             self.counter += 1;
 
             self.cache_rpc_client
                 .set_data(format!("{}: counter = {}", NAME, self.counter))
                 .await;
         }
+        log::info!("Stopping {}", NAME);
     }
 }
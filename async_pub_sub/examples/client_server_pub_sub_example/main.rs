@@ -9,7 +9,7 @@ use data_consumer::DataConsumerService;
 use data_producer::DataProducerService;
 use persistency::PersistencyService;
 use timer::{CacheTimerNotification, TimerService};
-use async_pub_sub::Result;
+use async_pub_sub::{Result, ShutdownCoordinator};
 use async_pub_sub_macros::routes;
 
 #[tokio::main]
@@ -32,12 +32,28 @@ async fn main() -> Result<()> {
         timer_service -> data_producer_service,
     )?;
 
+    // Services are stopped from the producers to the last consumer, so that every
+    // queued message is handled before its consumer stops.
+    let mut coordinator = ShutdownCoordinator::new();
+    let timer_shutdown = coordinator.add_stage("timer");
+    let data_services_shutdown = coordinator.add_stage("data services");
+    let cache_shutdown = coordinator.add_stage("cache");
+    let persistency_shutdown = coordinator.add_stage("persistency");
+
+    let shutdown = async move {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to listen for the shutdown signal");
+        coordinator.shutdown().await;
+    };
+
     tokio::join!(
-        cache_service.run(),
-        data_consumer_service.run(),
-        data_producer_service.run(),
-        persistency_service.run(),
-        timer_service.run(),
+        cache_service.run(cache_shutdown),
+        data_consumer_service.run(data_services_shutdown.clone()),
+        data_producer_service.run(data_services_shutdown),
+        persistency_service.run(persistency_shutdown),
+        timer_service.run(timer_shutdown),
+        shutdown,
     );
 
     Ok(())
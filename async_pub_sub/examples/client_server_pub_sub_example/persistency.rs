@@ -1,4 +1,6 @@
-use async_pub_sub::{PublisherImpl, SubscriberImpl};
+use std::time::Duration;
+
+use async_pub_sub::{PublisherImpl, ShutdownSignal, SubscriberImpl};
 use async_pub_sub_macros::{rpc_interface, DerivePublisher, DeriveSubscriber};
 
 const NAME: &str = "Persistency";
@@ -7,6 +9,7 @@ const NAME: &str = "Persistency";
 #[rpc_interface]
 pub trait PersistencyInterface {
     async fn get_data(&self) -> Vec<u8>;
+    #[notify]
     async fn store_data(&mut self, data: Vec<u8>);
 }
 
@@ -25,9 +28,10 @@ impl PersistencyService {
         }
     }
 
-    pub async fn run(mut self) {
+    pub async fn run(mut self, shutdown: ShutdownSignal) {
         log::info!("Starting {}", NAME);
-        PersistencyInterfaceServer::run(&mut self).await
+        PersistencyInterfaceServer::run_until_shutdown(&mut self, shutdown).await;
+        log::info!("Stopping {}", NAME);
     }
 }
 
@@ -57,4 +61,8 @@ impl PersistencyClient {
     }
 }
 
-impl PersistencyInterfaceClient for PersistencyClient {}
+impl PersistencyInterfaceClient for PersistencyClient {
+    fn rpc_timeout(&self) -> Option<Duration> {
+        Some(Duration::from_secs(1))
+    }
+}
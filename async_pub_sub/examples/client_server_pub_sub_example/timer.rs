@@ -1,6 +1,8 @@
 use std::time::Duration;
 
-use async_pub_sub::{DebuggingPublisherLayer, Publisher, PublisherBuilder, PublisherImpl};
+use async_pub_sub::{
+    DebuggingPublisherLayer, LayerStack, Publisher, PublisherImpl, ShutdownSignal,
+};
 use async_pub_sub_macros::DerivePublisher;
 use rand::Rng;
 
@@ -27,65 +29,93 @@ pub struct TimerService {
 
 impl TimerService {
     pub fn new() -> Self {
+        let layers = LayerStack::new().with_layer(DebuggingPublisherLayer);
+
         Self {
-            data_consumer_publisher: Box::new(
-                PublisherBuilder::new(PublisherImpl::new(NAME, 10))
-                    .with_layer(DebuggingPublisherLayer)
-                    .build(),
-            ),
-            data_producer_publisher: Box::new(
-                PublisherBuilder::new(PublisherImpl::new(NAME, 10))
-                    .with_layer(DebuggingPublisherLayer)
-                    .build(),
-            ),
-            cache_publisher: Box::new(
-                PublisherBuilder::new(PublisherImpl::new(NAME, 10))
-                    .with_layer(DebuggingPublisherLayer)
-                    .build(),
-            ),
+            data_consumer_publisher: Box::new(layers.build_publisher(PublisherImpl::new(NAME, 10))),
+            data_producer_publisher: Box::new(layers.build_publisher(PublisherImpl::new(NAME, 10))),
+            cache_publisher: Box::new(layers.build_publisher(PublisherImpl::new(NAME, 10))),
         }
     }
 
-    pub async fn run(self) {
+    pub async fn run(self, shutdown: ShutdownSignal) {
+        let shutdown_signal = shutdown.clone();
         let data_consumer_task = async move {
             let mut rng = rand::rng();
             loop {
                 let second_count = rng.random_range(3..6);
                 log::info!("[{}] notifying data customer in {}s", NAME, second_count);
-                tokio::time::sleep(Duration::from_secs(second_count)).await;
-                self.data_consumer_publisher
+                let delay = Duration::from_secs(second_count);
+                if !wait(&self.data_consumer_publisher, &shutdown_signal, delay).await {
+                    break;
+                }
+                if let Err(error) = self
+                    .data_consumer_publisher
                     .publish(DataConsumerTimerNotification)
                     .await
-                    .unwrap();
+                {
+                    log::warn!("[{}] stopping data consumer notifications: {}", NAME, error);
+                    break;
+                }
             }
+            self.data_consumer_publisher.close();
         };
 
+        let shutdown_signal = shutdown.clone();
         let data_producer_task = async move {
             let mut rng = rand::rng();
             loop {
                 let second_count = rng.random_range(1..4);
                 log::info!("[{}] notifying data producer in {}s", NAME, second_count);
-                tokio::time::sleep(Duration::from_secs(second_count)).await;
-                self.data_producer_publisher
+                let delay = Duration::from_secs(second_count);
+                if !wait(&self.data_producer_publisher, &shutdown_signal, delay).await {
+                    break;
+                }
+                if let Err(error) = self
+                    .data_producer_publisher
                     .publish(DataProducerTimerNotification)
                     .await
-                    .unwrap();
+                {
+                    log::warn!("[{}] stopping data producer notifications: {}", NAME, error);
+                    break;
+                }
             }
+            self.data_producer_publisher.close();
         };
 
+        let shutdown_signal = shutdown.clone();
         let cache_publisher = async move {
             let mut rng = rand::rng();
             loop {
                 let second_count = rng.random_range(5..8);
                 log::info!("[{}] notifying data cache in {}s", NAME, second_count);
-                tokio::time::sleep(Duration::from_secs(second_count)).await;
-                self.cache_publisher
-                    .publish(CacheTimerNotification)
-                    .await
-                    .unwrap();
+                let delay = Duration::from_secs(second_count);
+                if !wait(&self.cache_publisher, &shutdown_signal, delay).await {
+                    break;
+                }
+                if let Err(error) = self.cache_publisher.publish(CacheTimerNotification).await {
+                    log::warn!("[{}] stopping cache notifications: {}", NAME, error);
+                    break;
+                }
             }
+            self.cache_publisher.close();
         };
 
+        drop(shutdown);
         tokio::join!(data_consumer_task, data_producer_task, cache_publisher);
+        log::info!("Stopping {}", NAME);
+    }
+}
+
+/// Waits for the given delay, returns false if the timer must stop before: on shutdown,
+/// or once nobody listens to the publisher anymore.
+async fn wait(publisher: &impl Publisher, shutdown: &ShutdownSignal, delay: Duration) -> bool {
+    let sleep = tokio::time::sleep(delay);
+    tokio::select! {
+        slept = shutdown.until_shutdown(sleep) => slept.is_some(),
+        _ = publisher.closed() => {
+            log::info!("[{}] {} is not listened to anymore", NAME, publisher.get_name());
+            false
+        }
     }
 }
@@ -1,2 +1,339 @@
+use std::fmt::Display;
+
 pub type Result<T> = core::result::Result<T, Error>;
-pub type Error = Box<dyn std::error::Error>; // For early dev.
+
+/// Errors reported by publishers, subscribers and RPC helpers.
+///
+/// Every variant carries the names of the objects involved and the message type,
+/// so callers can decide how to react (retry, rebind, give up) without parsing
+/// error strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The publisher is already bound to a subscriber and cannot accept another one.
+    AlreadyBound {
+        /// The name of the publisher that was asked to bind.
+        publisher: &'static str,
+        /// The name of the subscriber the publisher is already bound to.
+        subscriber: &'static str,
+        /// The type of the messages carried by the publisher.
+        message_type: &'static str,
+    },
+    /// The subscriber side of the channel is gone, the message could not be delivered.
+    Disconnected {
+        /// The name of the publisher that tried to send the message.
+        publisher: &'static str,
+        /// The name of the subscriber that was bound, if any.
+        subscriber: Option<&'static str>,
+        /// The type of the message that could not be delivered.
+        message_type: &'static str,
+    },
+    /// The channel has no room left for the message.
+    ChannelFull {
+        /// The name of the publisher that tried to send the message.
+        publisher: &'static str,
+        /// The name of the subscriber that was bound, if any.
+        subscriber: Option<&'static str>,
+        /// The type of the message that could not be delivered.
+        message_type: &'static str,
+    },
+    /// The publisher has been closed and does not accept messages anymore.
+    Closed {
+        /// The name of the closed publisher.
+        publisher: &'static str,
+        /// The type of the message that could not be delivered.
+        message_type: &'static str,
+    },
+    /// The publication did not complete before its deadline.
+    Timeout {
+        /// The name of the publisher that tried to send the message.
+        publisher: &'static str,
+        /// The type of the message that could not be delivered in time.
+        message_type: &'static str,
+    },
+    /// The forwarder already forwards its messages to a subscriber, so it cannot subscribe
+    /// to more publishers.
+    AlreadyForwarding {
+        /// The name of the forwarder that was asked to subscribe.
+        forwarder: &'static str,
+        /// The name of the subscriber the forwarder forwards to.
+        subscriber: &'static str,
+        /// The type of the messages carried by the forwarder.
+        message_type: &'static str,
+    },
+    /// The publisher cannot hand out a message stream, subscribers must subscribe to the
+    /// publisher it wraps instead.
+    NotSubscribable {
+        /// The name of the publisher that was asked to bind.
+        publisher: &'static str,
+        /// The type of the messages accepted by the publisher.
+        message_type: &'static str,
+    },
+    /// The publisher is in use elsewhere, so it cannot be bound to a subscriber right now.
+    Shared {
+        /// The name of the publisher that was asked to bind.
+        publisher: &'static str,
+        /// The type of the messages accepted by the publisher.
+        message_type: &'static str,
+    },
+    /// The responder of an RPC request was dropped without sending a response.
+    ResponseDropped {
+        /// The type of the request content.
+        request_type: &'static str,
+        /// The type of the expected response.
+        response_type: &'static str,
+    },
+}
+
+impl Error {
+    /// Creates an [`Error::AlreadyBound`] error for the given message type.
+    pub fn already_bound<Message>(publisher: &'static str, subscriber: &'static str) -> Self {
+        Self::AlreadyBound {
+            publisher,
+            subscriber,
+            message_type: std::any::type_name::<Message>(),
+        }
+    }
+
+    /// Creates an [`Error::AlreadyForwarding`] error for the given message type.
+    pub fn already_forwarding<Message>(forwarder: &'static str, subscriber: &'static str) -> Self {
+        Self::AlreadyForwarding {
+            forwarder,
+            subscriber,
+            message_type: std::any::type_name::<Message>(),
+        }
+    }
+
+    /// Creates an [`Error::NotSubscribable`] error for the given message type.
+    pub fn not_subscribable<Message>(publisher: &'static str) -> Self {
+        Self::NotSubscribable {
+            publisher,
+            message_type: std::any::type_name::<Message>(),
+        }
+    }
+
+    /// Creates an [`Error::Shared`] error for the given message type.
+    pub fn shared<Message>(publisher: &'static str) -> Self {
+        Self::Shared {
+            publisher,
+            message_type: std::any::type_name::<Message>(),
+        }
+    }
+
+    /// Creates an [`Error::Disconnected`] error for the given message type.
+    pub fn disconnected<Message>(
+        publisher: &'static str,
+        subscriber: Option<&'static str>,
+    ) -> Self {
+        Self::Disconnected {
+            publisher,
+            subscriber,
+            message_type: std::any::type_name::<Message>(),
+        }
+    }
+
+    /// Creates an [`Error::ChannelFull`] error for the given message type.
+    pub fn channel_full<Message>(
+        publisher: &'static str,
+        subscriber: Option<&'static str>,
+    ) -> Self {
+        Self::ChannelFull {
+            publisher,
+            subscriber,
+            message_type: std::any::type_name::<Message>(),
+        }
+    }
+
+    /// Creates an [`Error::Closed`] error for the given message type.
+    pub fn closed<Message>(publisher: &'static str) -> Self {
+        Self::Closed {
+            publisher,
+            message_type: std::any::type_name::<Message>(),
+        }
+    }
+
+    /// Creates an [`Error::Timeout`] error for the given message type.
+    pub fn timeout<Message>(publisher: &'static str) -> Self {
+        Self::Timeout {
+            publisher,
+            message_type: std::any::type_name::<Message>(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::AlreadyBound {
+                publisher,
+                subscriber,
+                message_type,
+            } => write!(
+                f,
+                "{} publisher ({}) can only be bound to one subscriber (already bound to {})",
+                publisher, message_type, subscriber
+            ),
+            Error::AlreadyForwarding {
+                forwarder,
+                subscriber,
+                message_type,
+            } => write!(
+                f,
+                "{} forwarder ({}) is already forwarding to {}",
+                forwarder, message_type, subscriber
+            ),
+            Error::Disconnected {
+                publisher,
+                subscriber: Some(subscriber),
+                message_type,
+            } => write!(
+                f,
+                "{} publisher ({}) is disconnected from {}",
+                publisher, message_type, subscriber
+            ),
+            Error::Disconnected {
+                publisher,
+                subscriber: None,
+                message_type,
+            } => write!(
+                f,
+                "{} publisher ({}) is disconnected",
+                publisher, message_type
+            ),
+            Error::ChannelFull {
+                publisher,
+                subscriber: Some(subscriber),
+                message_type,
+            } => write!(
+                f,
+                "{} publisher ({}) channel to {} is full",
+                publisher, message_type, subscriber
+            ),
+            Error::ChannelFull {
+                publisher,
+                subscriber: None,
+                message_type,
+            } => write!(
+                f,
+                "{} publisher ({}) channel is full",
+                publisher, message_type
+            ),
+            Error::Closed {
+                publisher,
+                message_type,
+            } => write!(f, "{} publisher ({}) is closed", publisher, message_type),
+            Error::Timeout {
+                publisher,
+                message_type,
+            } => write!(f, "{} publisher ({}) timed out", publisher, message_type),
+            Error::NotSubscribable {
+                publisher,
+                message_type,
+            } => write!(
+                f,
+                "{} publisher ({}) cannot be subscribed to",
+                publisher, message_type
+            ),
+            Error::Shared {
+                publisher,
+                message_type,
+            } => write!(
+                f,
+                "{} publisher ({}) is in use elsewhere and cannot be bound",
+                publisher, message_type
+            ),
+            Error::ResponseDropped {
+                request_type,
+                response_type,
+            } => write!(
+                f,
+                "request ({}) was dropped before a response ({}) was sent",
+                request_type, response_type
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Errors reported by the `try_*` methods of the RPC clients generated by `rpc_interface`.
+///
+/// Unlike the plain client methods, which panic, the `try_*` methods report why a call
+/// failed, so that callers can degrade gracefully when a server is gone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RpcError {
+    /// The request could not be published.
+    Publish {
+        /// The name of the called method.
+        method: &'static str,
+        /// The publication error.
+        error: Error,
+    },
+    /// The server dropped the request without responding, e.g. because it stopped.
+    ServerDropped {
+        /// The name of the called method.
+        method: &'static str,
+    },
+    /// The call did not complete before its deadline.
+    Timeout {
+        /// The name of the called method.
+        method: &'static str,
+    },
+}
+
+impl RpcError {
+    /// Creates the error of a call whose request could not be published.
+    ///
+    /// An [`Error::Timeout`] is reported as [`RpcError::Timeout`], other errors as
+    /// [`RpcError::Publish`].
+    pub fn publish_failed(method: &'static str, error: Error) -> Self {
+        match error {
+            Error::Timeout { .. } => Self::Timeout { method },
+            error => Self::Publish { method, error },
+        }
+    }
+
+    /// Creates the error of a call whose response could not be received.
+    ///
+    /// An [`Error::Timeout`] is reported as [`RpcError::Timeout`], other errors as
+    /// [`RpcError::ServerDropped`].
+    pub fn response_failed(method: &'static str, error: Error) -> Self {
+        match error {
+            Error::Timeout { .. } => Self::Timeout { method },
+            _ => Self::ServerDropped { method },
+        }
+    }
+
+    /// Returns the name of the called method.
+    pub fn method(&self) -> &'static str {
+        match self {
+            RpcError::Publish { method, .. }
+            | RpcError::ServerDropped { method }
+            | RpcError::Timeout { method } => method,
+        }
+    }
+}
+
+impl Display for RpcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RpcError::Publish { method, error } => {
+                write!(f, "failed to publish {} request: {}", method, error)
+            }
+            RpcError::ServerDropped { method } => {
+                write!(f, "server dropped {} request without responding", method)
+            }
+            RpcError::Timeout { method } => write!(f, "{} request timed out", method),
+        }
+    }
+}
+
+impl std::error::Error for RpcError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RpcError::Publish { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
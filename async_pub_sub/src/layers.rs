@@ -0,0 +1,365 @@
+use std::pin::Pin;
+
+use futures::{future::BoxFuture, Stream};
+
+use crate::{
+    PublishOutcome, Publisher, PublisherLayer, PublisherWrapper, Result, Subscriber,
+    SubscriberLayer,
+};
+
+/// A layer that returns the publisher or subscriber it is given, untouched.
+///
+/// This is the starting point of a [`LayerStack`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Identity;
+
+impl<P> PublisherLayer<P> for Identity
+where
+    P: Publisher,
+{
+    type PublisherType = P;
+
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        publisher
+    }
+}
+
+impl<S> SubscriberLayer<S> for Identity
+where
+    S: Subscriber,
+{
+    type SubscriberType = S;
+
+    fn layer(&self, subscriber: S) -> Self::SubscriberType {
+        subscriber
+    }
+}
+
+/// Two layers applied one after the other: `inner` first, then `outer`.
+#[derive(Debug, Clone, Copy)]
+pub struct Stack<Inner, Outer> {
+    inner: Inner,
+    outer: Outer,
+}
+
+impl<Inner, Outer> Stack<Inner, Outer> {
+    /// Creates a new stack of two layers.
+    ///
+    /// # Arguments
+    /// * `inner` - The layer applied first, closest to the wrapped publisher or subscriber
+    /// * `outer` - The layer applied on top of the inner one
+    pub fn new(inner: Inner, outer: Outer) -> Self {
+        Self { inner, outer }
+    }
+}
+
+impl<P, Inner, Outer> PublisherLayer<P> for Stack<Inner, Outer>
+where
+    P: Publisher,
+    Inner: PublisherLayer<P>,
+    Outer: PublisherLayer<Inner::PublisherType>,
+{
+    type PublisherType = Outer::PublisherType;
+
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        self.outer.layer(self.inner.layer(publisher))
+    }
+}
+
+impl<S, Inner, Outer> SubscriberLayer<S> for Stack<Inner, Outer>
+where
+    S: Subscriber,
+    Inner: SubscriberLayer<S>,
+    Outer: SubscriberLayer<Inner::SubscriberType>,
+{
+    type SubscriberType = Outer::SubscriberType;
+
+    fn layer(&self, subscriber: S) -> Self::SubscriberType {
+        self.outer.layer(self.inner.layer(subscriber))
+    }
+}
+
+/// An optional layer: `Some(layer)` applies the layer, `None` leaves the publisher
+/// or subscriber untouched.
+impl<P, L> PublisherLayer<P> for Option<L>
+where
+    P: Publisher,
+    L: PublisherLayer<P>,
+    L::PublisherType: Publisher<Message = P::Message>,
+{
+    type PublisherType = Either<L::PublisherType, P>;
+
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        match self {
+            Some(layer) => Either::Left(layer.layer(publisher)),
+            None => Either::Right(publisher),
+        }
+    }
+}
+
+impl<S, L> SubscriberLayer<S> for Option<L>
+where
+    S: Subscriber + Send,
+    L: SubscriberLayer<S>,
+    L::SubscriberType: Subscriber<Message = S::Message> + Send,
+{
+    type SubscriberType = Either<L::SubscriberType, S>;
+
+    fn layer(&self, subscriber: S) -> Self::SubscriberType {
+        match self {
+            Some(layer) => Either::Left(layer.layer(subscriber)),
+            None => Either::Right(subscriber),
+        }
+    }
+}
+
+/// One of two publishers or subscribers handling the same message type.
+///
+/// This is the type produced by optional layers, see [`LayerStack::with_option_layer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<A, B> {
+    /// The first alternative.
+    Left(A),
+    /// The second alternative.
+    Right(B),
+}
+
+impl<A, B> Publisher for Either<A, B>
+where
+    A: Publisher,
+    B: Publisher<Message = A::Message>,
+{
+    type Message = A::Message;
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            Either::Left(publisher) => publisher.get_name(),
+            Either::Right(publisher) => publisher.get_name(),
+        }
+    }
+
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        match self {
+            Either::Left(publisher) => publisher.publish(message),
+            Either::Right(publisher) => publisher.publish(message),
+        }
+    }
+
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        match self {
+            Either::Left(publisher) => publisher.try_publish(message),
+            Either::Right(publisher) => publisher.try_publish(message),
+        }
+    }
+
+    fn publish_with_outcome(
+        &self,
+        message: Self::Message,
+    ) -> BoxFuture<'_, Result<PublishOutcome>> {
+        match self {
+            Either::Left(publisher) => publisher.publish_with_outcome(message),
+            Either::Right(publisher) => publisher.publish_with_outcome(message),
+        }
+    }
+
+    fn close(&self) {
+        match self {
+            Either::Left(publisher) => publisher.close(),
+            Either::Right(publisher) => publisher.close(),
+        }
+    }
+
+    fn is_bound(&self) -> bool {
+        match self {
+            Either::Left(publisher) => publisher.is_bound(),
+            Either::Right(publisher) => publisher.is_bound(),
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        match self {
+            Either::Left(publisher) => publisher.is_closed(),
+            Either::Right(publisher) => publisher.is_closed(),
+        }
+    }
+
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        match self {
+            Either::Left(publisher) => publisher.closed(),
+            Either::Right(publisher) => publisher.closed(),
+        }
+    }
+
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        match self {
+            Either::Left(publisher) => publisher.get_message_stream(subscriber_name),
+            Either::Right(publisher) => publisher.get_message_stream(subscriber_name),
+        }
+    }
+}
+
+impl<A, B> Subscriber for Either<A, B>
+where
+    A: Subscriber + Send,
+    B: Subscriber<Message = A::Message> + Send,
+{
+    type Message = A::Message;
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            Either::Left(subscriber) => subscriber.get_name(),
+            Either::Right(subscriber) => subscriber.get_name(),
+        }
+    }
+
+    fn subscribe_to(&mut self, publisher: &mut impl PublisherWrapper<Self::Message>) -> Result<()> {
+        match self {
+            Either::Left(subscriber) => subscriber.subscribe_to(publisher),
+            Either::Right(subscriber) => subscriber.subscribe_to(publisher),
+        }
+    }
+
+    async fn receive(&mut self) -> Self::Message {
+        match self {
+            Either::Left(subscriber) => subscriber.receive().await,
+            Either::Right(subscriber) => subscriber.receive().await,
+        }
+    }
+
+    async fn recv(&mut self) -> Option<Self::Message> {
+        match self {
+            Either::Left(subscriber) => subscriber.recv().await,
+            Either::Right(subscriber) => subscriber.recv().await,
+        }
+    }
+}
+
+/// A reusable stack of layers, built one layer at a time.
+///
+/// Layers are applied in the order they are added: the first layer added is the
+/// closest to the wrapped publisher or subscriber, as with
+/// [`PublisherBuilder::with_layer`](crate::PublisherBuilder::with_layer). Since layers
+/// only need a shared reference to wrap a publisher, the same stack can be applied to
+/// any number of publishers or subscribers.
+///
+/// A `LayerStack` is itself a layer, so it can be passed to
+/// [`PublisherBuilder::with_layer`](crate::PublisherBuilder::with_layer) or nested in
+/// another stack.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{
+///     DebuggingPublisherLayer, LayerStack, LoggingPublisherLayer, Publisher, PublisherImpl,
+/// };
+///
+/// let verbose = false;
+/// let layers = LayerStack::new()
+///     .with_layer(LoggingPublisherLayer)
+///     .with_option_layer(verbose.then_some(DebuggingPublisherLayer));
+///
+/// let publisher_a = layers.build_publisher(PublisherImpl::<i32>::new("publisher_a", 10));
+/// let publisher_b = layers.build_publisher(PublisherImpl::<i32>::new("publisher_b", 10));
+///
+/// assert_eq!(publisher_a.get_name(), "publisher_a");
+/// assert_eq!(publisher_b.get_name(), "publisher_b");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LayerStack<L> {
+    layer: L,
+}
+
+impl LayerStack<Identity> {
+    /// Creates an empty stack.
+    pub fn new() -> Self {
+        Self { layer: Identity }
+    }
+}
+
+impl Default for LayerStack<Identity> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L> LayerStack<L> {
+    /// Adds a layer on top of the stack.
+    ///
+    /// # Arguments
+    /// * `layer` - The layer to add
+    ///
+    /// # Returns
+    /// A new stack with the layer applied after every previously added layer
+    pub fn with_layer<T>(self, layer: T) -> LayerStack<Stack<L, T>> {
+        LayerStack {
+            layer: Stack::new(self.layer, layer),
+        }
+    }
+
+    /// Adds a layer on top of the stack if it is `Some`.
+    ///
+    /// # Arguments
+    /// * `layer` - The optional layer to add
+    ///
+    /// # Returns
+    /// A new stack wrapping publishers and subscribers in an [`Either`]
+    pub fn with_option_layer<T>(self, layer: Option<T>) -> LayerStack<Stack<L, Option<T>>> {
+        self.with_layer(layer)
+    }
+
+    /// Wraps a publisher with every layer of the stack.
+    ///
+    /// # Arguments
+    /// * `publisher` - The publisher to wrap
+    pub fn build_publisher<P>(&self, publisher: P) -> L::PublisherType
+    where
+        P: Publisher,
+        L: PublisherLayer<P>,
+    {
+        self.layer.layer(publisher)
+    }
+
+    /// Wraps a subscriber with every layer of the stack.
+    ///
+    /// # Arguments
+    /// * `subscriber` - The subscriber to wrap
+    pub fn build_subscriber<S>(&self, subscriber: S) -> L::SubscriberType
+    where
+        S: Subscriber,
+        L: SubscriberLayer<S>,
+    {
+        self.layer.layer(subscriber)
+    }
+
+    /// Returns the layers of the stack.
+    pub fn into_inner(self) -> L {
+        self.layer
+    }
+}
+
+impl<P, L> PublisherLayer<P> for LayerStack<L>
+where
+    P: Publisher,
+    L: PublisherLayer<P>,
+{
+    type PublisherType = L::PublisherType;
+
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        self.layer.layer(publisher)
+    }
+}
+
+impl<S, L> SubscriberLayer<S> for LayerStack<L>
+where
+    S: Subscriber,
+    L: SubscriberLayer<S>,
+{
+    type SubscriberType = L::SubscriberType;
+
+    fn layer(&self, subscriber: S) -> Self::SubscriberType {
+        self.layer.layer(subscriber)
+    }
+}
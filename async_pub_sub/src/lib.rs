@@ -2,19 +2,35 @@
 #![doc = include_str!("../README.md")]
 
 mod publisher;
+mod recorder;
 mod subscriber;
 mod utils;
 
 mod error;
+mod layers;
 
-pub use error::{Error, Result};
+pub use error::{Error, Result, RpcError};
+pub use layers::{Either, Identity, LayerStack, Stack};
 
 pub use publisher::{
-    DebuggingPublisherLayer, LoggingPublisherLayer, Publisher, PublisherBuilder, PublisherImpl,
-    PublisherLayer, PublisherWrapper, Request,
+    rpc_call, rpc_sleep, BroadcastPublisher, DebuggingPublisherLayer, Envelope,
+    EnvelopePublisherLayer, FilterMapPublisherLayer, FilterPublisherLayer, LoggingPublisherLayer,
+    MapPublisherLayer, MessageId, Metadata, MetricsPublisherLayer, Notification, OverflowPolicy,
+    PublishOutcome, Publisher, PublisherBuilder, PublisherHandle, PublisherImpl, PublisherLayer,
+    PublisherWrapper, Request, Response, ResponseSender, ResponseStream, StreamRequest,
+    StreamSender, TraceContext, Traceable, WeakPublisherHandle,
 };
-pub use subscriber::{Subscriber, SubscriberImpl, SubscriberWrapper};
-pub use utils::LoggingForwarder;
+#[cfg(feature = "tracing")]
+pub use publisher::{Traced, TracingPublisherLayer};
+#[cfg(feature = "metrics")]
+pub use recorder::MetricsCrateRecorder;
+pub use recorder::{MetricsRecorder, PrometheusRecorder};
+pub use subscriber::{
+    DebuggingSubscriberLayer, EnvelopeSubscriberLayer, LoggingSubscriberLayer,
+    MetricsSubscriberLayer, Subscriber, SubscriberBuilder, SubscriberImpl, SubscriberLayer,
+    SubscriberWrapper, Subscription, SubscriptionOptions,
+};
+pub use utils::{LogFormat, LoggingForwarder, Shutdown, ShutdownCoordinator, ShutdownSignal};
 
 #[cfg(feature = "macros")]
 pub use async_pub_sub_macros as macros;
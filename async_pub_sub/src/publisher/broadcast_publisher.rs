@@ -0,0 +1,329 @@
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+use futures::{future::BoxFuture, FutureExt, Stream};
+
+use super::{OverflowPolicy, PublishOutcome, Publisher};
+use crate::{
+    utils::channel::{self, Sender, TrySendError},
+    Error, Result, Shutdown,
+};
+
+/// A publisher that delivers a copy of every message to each bound subscriber.
+///
+/// Unlike [`PublisherImpl`](crate::PublisherImpl), a `BroadcastPublisher` can be bound
+/// any number of times. Each subscriber gets its own bounded queue, so a slow
+/// subscriber only affects the others when the [`OverflowPolicy`] is [`OverflowPolicy::Block`].
+///
+/// Messages published while no subscriber is bound are discarded, and subscribers
+/// only receive the messages published after they subscribed.
+///
+/// # Type Parameters
+///
+/// * `Message` - The type of message that can be published. Must be `Clone` so
+///   that each subscriber receives its own copy.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{BroadcastPublisher, OverflowPolicy, Publisher, SubscriberImpl};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut publisher = BroadcastPublisher::<i32>::new("publisher", 10)
+///         .with_overflow_policy(OverflowPolicy::DropOldest);
+///     let mut subscriber_a = SubscriberImpl::new("subscriber_a");
+///     let mut subscriber_b = SubscriberImpl::new("subscriber_b");
+///
+///     subscriber_a.subscribe_to(&mut publisher).unwrap();
+///     subscriber_b.subscribe_to(&mut publisher).unwrap();
+///
+///     publisher.publish(42).await.unwrap();
+///
+///     assert_eq!(subscriber_a.receive().await, 42);
+///     assert_eq!(subscriber_b.receive().await, 42);
+/// }
+/// ```
+pub struct BroadcastPublisher<Message>
+where
+    Message: Clone + Send + 'static,
+{
+    name: &'static str,
+    buffer_size: usize,
+    overflow_policy: OverflowPolicy,
+    subscribers: Mutex<Vec<SubscriberQueue<Message>>>,
+    dropped_count: AtomicU64,
+    closed: Shutdown,
+}
+
+/// The sending side of a single subscriber's queue.
+struct SubscriberQueue<Message> {
+    subscriber_name: &'static str,
+    sender: Sender<Message>,
+}
+
+impl<Message> Clone for SubscriberQueue<Message> {
+    fn clone(&self) -> Self {
+        Self {
+            subscriber_name: self.subscriber_name,
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<Message> BroadcastPublisher<Message>
+where
+    Message: Clone + Send + 'static,
+{
+    /// Creates a new broadcast publisher using the [`OverflowPolicy::Block`] policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A static string identifier for the publisher
+    /// * `buffer_size` - The size of the queue allocated for each subscriber
+    ///
+    /// # Returns
+    ///
+    /// A new `BroadcastPublisher` instance
+    pub fn new(name: &'static str, buffer_size: usize) -> Self {
+        Self {
+            name,
+            buffer_size,
+            overflow_policy: OverflowPolicy::default(),
+            subscribers: Mutex::new(Vec::new()),
+            dropped_count: AtomicU64::new(0),
+            closed: Shutdown::new(),
+        }
+    }
+
+    /// Sets the policy applied when a subscriber's queue is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `overflow_policy` - The policy to apply to lagging subscribers
+    pub fn with_overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+        self.overflow_policy = overflow_policy;
+        self
+    }
+
+    /// Returns the number of subscribers currently bound to this publisher.
+    pub fn subscriber_count(&self) -> usize {
+        self.lock_subscribers()
+            .iter()
+            .filter(|queue| !queue.sender.is_disconnected())
+            .count()
+    }
+
+    /// Returns how many messages were dropped because a subscriber was lagging.
+    ///
+    /// Each dropped copy is counted, so a message skipped by two subscribers counts twice.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+
+    /// Closes the publisher and the queue of every bound subscriber.
+    ///
+    /// Subscribers still receive the messages already queued, then their streams end.
+    /// Publishing afterwards fails with [`Error::Closed`], and subscribers binding after
+    /// the publisher is closed get an empty stream.
+    pub fn close(&self) {
+        self.closed.trigger();
+        self.lock_subscribers()
+            .iter()
+            .for_each(|queue| queue.sender.close());
+    }
+
+    /// Returns true once the publisher has been closed.
+    ///
+    /// Subscribers going away do not close a broadcast publisher, since new ones can bind;
+    /// see [`BroadcastPublisher::subscriber_count`].
+    pub fn is_closed(&self) -> bool {
+        self.closed.is_triggered()
+    }
+
+    /// Waits until the publisher is closed.
+    pub async fn closed(&self) {
+        self.closed.signal().await
+    }
+
+    fn lock_subscribers(&self) -> std::sync::MutexGuard<'_, Vec<SubscriberQueue<Message>>> {
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Sends a copy of the message to a single subscriber queue, applying the overflow policy.
+    ///
+    /// When `blocking` is false, the [`OverflowPolicy::Block`] policy behaves like
+    /// [`OverflowPolicy::Error`].
+    async fn deliver(
+        &self,
+        queue: &SubscriberQueue<Message>,
+        message: Message,
+        blocking: bool,
+    ) -> Result<PublishOutcome> {
+        if blocking && self.overflow_policy == OverflowPolicy::Block {
+            // A disconnected subscriber is pruned after the broadcast.
+            let _ = queue.sender.send(message).await;
+            return Ok(PublishOutcome::Delivered);
+        }
+
+        match queue
+            .sender
+            .try_send(message, self.overflow_policy.full_queue())
+        {
+            Ok(sent) => {
+                let outcome = PublishOutcome::from(sent);
+                if outcome.is_dropped() {
+                    self.dropped_count.fetch_add(1, Ordering::Relaxed);
+                    log::debug!(
+                        "[{}] -> [{}]: subscriber lagging, message dropped",
+                        self.name,
+                        queue.subscriber_name
+                    );
+                }
+                Ok(outcome)
+            }
+            // The queue is pruned after the broadcast, or the publisher is being closed.
+            Err(TrySendError::Disconnected(_)) | Err(TrySendError::Closed(_)) => {
+                Ok(PublishOutcome::Delivered)
+            }
+            Err(TrySendError::Full(_)) => Err(Error::channel_full::<Message>(
+                self.name,
+                Some(queue.subscriber_name),
+            )),
+        }
+    }
+
+    /// Delivers a copy of the message to every bound subscriber and prunes the
+    /// disconnected ones.
+    ///
+    /// Every subscriber is served even if one of them fails; the first error is returned.
+    async fn broadcast(&self, message: Message, blocking: bool) -> Result<PublishOutcome> {
+        if self.closed.is_triggered() {
+            return Err(Error::closed::<Message>(self.name));
+        }
+
+        let queues = self.lock_subscribers().clone();
+
+        let mut results = Vec::with_capacity(queues.len());
+        if let Some((last, others)) = queues.split_last() {
+            for queue in others {
+                results.push(self.deliver(queue, message.clone(), blocking).await);
+            }
+            results.push(self.deliver(last, message, blocking).await);
+        }
+
+        self.lock_subscribers()
+            .retain(|queue| !queue.sender.is_disconnected());
+
+        results
+            .into_iter()
+            .try_fold(PublishOutcome::Delivered, |outcome, result| {
+                let delivered = result?;
+                Ok(if delivered.is_dropped() {
+                    delivered
+                } else {
+                    outcome
+                })
+            })
+    }
+}
+
+impl<Message> Publisher for BroadcastPublisher<Message>
+where
+    Message: Clone + Send + Sync + 'static,
+{
+    type Message = Message;
+
+    /// Returns the name of the publisher.
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Publishes a copy of the message to every bound subscriber.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to publish
+    ///
+    /// # Returns
+    ///
+    /// A boxed future that resolves once every subscriber has been served according
+    /// to the overflow policy
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        async move { self.broadcast(message, true).await.map(|_| ()) }.boxed()
+    }
+
+    /// Publishes a copy of the message to every bound subscriber without waiting.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to publish
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the outcome of the operation; a dropped outcome is reported
+    /// if any subscriber missed the message
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Closed`] if the publisher has been closed, and
+    /// [`Error::ChannelFull`] if a subscriber's queue is full and the policy is
+    /// [`OverflowPolicy::Block`] or [`OverflowPolicy::Error`]
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        self.broadcast(message, false)
+            .now_or_never()
+            .expect("a non-blocking broadcast never waits")
+    }
+
+    /// Closes the publisher, see [`BroadcastPublisher::close`].
+    fn close(&self) {
+        BroadcastPublisher::close(self)
+    }
+
+    /// Returns true if at least one subscriber is bound.
+    fn is_bound(&self) -> bool {
+        self.subscriber_count() > 0
+    }
+
+    /// Returns true once the publisher is closed, see [`BroadcastPublisher::is_closed`].
+    fn is_closed(&self) -> bool {
+        BroadcastPublisher::is_closed(self)
+    }
+
+    /// Waits until the publisher is closed, see [`BroadcastPublisher::closed`].
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        BroadcastPublisher::closed(self).boxed()
+    }
+
+    /// Creates a dedicated queue for the subscriber and returns its message stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `subscriber_name` - The name of the subscriber to bind
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the message stream. Binding a broadcast publisher never fails.
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        let (sender, receiver) = channel::channel(self.buffer_size);
+        if self.closed.is_triggered() {
+            sender.close();
+        }
+        self.lock_subscribers().push(SubscriberQueue {
+            subscriber_name,
+            sender,
+        });
+
+        Ok(Box::pin(receiver))
+    }
+}
@@ -0,0 +1,209 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::LogFormat;
+
+/// The unique identifier of an [`Envelope`], displayed as `#<id>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MessageId(u64);
+
+impl MessageId {
+    /// Returns a new identifier, unique within the process.
+    fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Returns the numeric value of the identifier.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for MessageId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// The metadata of a message wrapped in an [`Envelope`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    id: MessageId,
+    timestamp: SystemTime,
+    source: &'static str,
+    correlation_id: Option<MessageId>,
+    headers: BTreeMap<String, String>,
+}
+
+impl Metadata {
+    /// Returns the unique identifier of the message.
+    pub fn id(&self) -> MessageId {
+        self.id
+    }
+
+    /// Returns when the message was wrapped, i.e. sent.
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    /// Returns the name of the publisher that sent the message.
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+
+    /// Returns the identifier of the message this one relates to, e.g. the request it
+    /// answers.
+    pub fn correlation_id(&self) -> Option<MessageId> {
+        self.correlation_id
+    }
+
+    /// Returns the user headers of the message.
+    pub fn headers(&self) -> &BTreeMap<String, String> {
+        &self.headers
+    }
+
+    /// Returns the value of a user header.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+}
+
+/// Formats the metadata as `#<id> [<source>] @<milliseconds since epoch>`, followed by the
+/// correlation id and the headers when there are some.
+impl Display for Metadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let timestamp = self
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        write!(f, "{} [{}] @{}", self.id, self.source, timestamp)?;
+
+        if let Some(correlation_id) = self.correlation_id {
+            write!(f, " (re {})", correlation_id)?;
+        }
+        if !self.headers.is_empty() {
+            let headers: Vec<_> = self
+                .headers
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect();
+            write!(f, " {{{}}}", headers.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// A message wrapped with its [`Metadata`]: a unique id, a send timestamp, the source
+/// publisher name, an optional correlation id and user headers.
+///
+/// Envelopes are opt-in: publishers of `Envelope<M>` carry them like any other message.
+/// `EnvelopePublisherLayer` wraps the messages published as plain `M` automatically, and
+/// `EnvelopeSubscriberLayer` unwraps them on the subscriber side.
+///
+/// Envelopes do not implement `Display`: the logging layers print their metadata through
+/// [`LogFormat`] instead of the publisher and subscriber names.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::Envelope;
+///
+/// let request = Envelope::new("client", "ping");
+/// let response = Envelope::new("server", "pong")
+///     .with_correlation_id(request.metadata().id())
+///     .with_header("status", "ok");
+///
+/// assert_eq!(response.metadata().correlation_id(), Some(request.metadata().id()));
+/// assert_eq!(response.metadata().header("status"), Some("ok"));
+/// assert_eq!(response.into_message(), "pong");
+/// ```
+#[derive(Clone)]
+pub struct Envelope<M> {
+    /// The wrapped message
+    pub message: M,
+    metadata: Metadata,
+}
+
+impl<M> Envelope<M> {
+    /// Wraps a message with a new unique id and the current time.
+    ///
+    /// # Arguments
+    /// * `source` - The name of the publisher sending the message
+    /// * `message` - The message to wrap
+    pub fn new(source: &'static str, message: M) -> Self {
+        Self {
+            message,
+            metadata: Metadata {
+                id: MessageId::next(),
+                timestamp: SystemTime::now(),
+                source,
+                correlation_id: None,
+                headers: BTreeMap::new(),
+            },
+        }
+    }
+
+    /// Sets the identifier of the message this one relates to.
+    pub fn with_correlation_id(mut self, correlation_id: MessageId) -> Self {
+        self.metadata.correlation_id = Some(correlation_id);
+        self
+    }
+
+    /// Adds a user header, replacing any header with the same name.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Returns the metadata of the message.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Unwraps the message, dropping its metadata.
+    pub fn into_message(self) -> M {
+        self.message
+    }
+
+    /// Splits the envelope into its metadata and its message.
+    pub fn into_parts(self) -> (Metadata, M) {
+        (self.metadata, self.message)
+    }
+
+    /// Converts the message, keeping its metadata.
+    pub fn map<N>(self, f: impl FnOnce(M) -> N) -> Envelope<N> {
+        Envelope {
+            message: f(self.message),
+            metadata: self.metadata,
+        }
+    }
+}
+
+impl<M> Debug for Envelope<M>
+where
+    M: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {:?}", self.metadata, self.message)
+    }
+}
+
+/// Logs the metadata of the envelope in place of the publisher name.
+impl<M> LogFormat for Envelope<M>
+where
+    M: Display,
+{
+    fn format_published(&self, _publisher: &'static str, subscriber: &'static str) -> String {
+        format!("{} -> [{}]: {}", self.metadata, subscriber, self.message)
+    }
+
+    fn format_received(&self, subscriber: &'static str, _publishers: &[&'static str]) -> String {
+        format!("[{}] <- {}: {}", subscriber, self.metadata, self.message)
+    }
+}
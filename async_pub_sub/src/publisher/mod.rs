@@ -1,10 +1,31 @@
+mod broadcast_publisher;
+mod envelope;
+mod notification;
+mod publisher_handle;
 mod publisher_impl;
 mod publisher_middlewares;
+mod rpc_call;
+mod stream_request;
 
 mod publisher_trait;
 mod publisher_types;
+mod trace_context;
 
+pub use broadcast_publisher::BroadcastPublisher;
+pub use envelope::{Envelope, MessageId, Metadata};
+pub use notification::Notification;
+pub use publisher_handle::{PublisherHandle, WeakPublisherHandle};
 pub use publisher_impl::PublisherImpl;
-pub use publisher_middlewares::{DebuggingPublisherLayer, LoggingPublisherLayer, PublisherBuilder};
+#[cfg(feature = "tracing")]
+pub use publisher_middlewares::TracingPublisherLayer;
+pub use publisher_middlewares::{
+    DebuggingPublisherLayer, EnvelopePublisherLayer, FilterMapPublisherLayer, FilterPublisherLayer,
+    LoggingPublisherLayer, MapPublisherLayer, MetricsPublisherLayer, PublisherBuilder,
+};
 pub use publisher_trait::{Publisher, PublisherLayer, PublisherWrapper};
-pub use publisher_types::Request;
+pub use publisher_types::{OverflowPolicy, PublishOutcome, Request, Response, ResponseSender};
+pub use rpc_call::{rpc_call, rpc_sleep};
+pub use stream_request::{ResponseStream, StreamRequest, StreamSender};
+#[cfg(feature = "tracing")]
+pub use trace_context::Traced;
+pub use trace_context::{TraceContext, Traceable};
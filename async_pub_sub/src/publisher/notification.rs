@@ -0,0 +1,77 @@
+use std::fmt::{Debug, Display};
+
+use crate::{TraceContext, Traceable};
+
+/// A one-way message, carrying a call without any response channel.
+///
+/// This is the message of the `#[notify]` methods of the interfaces generated by
+/// `rpc_interface`: their client methods resolve as soon as the notification is published,
+/// without waiting for the server to handle it.
+///
+/// A notification also carries the [`TraceContext`] it was published in, see [`Traceable`].
+///
+/// # Examples
+///
+/// ```
+/// use async_pub_sub::Notification;
+///
+/// let notification = Notification::new(String::from("hello"));
+/// assert_eq!(notification.content, "hello");
+/// ```
+#[derive(Clone)]
+pub struct Notification<Req>
+where
+    Req: Debug,
+{
+    pub content: Req,
+    trace_context: TraceContext,
+}
+
+impl<Req> Notification<Req>
+where
+    Req: Debug,
+{
+    /// Creates a notification with the given content.
+    pub fn new(content: Req) -> Self {
+        Self {
+            content,
+            trace_context: TraceContext::default(),
+        }
+    }
+}
+
+impl<Req> Traceable for Notification<Req>
+where
+    Req: Debug,
+{
+    fn trace_context(&self) -> &TraceContext {
+        &self.trace_context
+    }
+
+    fn trace_context_mut(&mut self) -> &mut TraceContext {
+        &mut self.trace_context
+    }
+}
+
+impl<Req> Display for Notification<Req>
+where
+    Req: Display + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Notification({}: {})",
+            self.content,
+            std::any::type_name::<Req>(),
+        )
+    }
+}
+
+impl<Req> Debug for Notification<Req>
+where
+    Req: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "inputs: {:?}", self.content)
+    }
+}
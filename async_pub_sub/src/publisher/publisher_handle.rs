@@ -0,0 +1,186 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Weak},
+};
+
+use futures::{future::BoxFuture, Stream};
+
+use super::{PublishOutcome, Publisher};
+use crate::{Error, Result};
+
+/// A shared publisher.
+type SharedPublisher<Message> = dyn Publisher<Message = Message> + Send + Sync;
+
+/// A cheap, cloneable handle on a publisher, to publish from several tasks.
+///
+/// A handle takes ownership of a publisher once its subscriber is bound, and can be cloned
+/// and sent across tasks; every clone publishes through the same publisher, including its
+/// layers. The publisher is dropped, ending the stream of its subscriber, once the last
+/// handle is dropped. [`PublisherHandle::downgrade`] gives a [`WeakPublisherHandle`] that
+/// does not keep the publisher alive.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{Publisher, PublisherHandle, PublisherImpl, SubscriberImpl};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut publisher = PublisherImpl::new("publisher", 10);
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     let handle = PublisherHandle::new(publisher);
+///     let producers: Vec<_> = (0..3)
+///         .map(|value| {
+///             let handle = handle.clone();
+///             tokio::spawn(async move { handle.publish(value).await.unwrap() })
+///         })
+///         .collect();
+///     drop(handle);
+///
+///     for producer in producers {
+///         producer.await.unwrap();
+///     }
+///
+///     let mut received = Vec::new();
+///     while let Some(value) = subscriber.recv().await {
+///         received.push(value);
+///     }
+///     received.sort();
+///     assert_eq!(received, vec![0, 1, 2]);
+/// }
+/// ```
+pub struct PublisherHandle<Message> {
+    /// The publisher shared by every clone of the handle
+    publisher: Arc<SharedPublisher<Message>>,
+}
+
+impl<Message> PublisherHandle<Message>
+where
+    Message: Send + 'static,
+{
+    /// Creates a handle owning the given publisher.
+    ///
+    /// # Arguments
+    /// * `publisher` - The publisher to share, usually already bound to its subscriber
+    pub fn new(publisher: impl Publisher<Message = Message> + Send + Sync + 'static) -> Self {
+        Self {
+            publisher: Arc::new(publisher),
+        }
+    }
+
+    /// Creates a weak handle on the same publisher, that does not keep it alive.
+    pub fn downgrade(&self) -> WeakPublisherHandle<Message> {
+        WeakPublisherHandle {
+            publisher: Arc::downgrade(&self.publisher),
+        }
+    }
+}
+
+impl<Message> Clone for PublisherHandle<Message> {
+    fn clone(&self) -> Self {
+        Self {
+            publisher: self.publisher.clone(),
+        }
+    }
+}
+
+impl<Message> Publisher for PublisherHandle<Message>
+where
+    Message: Send + 'static,
+{
+    type Message = Message;
+
+    /// Returns the name of the shared publisher
+    fn get_name(&self) -> &'static str {
+        self.publisher.get_name()
+    }
+
+    /// Publishes the message through the shared publisher
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        self.publisher.publish(message)
+    }
+
+    /// Publishes the message through the shared publisher without waiting
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        self.publisher.try_publish(message)
+    }
+
+    /// Closes the shared publisher, for every handle
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    /// Returns true if a subscriber is bound to the shared publisher
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    /// Returns true once the shared publisher is closed
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    /// Waits until the shared publisher is closed
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
+    /// Gets a message stream from the shared publisher.
+    ///
+    /// # Errors
+    /// * [`Error::Shared`] if the handle has been cloned or downgraded: subscribers should
+    ///   subscribe to the publisher before it is shared
+    /// * Any error reported by the shared publisher
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        let name = self.publisher.get_name();
+        Arc::get_mut(&mut self.publisher)
+            .ok_or_else(|| Error::shared::<Message>(name))?
+            .get_message_stream(subscriber_name)
+    }
+}
+
+/// A weak handle on a publisher shared by [`PublisherHandle`]s.
+///
+/// A weak handle does not keep the publisher alive: once every [`PublisherHandle`] is
+/// dropped, the publisher is dropped and the stream of its subscriber ends.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{PublisherHandle, PublisherImpl};
+///
+/// let handle = PublisherHandle::new(PublisherImpl::<i32>::new("publisher", 10));
+/// let weak_handle = handle.downgrade();
+/// assert!(weak_handle.upgrade().is_some());
+///
+/// drop(handle);
+/// assert!(weak_handle.upgrade().is_none());
+/// ```
+pub struct WeakPublisherHandle<Message> {
+    /// The publisher shared by the strong handles
+    publisher: Weak<SharedPublisher<Message>>,
+}
+
+impl<Message> WeakPublisherHandle<Message>
+where
+    Message: Send + 'static,
+{
+    /// Returns a strong handle on the publisher, or `None` if it has been dropped.
+    pub fn upgrade(&self) -> Option<PublisherHandle<Message>> {
+        let publisher = self.publisher.upgrade()?;
+        Some(PublisherHandle { publisher })
+    }
+}
+
+impl<Message> Clone for WeakPublisherHandle<Message> {
+    fn clone(&self) -> Self {
+        Self {
+            publisher: self.publisher.clone(),
+        }
+    }
+}
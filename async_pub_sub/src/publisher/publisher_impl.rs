@@ -1,7 +1,12 @@
-use futures::{FutureExt, SinkExt};
+use std::sync::atomic::{AtomicU64, Ordering};
 
-use super::Publisher;
-use crate::Result;
+use futures::FutureExt;
+
+use super::{OverflowPolicy, PublishOutcome, Publisher};
+use crate::{
+    utils::channel::{self, Receiver, SendError, Sender, TrySendError},
+    Error, Result,
+};
 
 /// A concrete implementation of the Publisher trait that handles message distribution
 /// to a single subscriber.
@@ -15,8 +20,11 @@ use crate::Result;
 ///
 /// * `name` - A static identifier for the publisher
 /// * `subscriber_name` - The name of the currently bound subscriber, if any
+/// * `overflow_policy` - What to do with a message when the channel is full
 /// * `sender` - The sending half of the message channel
-/// * `receiver` - The receiving half of the channel, available until a subscriber is bound
+/// * `receiver` - The receiving half of the channel, available until a subscriber is first
+///   bound
+/// * `dropped_count` - The number of messages dropped because of the overflow policy
 ///
 /// # Example
 ///
@@ -45,8 +53,10 @@ where
 {
     name: &'static str,
     subscriber_name: Option<&'static str>,
-    sender: futures::channel::mpsc::Sender<Message>,
-    receiver: Option<futures::channel::mpsc::Receiver<Message>>,
+    overflow_policy: OverflowPolicy,
+    sender: Sender<Message>,
+    receiver: Option<Receiver<Message>>,
+    dropped_count: AtomicU64,
 }
 
 impl<Message> PublisherImpl<Message>
@@ -55,6 +65,9 @@ where
 {
     /// Creates a new publisher with the specified name and message buffer capacity.
     ///
+    /// The publisher uses the [`OverflowPolicy::Block`] policy, see
+    /// [`PublisherImpl::with_overflow_policy`] to change it.
+    ///
     /// # Arguments
     ///
     /// * `name` - A static string identifier for the publisher
@@ -64,17 +77,37 @@ where
     ///
     /// A new `PublisherImpl` instance
     pub fn new(name: &'static str, buffer_size: usize) -> Self {
-        let (sender, receiver) = futures::channel::mpsc::channel(buffer_size);
+        let (sender, receiver) = channel::channel(buffer_size);
         Self {
             name,
             subscriber_name: None,
+            overflow_policy: OverflowPolicy::default(),
             sender,
             receiver: Some(receiver),
+            dropped_count: AtomicU64::new(0),
         }
     }
 
+    /// Sets the policy applied when the channel is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `overflow_policy` - The policy to apply when the subscriber is lagging
+    pub fn with_overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+        self.overflow_policy = overflow_policy;
+        self
+    }
+
+    /// Returns how many messages were dropped because of the overflow policy.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+
     /// Publishes a message to the channel.
     ///
+    /// With the [`OverflowPolicy::Block`] policy, waits until the channel has room.
+    /// Every other policy behaves like [`PublisherImpl::try_publish`].
+    ///
     /// # Arguments
     ///
     /// * `message` - The message to publish
@@ -82,9 +115,118 @@ where
     /// # Returns
     ///
     /// A Result indicating whether the message was successfully sent
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Disconnected`] if the subscriber has been dropped or unsubscribed,
+    /// [`Error::Closed`] if the publisher has been closed, and [`Error::ChannelFull`]
+    /// if the channel is full and the policy is [`OverflowPolicy::Error`]
     pub async fn publish(&self, message: Message) -> Result<()> {
-        self.sender.clone().send(message).await?;
-        Ok(())
+        self.publish_with_outcome(message).await.map(|_| ())
+    }
+
+    /// Publishes a message to the channel like [`PublisherImpl::publish`], reporting
+    /// whether a message was dropped because of the overflow policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to publish
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the outcome of the operation, telling whether a message was dropped
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`PublisherImpl::publish`]
+    pub async fn publish_with_outcome(&self, message: Message) -> Result<PublishOutcome> {
+        if self.overflow_policy != OverflowPolicy::Block {
+            return self.try_publish(message);
+        }
+
+        self.sender
+            .send(message)
+            .await
+            .map(|_| PublishOutcome::Delivered)
+            .map_err(|err| match err {
+                SendError::Disconnected(_) => {
+                    Error::disconnected::<Message>(self.name, self.subscriber_name)
+                }
+                SendError::Closed(_) => Error::closed::<Message>(self.name),
+            })
+    }
+
+    /// Publishes a message to the channel without waiting, applying the overflow policy
+    /// if the channel is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to publish
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the outcome of the operation, telling whether a message was dropped
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Disconnected`] if the subscriber has been dropped or unsubscribed,
+    /// [`Error::Closed`] if the publisher has been closed, and [`Error::ChannelFull`]
+    /// if the channel is full and the policy is [`OverflowPolicy::Block`] or
+    /// [`OverflowPolicy::Error`]
+    pub fn try_publish(&self, message: Message) -> Result<PublishOutcome> {
+        match self
+            .sender
+            .try_send(message, self.overflow_policy.full_queue())
+        {
+            Ok(sent) => {
+                let outcome = PublishOutcome::from(sent);
+                if outcome.is_dropped() {
+                    self.dropped_count.fetch_add(1, Ordering::Relaxed);
+                    log::debug!(
+                        "[{}] -> [{}]: channel full, message dropped ({:?})",
+                        self.name,
+                        self.subscriber_name.unwrap_or("unbound"),
+                        outcome
+                    );
+                }
+                Ok(outcome)
+            }
+            Err(TrySendError::Full(_)) => Err(Error::channel_full::<Message>(
+                self.name,
+                self.subscriber_name,
+            )),
+            Err(TrySendError::Disconnected(_)) => Err(Error::disconnected::<Message>(
+                self.name,
+                self.subscriber_name,
+            )),
+            Err(TrySendError::Closed(_)) => Err(Error::closed::<Message>(self.name)),
+        }
+    }
+
+    /// Closes the publisher.
+    ///
+    /// The subscriber still receives the messages already queued, then its stream ends.
+    /// Publishing afterwards fails with [`Error::Closed`].
+    pub fn close(&self) {
+        self.sender.close();
+    }
+
+    /// Returns true if a subscriber is currently bound to the publisher.
+    pub fn is_bound(&self) -> bool {
+        self.receiver.is_none() && !self.sender.is_disconnected()
+    }
+
+    /// Returns true once the subscriber has gone away or the publisher has been closed.
+    ///
+    /// A publisher whose subscriber has gone away is open again once a new subscriber is
+    /// bound, see [`Publisher::get_message_stream`].
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+
+    /// Waits until the subscriber goes away or the publisher is closed.
+    pub async fn closed(&self) {
+        self.sender.closed().await
     }
 }
 
@@ -108,19 +250,65 @@ where
     /// # Returns
     ///
     /// A boxed future that resolves to a Result indicating success or failure
-    fn publish(&self, message: Self::Message) -> futures::future::BoxFuture<Result<()>> {
-        let mut sender = self.sender.clone();
-        async move {
-            sender
-                .send(message)
-                .await
-                .map_err(|err| format!("Failed to send message (err: {})", err).into())
-        }
-        .boxed()
+    fn publish(&self, message: Self::Message) -> futures::future::BoxFuture<'_, Result<()>> {
+        PublisherImpl::publish(self, message).boxed()
+    }
+
+    /// Publishes a message through the channel without waiting.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to publish
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the outcome of the operation
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        PublisherImpl::try_publish(self, message)
+    }
+
+    /// Publishes a message through the channel, reporting its outcome.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to publish
+    ///
+    /// # Returns
+    ///
+    /// A future that resolves to the outcome of the operation
+    fn publish_with_outcome(
+        &self,
+        message: Self::Message,
+    ) -> futures::future::BoxFuture<'_, Result<PublishOutcome>> {
+        PublisherImpl::publish_with_outcome(self, message).boxed()
+    }
+
+    /// Closes the publisher, see [`PublisherImpl::close`].
+    fn close(&self) {
+        PublisherImpl::close(self)
+    }
+
+    /// Returns true if a subscriber is bound, see [`PublisherImpl::is_bound`].
+    fn is_bound(&self) -> bool {
+        PublisherImpl::is_bound(self)
+    }
+
+    /// Returns true once the publisher is closed, see [`PublisherImpl::is_closed`].
+    fn is_closed(&self) -> bool {
+        PublisherImpl::is_closed(self)
+    }
+
+    /// Waits until the publisher is closed, see [`PublisherImpl::closed`].
+    fn closed(&self) -> futures::future::BoxFuture<'_, ()> {
+        PublisherImpl::closed(self).boxed()
     }
 
     /// Binds a subscriber to this publisher and returns the message stream.
     ///
+    /// Once the stream of the previous subscriber has been dropped, e.g. after an
+    /// unsubscription, the publisher can be bound again. The messages queued and not yet
+    /// received by the previous subscriber are delivered to the new one.
+    ///
     /// # Arguments
     ///
     /// * `subscriber_name` - The name of the subscriber to bind
@@ -132,20 +320,18 @@ where
     ///
     /// # Errors
     ///
-    /// Returns an error if the publisher is already bound to another subscriber
+    /// Returns [`Error::AlreadyBound`] if the publisher is already bound to another subscriber
     fn get_message_stream(
         &mut self,
         subscriber_name: &'static str,
     ) -> Result<std::pin::Pin<Box<dyn futures::Stream<Item = Self::Message> + Send + Sync + 'static>>>
     {
-        let Some(receiver) = self.receiver.take() else {
-            return Err(format!(
-                "{} publisher can only be bound to one subscriber (already bound to {})",
+        let Some(receiver) = self.receiver.take().or_else(|| self.sender.rebind()) else {
+            return Err(Error::already_bound::<Message>(
                 self.name,
                 self.subscriber_name
-                    .expect("the subscriber name should be known at this point")
-            )
-            .into());
+                    .expect("the subscriber name should be known at this point"),
+            ));
         };
 
         self.subscriber_name = Some(subscriber_name);
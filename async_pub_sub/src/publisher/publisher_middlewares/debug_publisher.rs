@@ -2,7 +2,7 @@ use std::{fmt::Debug, pin::Pin};
 
 use futures::{future::BoxFuture, FutureExt, Stream};
 
-use crate::{Publisher, PublisherLayer, Result};
+use crate::{PublishOutcome, Publisher, PublisherLayer, Result};
 
 /// A middleware layer that adds debug logging capabilities to a publisher.
 /// When messages are published, it will log them using the debug format.
@@ -59,7 +59,7 @@ where
     /// * `message` - The message to publish
     ///
     /// Logs the message in the format: "[publisher_name] -> [subscriber_name]: message_debug_format"
-    fn publish(&self, message: Self::Message) -> BoxFuture<Result<()>> {
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
         async move {
             let message_str = format!("{:?}", &message);
             let result = self.publisher.publish(message).await;
@@ -75,6 +75,46 @@ where
         .boxed()
     }
 
+    /// Publishes a message without waiting while logging its debug representation.
+    ///
+    /// # Arguments
+    /// * `message` - The message to publish
+    ///
+    /// Logs the message in the format: "[publisher_name] -> [subscriber_name]: message_debug_format (outcome)"
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        let message_str = format!("{:?}", &message);
+        let result = self.publisher.try_publish(message);
+        log::info!(
+            "[{}] -> [{}]: {} ({:?})",
+            self.publisher.get_name(),
+            self.subscriber_name
+                .expect("subscriber name should be known"),
+            message_str,
+            result
+        );
+        result
+    }
+
+    /// Closes the underlying publisher
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    /// Returns true if a subscriber is bound to the underlying publisher
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    /// Returns true once the underlying publisher is closed
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    /// Waits until the underlying publisher is closed
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
     /// Gets a message stream for the given subscriber name.
     /// Stores the subscriber name for use in debug logging.
     ///
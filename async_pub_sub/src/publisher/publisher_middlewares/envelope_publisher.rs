@@ -0,0 +1,162 @@
+use std::{collections::BTreeMap, pin::Pin};
+
+use futures::{future::BoxFuture, Stream, StreamExt};
+
+use crate::{Envelope, PublishOutcome, Publisher, PublisherLayer, Result};
+
+/// A publisher middleware layer wrapping every published message in an [`Envelope`].
+///
+/// The layer turns a `Publisher<Message = Envelope<M>>` into a `Publisher<Message = M>`:
+/// each message gets a new id, the current time, the publisher name as source and the
+/// headers of the layer. Subscribers of the enveloping publisher receive the bare messages;
+/// subscribers that want the envelopes subscribe to the underlying publisher before it is
+/// layered.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{
+///     Envelope, EnvelopePublisherLayer, Publisher, PublisherImpl, PublisherLayer, SubscriberImpl,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut inner = PublisherImpl::<Envelope<i32>>::new("publisher", 10);
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///     subscriber.subscribe_to(&mut inner).unwrap();
+///
+///     let publisher = EnvelopePublisherLayer::new()
+///         .with_header("unit", "celsius")
+///         .layer(inner);
+///     publisher.publish(21).await.unwrap();
+///
+///     let envelope = subscriber.receive().await;
+///     assert_eq!(envelope.metadata().source(), "publisher");
+///     assert_eq!(envelope.metadata().header("unit"), Some("celsius"));
+///     assert_eq!(envelope.message, 21);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopePublisherLayer {
+    /// The headers added to every envelope
+    headers: BTreeMap<String, String>,
+}
+
+impl EnvelopePublisherLayer {
+    /// Creates a layer wrapping the messages in envelopes without headers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a header to every envelope created by the publishers built from this layer.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+}
+
+impl<P, M> PublisherLayer<P> for EnvelopePublisherLayer
+where
+    P: Publisher<Message = Envelope<M>>,
+    M: Send + Sync + 'static,
+{
+    type PublisherType = EnvelopePublisher<P>;
+
+    /// Creates a new `EnvelopePublisher` by wrapping the provided publisher.
+    ///
+    /// # Arguments
+    /// * `publisher` - The underlying publisher of envelopes
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        EnvelopePublisher {
+            headers: self.headers.clone(),
+            publisher,
+        }
+    }
+}
+
+/// A publisher wrapper wrapping the published messages in envelopes, built by
+/// [`EnvelopePublisherLayer`].
+pub struct EnvelopePublisher<P> {
+    /// The headers added to every envelope
+    headers: BTreeMap<String, String>,
+    /// The underlying publisher being wrapped
+    publisher: P,
+}
+
+impl<P, M> EnvelopePublisher<P>
+where
+    P: Publisher<Message = Envelope<M>>,
+    M: Send + Sync + 'static,
+{
+    /// Publishes an envelope built by the caller, e.g. to set its correlation id. The
+    /// headers of the layer are added, unless the envelope already has them.
+    pub fn publish_envelope(&self, envelope: Envelope<M>) -> BoxFuture<'_, Result<()>> {
+        self.publisher.publish(self.add_headers(envelope))
+    }
+
+    fn wrap(&self, message: M) -> Envelope<M> {
+        self.add_headers(Envelope::new(self.publisher.get_name(), message))
+    }
+
+    fn add_headers(&self, envelope: Envelope<M>) -> Envelope<M> {
+        let mut envelope = envelope;
+        for (name, value) in &self.headers {
+            if envelope.metadata().header(name).is_none() {
+                envelope = envelope.with_header(name.clone(), value.clone());
+            }
+        }
+        envelope
+    }
+}
+
+impl<P, M> Publisher for EnvelopePublisher<P>
+where
+    P: Publisher<Message = Envelope<M>>,
+    M: Send + Sync + 'static,
+{
+    type Message = M;
+
+    /// Returns the name of the underlying publisher
+    fn get_name(&self) -> &'static str {
+        self.publisher.get_name()
+    }
+
+    /// Wraps the message in a new envelope and publishes it.
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        self.publisher.publish(self.wrap(message))
+    }
+
+    /// Wraps the message in a new envelope and publishes it without waiting.
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        self.publisher.try_publish(self.wrap(message))
+    }
+
+    /// Closes the underlying publisher
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    /// Returns true if a subscriber is bound to the underlying publisher
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    /// Returns true once the underlying publisher is closed
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    /// Waits until the underlying publisher is closed
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
+    /// Gets a stream of the messages of the underlying publisher, out of their envelopes.
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        let stream = self.publisher.get_message_stream(subscriber_name)?;
+        Ok(Box::pin(stream.map(Envelope::into_message)))
+    }
+}
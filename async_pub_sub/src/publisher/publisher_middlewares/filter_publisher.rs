@@ -0,0 +1,198 @@
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use futures::{future::BoxFuture, FutureExt, Stream};
+
+use crate::{PublishOutcome, Publisher, PublisherLayer, Result};
+
+/// A publisher middleware layer that only lets through the messages matching a predicate.
+///
+/// Rejected messages are never queued, but publishing them still succeeds. The number of
+/// rejected messages is available through [`FilterPublisherLayer::dropped_count`] for every
+/// publisher built from the layer, and through `dropped_count` on each filtered publisher.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{FilterPublisherLayer, Publisher, PublisherImpl, PublisherLayer, SubscriberImpl};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let layer = FilterPublisherLayer::new(|value: &i32| value % 2 == 0);
+///     let mut publisher = layer.layer(PublisherImpl::new("publisher", 10));
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     publisher.publish(1).await.unwrap();
+///     publisher.publish(2).await.unwrap();
+///
+///     assert_eq!(subscriber.receive().await, 2);
+///     assert_eq!(layer.dropped_count(), 1);
+/// }
+/// ```
+pub struct FilterPublisherLayer<F> {
+    /// The predicate deciding which messages are published
+    predicate: Arc<F>,
+    /// The number of messages rejected by every publisher built from this layer
+    dropped_count: Arc<AtomicU64>,
+}
+
+impl<F> FilterPublisherLayer<F> {
+    /// Creates a new filtering layer.
+    ///
+    /// # Arguments
+    /// * `predicate` - Returns true for the messages that must be published
+    pub fn new(predicate: F) -> Self {
+        Self {
+            predicate: Arc::new(predicate),
+            dropped_count: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Returns how many messages were rejected by the publishers built from this layer.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+}
+
+impl<F> Clone for FilterPublisherLayer<F> {
+    fn clone(&self) -> Self {
+        Self {
+            predicate: self.predicate.clone(),
+            dropped_count: self.dropped_count.clone(),
+        }
+    }
+}
+
+impl<P, F> PublisherLayer<P> for FilterPublisherLayer<F>
+where
+    P: Publisher,
+    F: Fn(&P::Message) -> bool + Send + Sync + 'static,
+{
+    type PublisherType = FilterPublisher<P, F>;
+
+    /// Creates a new `FilterPublisher` by wrapping the provided publisher.
+    ///
+    /// # Arguments
+    /// * `publisher` - The underlying publisher to filter messages for
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        FilterPublisher {
+            layer: self.clone(),
+            dropped_count: AtomicU64::new(0),
+            publisher,
+        }
+    }
+}
+
+/// A publisher wrapper that discards the messages rejected by a predicate.
+pub struct FilterPublisher<P, F> {
+    /// The layer this publisher was built from, holding the predicate and the shared counter
+    layer: FilterPublisherLayer<F>,
+    /// The number of messages rejected by this publisher
+    dropped_count: AtomicU64,
+    /// The underlying publisher being wrapped
+    publisher: P,
+}
+
+impl<P, F> FilterPublisher<P, F>
+where
+    P: Publisher,
+    F: Fn(&P::Message) -> bool,
+{
+    /// Returns how many messages were rejected by this publisher.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+
+    /// Applies the predicate, counting the message if it is rejected.
+    fn accepts(&self, message: &P::Message) -> bool {
+        if (self.layer.predicate)(message) {
+            return true;
+        }
+
+        self.dropped_count.fetch_add(1, Ordering::Relaxed);
+        self.layer.dropped_count.fetch_add(1, Ordering::Relaxed);
+        log::debug!("[{}]: message filtered out", self.publisher.get_name());
+        false
+    }
+}
+
+impl<P, F> Publisher for FilterPublisher<P, F>
+where
+    P: Publisher,
+    F: Fn(&P::Message) -> bool + Send + Sync + 'static,
+{
+    type Message = P::Message;
+
+    /// Returns the name of the underlying publisher
+    fn get_name(&self) -> &'static str {
+        self.publisher.get_name()
+    }
+
+    /// Publishes the message if it matches the predicate; rejected messages resolve
+    /// successfully without being queued.
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        if self.accepts(&message) {
+            self.publisher.publish(message)
+        } else {
+            async { Ok(()) }.boxed()
+        }
+    }
+
+    /// Publishes the message without waiting if it matches the predicate, reports
+    /// [`PublishOutcome::Filtered`] otherwise.
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        if self.accepts(&message) {
+            self.publisher.try_publish(message)
+        } else {
+            Ok(PublishOutcome::Filtered)
+        }
+    }
+
+    /// Publishes the message if it matches the predicate, reports
+    /// [`PublishOutcome::Filtered`] otherwise.
+    fn publish_with_outcome(
+        &self,
+        message: Self::Message,
+    ) -> BoxFuture<'_, Result<PublishOutcome>> {
+        if self.accepts(&message) {
+            self.publisher.publish_with_outcome(message)
+        } else {
+            async { Ok(PublishOutcome::Filtered) }.boxed()
+        }
+    }
+
+    /// Closes the underlying publisher
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    /// Returns true if a subscriber is bound to the underlying publisher
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    /// Returns true once the underlying publisher is closed
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    /// Waits until the underlying publisher is closed
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
+    /// Gets a message stream from the underlying publisher.
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        self.publisher.get_message_stream(subscriber_name)
+    }
+}
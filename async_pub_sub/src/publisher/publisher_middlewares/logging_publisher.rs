@@ -1,11 +1,12 @@
-use std::{fmt::Display, pin::Pin};
+use std::pin::Pin;
 
 use futures::{future::BoxFuture, FutureExt, Stream};
 
-use crate::{Publisher, PublisherLayer, Result};
+use crate::{LogFormat, PublishOutcome, Publisher, PublisherLayer, Result};
 
 /// A publisher middleware layer that adds logging capabilities to any publisher.
-/// This layer will log all messages that are published through the publisher.
+/// This layer will log all messages that are published through the publisher, formatted
+/// with [`LogFormat`].
 pub struct LoggingPublisherLayer;
 
 /// Implementation of the PublisherLayer trait for LoggingPublisherLayer.
@@ -13,7 +14,7 @@ pub struct LoggingPublisherLayer;
 impl<P> PublisherLayer<P> for LoggingPublisherLayer
 where
     P: Publisher + Send + Sync,
-    P::Message: Display,
+    P::Message: LogFormat,
 {
     type PublisherType = LoggingPublisher<P>;
 
@@ -43,7 +44,7 @@ where
 impl<P> Publisher for LoggingPublisher<P>
 where
     P: Publisher,
-    P::Message: Display,
+    P::Message: LogFormat,
     Self: Sync,
 {
     type Message = P::Message;
@@ -54,22 +55,52 @@ where
     }
 
     /// Publishes a message and logs the operation with source publisher and destination subscriber
-    fn publish(&self, message: Self::Message) -> BoxFuture<Result<()>> {
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
         async move {
-            let message_str = format!("{}", &message);
-            let result = self.publisher.publish(message).await;
-            log::info!(
-                "[{}] -> [{}]: {}",
+            let message_str = message.format_published(
                 self.publisher.get_name(),
                 self.subscriber_name
                     .expect("subscriber name should be known"),
-                message_str
             );
+            let result = self.publisher.publish(message).await;
+            log::info!("{}", message_str);
             result
         }
         .boxed()
     }
 
+    /// Publishes a message without waiting and logs the operation with its outcome
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        let message_str = message.format_published(
+            self.publisher.get_name(),
+            self.subscriber_name
+                .expect("subscriber name should be known"),
+        );
+        let result = self.publisher.try_publish(message);
+        log::info!("{} ({:?})", message_str, result);
+        result
+    }
+
+    /// Closes the underlying publisher
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    /// Returns true if a subscriber is bound to the underlying publisher
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    /// Returns true once the underlying publisher is closed
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    /// Waits until the underlying publisher is closed
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
     /// Sets up a message stream for a subscriber and stores the subscriber's name for logging
     fn get_message_stream(
         &mut self,
@@ -0,0 +1,358 @@
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use futures::{future::BoxFuture, FutureExt, Stream};
+
+use crate::{Error, PublishOutcome, Publisher, PublisherLayer, Result};
+
+/// A publisher middleware layer that converts the published messages to the message
+/// type of the wrapped publisher.
+///
+/// The layer wraps a `Publisher<Message = B>` with a function `A -> B` and exposes a
+/// `Publisher<Message = A>`. This lets a service publish its own message type to a
+/// subscriber expecting another one, without a forwarder in between.
+///
+/// The mapped publisher only converts published messages: subscribers receive the
+/// converted messages from the wrapped publisher, so they must subscribe to it before
+/// it is layered. Subscribing to the mapped publisher itself fails with
+/// [`Error::NotSubscribable`].
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{MapPublisherLayer, Publisher, PublisherImpl, PublisherLayer, SubscriberImpl};
+/// use async_pub_sub_macros::route;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut publisher = PublisherImpl::<String>::new("publisher", 10);
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///
+///     route!(publisher -> subscriber).unwrap();
+///
+///     let publisher = MapPublisherLayer::new(|value: i32| value.to_string()).layer(publisher);
+///     publisher.publish(42).await.unwrap();
+///
+///     assert_eq!(subscriber.receive().await, "42");
+/// }
+/// ```
+pub struct MapPublisherLayer<F, A> {
+    /// The conversion applied to every published message
+    map: Arc<F>,
+    /// The type of the messages accepted by the mapped publishers
+    _message: PhantomData<fn(A)>,
+}
+
+impl<F, A> MapPublisherLayer<F, A> {
+    /// Creates a new mapping layer.
+    ///
+    /// # Arguments
+    /// * `map` - Converts the published messages to the message type of the wrapped publisher
+    pub fn new(map: F) -> Self {
+        Self {
+            map: Arc::new(map),
+            _message: PhantomData,
+        }
+    }
+}
+
+impl<F, A> Clone for MapPublisherLayer<F, A> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+            _message: PhantomData,
+        }
+    }
+}
+
+impl<P, F, A> PublisherLayer<P> for MapPublisherLayer<F, A>
+where
+    P: Publisher,
+    F: Fn(A) -> P::Message + Send + Sync + 'static,
+    A: Send + 'static,
+{
+    type PublisherType = MapPublisher<P, F, A>;
+
+    /// Creates a new `MapPublisher` by wrapping the provided publisher.
+    ///
+    /// # Arguments
+    /// * `publisher` - The underlying publisher receiving the converted messages
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        MapPublisher {
+            map: self.map.clone(),
+            publisher,
+            _message: PhantomData,
+        }
+    }
+}
+
+/// A publisher wrapper converting every published message before handing it to the
+/// underlying publisher.
+pub struct MapPublisher<P, F, A> {
+    /// The conversion applied to every published message
+    map: Arc<F>,
+    /// The underlying publisher being wrapped
+    publisher: P,
+    /// The type of the messages accepted by this publisher
+    _message: PhantomData<fn(A)>,
+}
+
+impl<P, F, A> Publisher for MapPublisher<P, F, A>
+where
+    P: Publisher,
+    F: Fn(A) -> P::Message + Send + Sync + 'static,
+    A: Send + 'static,
+{
+    type Message = A;
+
+    /// Returns the name of the underlying publisher
+    fn get_name(&self) -> &'static str {
+        self.publisher.get_name()
+    }
+
+    /// Converts the message and publishes it through the underlying publisher
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        self.publisher.publish((self.map)(message))
+    }
+
+    /// Converts the message and publishes it through the underlying publisher without waiting
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        self.publisher.try_publish((self.map)(message))
+    }
+
+    /// Closes the underlying publisher
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    /// Returns true if a subscriber is bound to the underlying publisher
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    /// Returns true once the underlying publisher is closed
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    /// Waits until the underlying publisher is closed
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
+    /// Not supported by MapPublisher: the underlying publisher carries the converted messages,
+    /// which cannot be turned back into the messages of this publisher.
+    ///
+    /// Subscribers must subscribe to the underlying publisher before it is layered.
+    ///
+    /// # Errors
+    /// Always returns [`Error::NotSubscribable`].
+    fn get_message_stream(
+        &mut self,
+        _subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        Err(Error::not_subscribable::<Self::Message>(
+            self.publisher.get_name(),
+        ))
+    }
+}
+
+/// A publisher middleware layer that converts the published messages to the message
+/// type of the wrapped publisher, dropping the ones that cannot be converted.
+///
+/// This is the fallible counterpart of [`MapPublisherLayer`]: the function `A -> Option<B>`
+/// returns `None` for the messages that must not be published. As with
+/// [`FilterPublisherLayer`](crate::FilterPublisherLayer), publishing them still succeeds
+/// and the number of dropped messages is available through
+/// [`FilterMapPublisherLayer::dropped_count`].
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{
+///     FilterMapPublisherLayer, Publisher, PublisherImpl, PublisherLayer, SubscriberImpl,
+/// };
+/// use async_pub_sub_macros::route;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut publisher = PublisherImpl::<i32>::new("publisher", 10);
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///
+///     route!(publisher -> subscriber).unwrap();
+///
+///     let layer = FilterMapPublisherLayer::new(|value: String| value.parse().ok());
+///     let publisher = layer.layer(publisher);
+///     publisher.publish("not a number".to_string()).await.unwrap();
+///     publisher.publish("42".to_string()).await.unwrap();
+///
+///     assert_eq!(subscriber.receive().await, 42);
+///     assert_eq!(layer.dropped_count(), 1);
+/// }
+/// ```
+pub struct FilterMapPublisherLayer<F, A> {
+    /// The conversion applied to every published message
+    filter_map: Arc<F>,
+    /// The number of messages dropped by every publisher built from this layer
+    dropped_count: Arc<AtomicU64>,
+    /// The type of the messages accepted by the mapped publishers
+    _message: PhantomData<fn(A)>,
+}
+
+impl<F, A> FilterMapPublisherLayer<F, A> {
+    /// Creates a new fallible mapping layer.
+    ///
+    /// # Arguments
+    /// * `filter_map` - Converts the published messages to the message type of the wrapped
+    ///   publisher, returns `None` for the messages to drop
+    pub fn new(filter_map: F) -> Self {
+        Self {
+            filter_map: Arc::new(filter_map),
+            dropped_count: Arc::new(AtomicU64::new(0)),
+            _message: PhantomData,
+        }
+    }
+
+    /// Returns how many messages were dropped by the publishers built from this layer.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+}
+
+impl<F, A> Clone for FilterMapPublisherLayer<F, A> {
+    fn clone(&self) -> Self {
+        Self {
+            filter_map: self.filter_map.clone(),
+            dropped_count: self.dropped_count.clone(),
+            _message: PhantomData,
+        }
+    }
+}
+
+impl<P, F, A> PublisherLayer<P> for FilterMapPublisherLayer<F, A>
+where
+    P: Publisher,
+    F: Fn(A) -> Option<P::Message> + Send + Sync + 'static,
+    A: Send + 'static,
+{
+    type PublisherType = FilterMapPublisher<P, F, A>;
+
+    /// Creates a new `FilterMapPublisher` by wrapping the provided publisher.
+    ///
+    /// # Arguments
+    /// * `publisher` - The underlying publisher receiving the converted messages
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        FilterMapPublisher {
+            layer: self.clone(),
+            dropped_count: AtomicU64::new(0),
+            publisher,
+        }
+    }
+}
+
+/// A publisher wrapper converting every published message before handing it to the
+/// underlying publisher, and dropping the ones that cannot be converted.
+pub struct FilterMapPublisher<P, F, A> {
+    /// The layer this publisher was built from, holding the conversion and the shared counter
+    layer: FilterMapPublisherLayer<F, A>,
+    /// The number of messages dropped by this publisher
+    dropped_count: AtomicU64,
+    /// The underlying publisher being wrapped
+    publisher: P,
+}
+
+impl<P, F, A> FilterMapPublisher<P, F, A>
+where
+    P: Publisher,
+    F: Fn(A) -> Option<P::Message>,
+{
+    /// Returns how many messages were dropped by this publisher.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+
+    /// Applies the conversion, counting the message if it is dropped.
+    fn convert(&self, message: A) -> Option<P::Message> {
+        let converted = (self.layer.filter_map)(message);
+        if converted.is_none() {
+            self.dropped_count.fetch_add(1, Ordering::Relaxed);
+            self.layer.dropped_count.fetch_add(1, Ordering::Relaxed);
+            log::debug!("[{}]: message filtered out", self.publisher.get_name());
+        }
+        converted
+    }
+}
+
+impl<P, F, A> Publisher for FilterMapPublisher<P, F, A>
+where
+    P: Publisher,
+    F: Fn(A) -> Option<P::Message> + Send + Sync + 'static,
+    A: Send + 'static,
+{
+    type Message = A;
+
+    /// Returns the name of the underlying publisher
+    fn get_name(&self) -> &'static str {
+        self.publisher.get_name()
+    }
+
+    /// Converts the message and publishes it through the underlying publisher; messages
+    /// that cannot be converted resolve successfully without being queued.
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        match self.convert(message) {
+            Some(message) => self.publisher.publish(message),
+            None => async { Ok(()) }.boxed(),
+        }
+    }
+
+    /// Converts the message and publishes it without waiting, reports
+    /// [`PublishOutcome::Filtered`] for messages that cannot be converted.
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        match self.convert(message) {
+            Some(message) => self.publisher.try_publish(message),
+            None => Ok(PublishOutcome::Filtered),
+        }
+    }
+
+    /// Closes the underlying publisher
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    /// Returns true if a subscriber is bound to the underlying publisher
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    /// Returns true once the underlying publisher is closed
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    /// Waits until the underlying publisher is closed
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
+    /// Not supported by FilterMapPublisher: the underlying publisher carries the converted messages,
+    /// which cannot be turned back into the messages of this publisher.
+    ///
+    /// Subscribers must subscribe to the underlying publisher before it is layered.
+    ///
+    /// # Errors
+    /// Always returns [`Error::NotSubscribable`].
+    fn get_message_stream(
+        &mut self,
+        _subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        Err(Error::not_subscribable::<Self::Message>(
+            self.publisher.get_name(),
+        ))
+    }
+}
@@ -0,0 +1,316 @@
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    time::Instant,
+};
+
+use futures::{future::BoxFuture, FutureExt, Stream, StreamExt};
+
+use crate::{
+    recorder::{
+        MetricsRecorder, MESSAGES_DROPPED, MESSAGES_PUBLISHED, PUBLISH_DURATION, PUBLISH_FAILURES,
+        QUEUE_DEPTH,
+    },
+    Error, PublishOutcome, Publisher, PublisherLayer, Result,
+};
+
+/// A publisher middleware layer reporting metrics about the published messages to a
+/// [`MetricsRecorder`].
+///
+/// The reported metrics are:
+/// * `async_pub_sub_messages_published_total` - counter of the messages handed to each
+///   subscriber, labelled by `publisher` and `subscriber`
+/// * `async_pub_sub_messages_dropped_total` - counter of the messages dropped because of
+///   the overflow policy of the underlying publisher, labelled by `publisher`
+/// * `async_pub_sub_publish_failures_total` - counter of the failed publications,
+///   labelled by `publisher` and `error`
+/// * `async_pub_sub_publish_duration_seconds` - histogram of the time spent publishing,
+///   labelled by `publisher`
+/// * `async_pub_sub_queue_depth` - gauge of the messages published but not received yet,
+///   labelled by `publisher` and `subscriber`
+///
+/// The queue depth is computed from the messages going through this layer, so it should
+/// be the innermost layer: messages discarded by an inner layer would never be received.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{
+///     MetricsPublisherLayer, PrometheusRecorder, Publisher, PublisherBuilder, PublisherImpl,
+///     SubscriberImpl,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let recorder = PrometheusRecorder::new();
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///     let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 10))
+///         .with_layer(MetricsPublisherLayer::new(recorder.clone()))
+///         .build();
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///     publisher.publish(1).await.unwrap();
+///     publisher.publish(2).await.unwrap();
+///     subscriber.receive().await;
+///
+///     assert!(recorder
+///         .render()
+///         .contains(r#"async_pub_sub_queue_depth{publisher="publisher",subscriber="subscriber"} 1"#));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MetricsPublisherLayer<R> {
+    /// The recorder receiving the metrics of every publisher built from this layer
+    recorder: R,
+}
+
+impl<R> MetricsPublisherLayer<R>
+where
+    R: MetricsRecorder,
+{
+    /// Creates a new metrics layer.
+    ///
+    /// # Arguments
+    /// * `recorder` - The recorder the metrics are reported to
+    pub fn new(recorder: R) -> Self {
+        Self { recorder }
+    }
+}
+
+impl<P, R> PublisherLayer<P> for MetricsPublisherLayer<R>
+where
+    P: Publisher + Send + Sync,
+    R: MetricsRecorder,
+{
+    type PublisherType = MetricsPublisher<P, R>;
+
+    /// Creates a new `MetricsPublisher` by wrapping the provided publisher.
+    ///
+    /// # Arguments
+    /// * `publisher` - The underlying publisher to report metrics for
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        MetricsPublisher {
+            recorder: self.recorder.clone(),
+            subscriptions: Arc::new(Mutex::new(Vec::new())),
+            publisher,
+        }
+    }
+}
+
+/// A publisher wrapper reporting metrics about the underlying publisher.
+pub struct MetricsPublisher<P, R> {
+    /// The recorder the metrics are reported to
+    recorder: R,
+    /// The subscribers bound through this publisher, with their queue depth
+    subscriptions: Subscriptions,
+    /// The underlying publisher being wrapped
+    publisher: P,
+}
+
+/// The subscribers bound through a [`MetricsPublisher`], with their queue depth.
+type Subscriptions = Arc<Mutex<Vec<(&'static str, Arc<AtomicI64>)>>>;
+
+fn lock(subscriptions: &Subscriptions) -> MutexGuard<'_, Vec<(&'static str, Arc<AtomicI64>)>> {
+    subscriptions
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The queue depth of a subscriber, removed from the subscriptions of the publisher when
+/// the message stream of the subscriber is dropped.
+struct SubscriptionDepth {
+    subscriptions: Subscriptions,
+    depth: Arc<AtomicI64>,
+}
+
+impl Drop for SubscriptionDepth {
+    fn drop(&mut self) {
+        lock(&self.subscriptions).retain(|(_, depth)| !Arc::ptr_eq(depth, &self.depth));
+    }
+}
+
+impl<P, R> MetricsPublisher<P, R>
+where
+    P: Publisher,
+    R: MetricsRecorder,
+{
+    /// Records the outcome of a publication.
+    fn record_outcome(&self, result: &Result<PublishOutcome>) {
+        match result {
+            Ok(PublishOutcome::Delivered) => self.record_published(),
+            Ok(PublishOutcome::DroppedOldest) => {
+                self.record_replaced();
+                self.record_dropped();
+            }
+            Ok(PublishOutcome::DroppedNewest) => self.record_dropped(),
+            Ok(PublishOutcome::Filtered) => {}
+            Err(error) => self.record_failure(error),
+        }
+    }
+
+    /// Records a message handed to every subscriber.
+    fn record_published(&self) {
+        let publisher = self.publisher.get_name();
+        for (subscriber, depth) in lock(&self.subscriptions).iter() {
+            let labels = [("publisher", publisher), ("subscriber", *subscriber)];
+            self.recorder
+                .increment_counter(MESSAGES_PUBLISHED, &labels, 1);
+            let depth = depth.fetch_add(1, Ordering::Relaxed) + 1;
+            self.recorder
+                .set_gauge(QUEUE_DEPTH, &labels, depth.max(0) as f64);
+        }
+    }
+
+    /// Records a message that replaced a queued one: the queue depth does not change.
+    fn record_replaced(&self) {
+        let publisher = self.publisher.get_name();
+        for (subscriber, _) in lock(&self.subscriptions).iter() {
+            self.recorder.increment_counter(
+                MESSAGES_PUBLISHED,
+                &[("publisher", publisher), ("subscriber", *subscriber)],
+                1,
+            );
+        }
+    }
+
+    fn record_dropped(&self) {
+        self.recorder.increment_counter(
+            MESSAGES_DROPPED,
+            &[("publisher", self.publisher.get_name())],
+            1,
+        );
+    }
+
+    fn record_failure(&self, error: &Error) {
+        self.recorder.increment_counter(
+            PUBLISH_FAILURES,
+            &[
+                ("publisher", self.publisher.get_name()),
+                ("error", error_label(error)),
+            ],
+            1,
+        );
+    }
+
+    fn record_duration(&self, start: Instant) {
+        self.recorder.record_histogram(
+            PUBLISH_DURATION,
+            &[("publisher", self.publisher.get_name())],
+            start.elapsed().as_secs_f64(),
+        );
+    }
+}
+
+/// Returns the value of the `error` label for a publication failure.
+fn error_label(error: &Error) -> &'static str {
+    match error {
+        Error::AlreadyBound { .. } => "already_bound",
+        Error::AlreadyForwarding { .. } => "already_forwarding",
+        Error::NotSubscribable { .. } => "not_subscribable",
+        Error::Shared { .. } => "shared",
+        Error::Disconnected { .. } => "disconnected",
+        Error::ChannelFull { .. } => "channel_full",
+        Error::Closed { .. } => "closed",
+        Error::Timeout { .. } => "timeout",
+        Error::ResponseDropped { .. } => "response_dropped",
+    }
+}
+
+impl<P, R> Publisher for MetricsPublisher<P, R>
+where
+    P: Publisher + Send + Sync,
+    R: MetricsRecorder,
+{
+    type Message = P::Message;
+
+    /// Returns the name of the underlying publisher
+    fn get_name(&self) -> &'static str {
+        self.publisher.get_name()
+    }
+
+    /// Publishes the message, recording its duration and outcome.
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        self.publish_with_outcome(message)
+            .map(|result| result.map(|_| ()))
+            .boxed()
+    }
+
+    /// Publishes the message without waiting, recording its duration and outcome.
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        let start = Instant::now();
+        let result = self.publisher.try_publish(message);
+        self.record_duration(start);
+        self.record_outcome(&result);
+        result
+    }
+
+    /// Publishes the message, recording its duration and outcome.
+    fn publish_with_outcome(
+        &self,
+        message: Self::Message,
+    ) -> BoxFuture<'_, Result<PublishOutcome>> {
+        let start = Instant::now();
+        self.publisher
+            .publish_with_outcome(message)
+            .map(move |result| {
+                self.record_duration(start);
+                self.record_outcome(&result);
+                result
+            })
+            .boxed()
+    }
+
+    /// Closes the underlying publisher
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    /// Returns true if a subscriber is bound to the underlying publisher
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    /// Returns true once the underlying publisher is closed
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    /// Waits until the underlying publisher is closed
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
+    /// Gets a message stream from the underlying publisher, decreasing the queue depth of
+    /// the subscriber each time it receives a message. The subscriber is no longer tracked
+    /// once the stream is dropped.
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        let stream = self.publisher.get_message_stream(subscriber_name)?;
+
+        let depth = Arc::new(AtomicI64::new(0));
+        lock(&self.subscriptions).push((subscriber_name, depth.clone()));
+        let subscription = SubscriptionDepth {
+            subscriptions: self.subscriptions.clone(),
+            depth,
+        };
+
+        let recorder = self.recorder.clone();
+        let publisher_name = self.publisher.get_name();
+        Ok(Box::pin(stream.inspect(move |_| {
+            let depth = subscription.depth.fetch_sub(1, Ordering::Relaxed) - 1;
+            recorder.set_gauge(
+                QUEUE_DEPTH,
+                &[
+                    ("publisher", publisher_name),
+                    ("subscriber", subscriber_name),
+                ],
+                depth.max(0) as f64,
+            );
+        })))
+    }
+}
@@ -1,7 +1,19 @@
 mod debug_publisher;
+mod envelope_publisher;
+mod filter_publisher;
 mod logging_publisher;
+mod map_publisher;
+mod metrics_publisher;
 mod publisher_builder;
+#[cfg(feature = "tracing")]
+mod tracing_publisher;
 
 pub use debug_publisher::DebuggingPublisherLayer;
+pub use envelope_publisher::EnvelopePublisherLayer;
+pub use filter_publisher::FilterPublisherLayer;
 pub use logging_publisher::LoggingPublisherLayer;
+pub use map_publisher::{FilterMapPublisherLayer, MapPublisherLayer};
+pub use metrics_publisher::MetricsPublisherLayer;
 pub use publisher_builder::PublisherBuilder;
+#[cfg(feature = "tracing")]
+pub use tracing_publisher::TracingPublisherLayer;
@@ -1,8 +1,10 @@
 use crate::{Publisher, PublisherLayer};
 
-// TODO: rework, take inspiration on the tower crate
 /// A builder pattern implementation for constructing a publisher with middleware layers.
 /// This struct allows for composing multiple middleware layers around a base publisher.
+///
+/// To apply the same layers to several publishers, build a [`LayerStack`](crate::LayerStack)
+/// once and pass it to [`PublisherBuilder::with_layer`].
 pub struct PublisherBuilder<P>
 where
     P: Publisher,
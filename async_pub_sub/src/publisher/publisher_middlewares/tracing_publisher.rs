@@ -0,0 +1,155 @@
+use std::pin::Pin;
+
+use futures::{future::BoxFuture, FutureExt, Stream};
+use tracing::Instrument;
+
+use crate::{PublishOutcome, Publisher, PublisherLayer, Result, Traceable};
+
+/// A publisher middleware layer propagating the current `tracing` span with the messages.
+///
+/// Publishing a message records the span it is published from in the message's
+/// [`crate::TraceContext`], and runs the publication inside a `publish` span. Subscribers
+/// resume the message inside a child span of the recorded span with
+/// [`crate::TraceContext::instrument`]; the servers generated by `rpc_interface` handle
+/// every request that way, so that a request is traced from the client to the server.
+///
+/// The messages must be [`Traceable`]: requests and `rpc_interface` messages are, other
+/// messages can be wrapped in a [`crate::Traced`] message.
+///
+/// Available with the `tracing` feature.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{
+///     Publisher, PublisherBuilder, PublisherImpl, SubscriberImpl, Traceable, Traced,
+///     TracingPublisherLayer,
+/// };
+/// use tracing::Instrument;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///     let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 10))
+///         .with_layer(TracingPublisherLayer)
+///         .build();
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     publisher
+///         .publish(Traced::new(42))
+///         .instrument(tracing::info_span!("producer"))
+///         .await
+///         .unwrap();
+///
+///     let message = subscriber.receive().await;
+///     let value = message
+///         .trace_context()
+///         .instrument("subscriber", async { message.message })
+///         .await;
+///     assert_eq!(value, 42);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TracingPublisherLayer;
+
+impl<P> PublisherLayer<P> for TracingPublisherLayer
+where
+    P: Publisher + Send + Sync,
+    P::Message: Traceable,
+{
+    type PublisherType = TracingPublisher<P>;
+
+    /// Creates a new `TracingPublisher` by wrapping the provided publisher.
+    ///
+    /// # Arguments
+    /// * `publisher` - The underlying publisher to trace messages for
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        TracingPublisher { publisher }
+    }
+}
+
+/// A publisher wrapper recording the current span in the published messages.
+pub struct TracingPublisher<P> {
+    /// The underlying publisher being wrapped
+    publisher: P,
+}
+
+impl<P> TracingPublisher<P>
+where
+    P: Publisher,
+    P::Message: Traceable,
+{
+    /// Records the current span in the message and creates the span of its publication.
+    fn trace(&self, message: &mut P::Message) -> tracing::Span {
+        message
+            .trace_context_mut()
+            .set_span(tracing::Span::current());
+
+        tracing::info_span!(
+            "publish",
+            publisher = self.publisher.get_name(),
+            message = std::any::type_name::<P::Message>()
+        )
+    }
+}
+
+impl<P> Publisher for TracingPublisher<P>
+where
+    P: Publisher + Send + Sync,
+    P::Message: Traceable,
+{
+    type Message = P::Message;
+
+    /// Returns the name of the underlying publisher
+    fn get_name(&self) -> &'static str {
+        self.publisher.get_name()
+    }
+
+    /// Publishes the message along with the current span, inside a `publish` span.
+    ///
+    /// The current span is the one the returned future is polled in.
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        async move {
+            let mut message = message;
+            let span = self.trace(&mut message);
+            self.publisher.publish(message).instrument(span).await
+        }
+        .boxed()
+    }
+
+    /// Publishes the message along with the current span, without waiting.
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        let mut message = message;
+        let span = self.trace(&mut message);
+        span.in_scope(|| self.publisher.try_publish(message))
+    }
+
+    /// Closes the underlying publisher
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    /// Returns true if a subscriber is bound to the underlying publisher
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    /// Returns true once the underlying publisher is closed
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    /// Waits until the underlying publisher is closed
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
+    /// Gets a message stream from the underlying publisher.
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        self.publisher.get_message_stream(subscriber_name)
+    }
+}
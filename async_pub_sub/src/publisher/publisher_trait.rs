@@ -1,8 +1,9 @@
 use std::pin::Pin;
 
-use futures::{future::BoxFuture, Stream};
+use futures::{future::BoxFuture, FutureExt, Stream};
 
-use crate::Result;
+use super::PublishOutcome;
+use crate::{Error, Result};
 
 /// A trait for types that can publish messages to subscribers.
 ///
@@ -25,7 +26,79 @@ pub trait Publisher {
     ///
     /// # Returns
     /// A future that resolves to a Result indicating success or failure of the publish operation.
-    fn publish(&self, message: Self::Message) -> BoxFuture<Result<()>>;
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>>;
+
+    /// Publishes a message without waiting for room in the subscriber's queue.
+    ///
+    /// The default implementation polls [`Publisher::publish`] once and reports
+    /// [`Error::ChannelFull`] if it could not complete immediately. Publishers with an
+    /// [`OverflowPolicy`](crate::OverflowPolicy) apply it instead.
+    ///
+    /// # Arguments
+    /// * `message` - The message to publish
+    ///
+    /// # Returns
+    /// A Result containing the outcome of the operation, telling whether a message was dropped.
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        match self.publish(message).now_or_never() {
+            Some(result) => result.map(|_| PublishOutcome::Delivered),
+            None => Err(Error::channel_full::<Self::Message>(self.get_name(), None)),
+        }
+    }
+
+    /// Publishes a message like [`Publisher::publish`], reporting whether a message was
+    /// dropped or filtered out.
+    ///
+    /// The default implementation reports [`PublishOutcome::Delivered`] whenever
+    /// [`Publisher::publish`] succeeds, for publishers that never drop messages.
+    ///
+    /// # Arguments
+    /// * `message` - The message to publish
+    ///
+    /// # Returns
+    /// A future that resolves to the outcome of the operation.
+    fn publish_with_outcome(
+        &self,
+        message: Self::Message,
+    ) -> BoxFuture<'_, Result<PublishOutcome>> {
+        self.publish(message)
+            .map(|result| result.map(|_| PublishOutcome::Delivered))
+            .boxed()
+    }
+
+    /// Closes the publisher.
+    ///
+    /// Messages already queued are still delivered, then the subscribers' streams end.
+    /// Publishing to a closed publisher fails with [`Error::Closed`]. The default
+    /// implementation does nothing, for publishers that do not own a channel.
+    fn close(&self) {}
+
+    /// Returns true if a subscriber is currently bound to the publisher.
+    ///
+    /// The default implementation returns true, for publishers that cannot tell.
+    fn is_bound(&self) -> bool {
+        true
+    }
+
+    /// Returns true once publishing cannot succeed anymore: the subscriber has gone away
+    /// or the publisher has been closed.
+    ///
+    /// The default implementation returns false, for publishers that cannot tell.
+    fn is_closed(&self) -> bool {
+        false
+    }
+
+    /// Waits until the publisher is closed, see [`Publisher::is_closed`].
+    ///
+    /// Producers can select on this future to stop as soon as nobody listens anymore.
+    /// The default implementation never resolves, for publishers that cannot tell.
+    ///
+    /// # Returns
+    /// A future that resolves once the subscriber has gone away or the publisher has been
+    /// closed.
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        futures::future::pending().boxed()
+    }
 
     /// Creates a new message stream for a subscriber.
     ///
@@ -51,10 +124,37 @@ where
         (**self).get_name()
     }
 
-    fn publish(&self, message: Self::Message) -> BoxFuture<Result<()>> {
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
         (**self).publish(message)
     }
 
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        (**self).try_publish(message)
+    }
+
+    fn publish_with_outcome(
+        &self,
+        message: Self::Message,
+    ) -> BoxFuture<'_, Result<PublishOutcome>> {
+        (**self).publish_with_outcome(message)
+    }
+
+    fn close(&self) {
+        (**self).close()
+    }
+
+    fn is_bound(&self) -> bool {
+        (**self).is_bound()
+    }
+
+    fn is_closed(&self) -> bool {
+        (**self).is_closed()
+    }
+
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        (**self).closed()
+    }
+
     fn get_message_stream(
         &mut self,
         subscriber_name: &'static str,
@@ -119,10 +219,52 @@ where
     ///
     /// # Returns
     /// A future that resolves to a Result indicating success or failure of the publish operation.
-    fn publish(&self, message: Message) -> futures::future::BoxFuture<Result<()>> {
+    fn publish(&self, message: Message) -> futures::future::BoxFuture<'_, Result<()>> {
         Publisher::publish(self.get_publisher(), message)
     }
 
+    /// Publishes a message using the wrapped publisher without waiting.
+    ///
+    /// # Arguments
+    /// * `message` - The message to publish
+    ///
+    /// # Returns
+    /// A Result containing the outcome of the operation, telling whether a message was dropped.
+    fn try_publish(&self, message: Message) -> Result<PublishOutcome> {
+        Publisher::try_publish(self.get_publisher(), message)
+    }
+
+    /// Publishes a message using the wrapped publisher, reporting its outcome.
+    ///
+    /// # Arguments
+    /// * `message` - The message to publish
+    ///
+    /// # Returns
+    /// A future that resolves to the outcome of the operation.
+    fn publish_with_outcome(&self, message: Message) -> BoxFuture<'_, Result<PublishOutcome>> {
+        Publisher::publish_with_outcome(self.get_publisher(), message)
+    }
+
+    /// Closes the wrapped publisher.
+    fn close(&self) {
+        Publisher::close(self.get_publisher())
+    }
+
+    /// Returns true if a subscriber is currently bound to the wrapped publisher.
+    fn is_bound(&self) -> bool {
+        Publisher::is_bound(self.get_publisher())
+    }
+
+    /// Returns true once the wrapped publisher is closed.
+    fn is_closed(&self) -> bool {
+        Publisher::is_closed(self.get_publisher())
+    }
+
+    /// Waits until the wrapped publisher is closed.
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        Publisher::closed(self.get_publisher())
+    }
+
     /// Creates a new message stream using the wrapped publisher.
     ///
     /// # Arguments
@@ -1,4 +1,73 @@
-use std::fmt::{Debug, Display};
+use std::{
+    fmt::{Debug, Display},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::FutureExt;
+
+use crate::{
+    utils::channel::{FullQueue, TrySent},
+    Error, Result, TraceContext, Traceable,
+};
+
+/// Defines what a publisher does with a message when the subscriber's queue is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// `publish` waits until the subscriber makes room; `try_publish` fails with
+    /// [`Error::ChannelFull`].
+    #[default]
+    Block,
+    /// The new message is discarded.
+    DropNewest,
+    /// The oldest queued message is evicted to make room for the new one.
+    DropOldest,
+    /// The publish operation fails with [`Error::ChannelFull`].
+    Error,
+}
+
+/// The outcome of a successful publish operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublishOutcome {
+    /// The message was queued without dropping anything.
+    Delivered,
+    /// The queue was full and the new message was discarded.
+    DroppedNewest,
+    /// The queue was full and the oldest queued message was evicted to make room.
+    DroppedOldest,
+    /// The message was rejected by a filter and never queued.
+    Filtered,
+}
+
+impl OverflowPolicy {
+    /// Returns how a non-blocking send handles a full queue under this policy.
+    pub(crate) fn full_queue(&self) -> FullQueue {
+        match self {
+            OverflowPolicy::Block | OverflowPolicy::Error => FullQueue::Reject,
+            OverflowPolicy::DropNewest => FullQueue::DropNewest,
+            OverflowPolicy::DropOldest => FullQueue::DropOldest,
+        }
+    }
+}
+
+impl From<TrySent> for PublishOutcome {
+    fn from(sent: TrySent) -> Self {
+        match sent {
+            TrySent::Queued => PublishOutcome::Delivered,
+            TrySent::DroppedNewest => PublishOutcome::DroppedNewest,
+            TrySent::DroppedOldest => PublishOutcome::DroppedOldest,
+        }
+    }
+}
+
+impl PublishOutcome {
+    /// Returns true if a message was dropped to complete the operation.
+    pub fn is_dropped(&self) -> bool {
+        !matches!(self, PublishOutcome::Delivered)
+    }
+}
 
 /// A request structure that represents a request-response pattern for asynchronous communication.
 ///
@@ -15,6 +84,12 @@ use std::fmt::{Debug, Display};
 /// * `content` - The actual request content
 /// * `response_sender` - A oneshot channel sender for sending the response
 ///
+/// Cloning a request clones its content, and the clones share the same response sender:
+/// only the first response sent reaches the requester. This lets middlewares such as
+/// retry layers publish a request again after a failed attempt.
+///
+/// A request also carries the [`TraceContext`] it was published in, see [`Traceable`].
+///
 /// # Examples
 ///
 /// ```
@@ -33,7 +108,8 @@ where
     Rsp: Debug,
 {
     pub content: Req,
-    pub response_sender: futures::channel::oneshot::Sender<Rsp>,
+    pub response_sender: ResponseSender<Rsp>,
+    trace_context: TraceContext,
 }
 
 impl<Req, Rsp> Request<Req, Rsp>
@@ -41,21 +117,70 @@ where
     Req: Debug,
     Rsp: Debug,
 {
-    pub fn new(content: Req) -> (Self, futures::channel::oneshot::Receiver<Rsp>) {
+    pub fn new(content: Req) -> (Self, Response<Rsp>) {
         let (response_sender, response_receiver) = futures::channel::oneshot::channel();
         (
             Self {
                 content,
-                response_sender,
+                response_sender: ResponseSender {
+                    sender: Arc::new(Mutex::new(Some(response_sender))),
+                },
+                trace_context: TraceContext::default(),
+            },
+            Response {
+                request_type: std::any::type_name::<Req>(),
+                receiver: response_receiver,
             },
-            response_receiver,
         )
     }
 
+    /// Sends the response to the requester.
+    ///
+    /// The response is discarded if the requester is gone, e.g. because its call timed out,
+    /// or if a clone of the request already responded.
     pub fn respond(self, response: Rsp) {
-        self.response_sender
-            .send(response)
-            .expect("failed to send response");
+        if self.response_sender.send(response).is_err() {
+            log::debug!(
+                "response to {} request discarded: the requester is gone",
+                std::any::type_name::<Req>()
+            );
+        }
+    }
+
+    /// Returns true once the requester stopped waiting for the response, or once a clone
+    /// of the request responded.
+    ///
+    /// A server can check it to skip a request whose response nobody is waiting for.
+    pub fn is_cancelled(&self) -> bool {
+        self.response_sender.is_cancelled()
+    }
+}
+
+impl<Req, Rsp> Clone for Request<Req, Rsp>
+where
+    Req: Clone + Debug,
+    Rsp: Debug,
+{
+    fn clone(&self) -> Self {
+        Self {
+            content: self.content.clone(),
+            response_sender: self.response_sender.clone(),
+            trace_context: self.trace_context.clone(),
+        }
+    }
+}
+
+impl<Req, Rsp> Traceable for Request<Req, Rsp>
+where
+    Req: Debug,
+    Rsp: Debug,
+{
+    fn trace_context(&self) -> &TraceContext {
+        &self.trace_context
+    }
+
+    fn trace_context_mut(&mut self) -> &mut TraceContext {
+        &mut self.trace_context
     }
 }
 
@@ -84,3 +209,82 @@ where
         write!(f, "inputs: {:?}", self.content)
     }
 }
+
+/// The sending side of the response of a [`Request`], shared by the clones of the request.
+pub struct ResponseSender<Rsp> {
+    sender: Arc<Mutex<Option<futures::channel::oneshot::Sender<Rsp>>>>,
+}
+
+impl<Rsp> ResponseSender<Rsp> {
+    /// Sends the response to the requester.
+    ///
+    /// # Errors
+    ///
+    /// Gives the response back if the requester is gone, or if a clone of the request
+    /// already responded.
+    pub fn send(self, response: Rsp) -> core::result::Result<(), Rsp> {
+        let sender = self
+            .sender
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+
+        match sender {
+            Some(sender) => sender.send(response),
+            None => Err(response),
+        }
+    }
+
+    /// Returns true once the requester is gone, or once a clone of the request responded.
+    pub fn is_cancelled(&self) -> bool {
+        self.sender
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_ref()
+            .is_none_or(|sender| sender.is_canceled())
+    }
+}
+
+impl<Rsp> Clone for ResponseSender<Rsp> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<Rsp> Debug for ResponseSender<Rsp> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ResponseSender({})", std::any::type_name::<Rsp>())
+    }
+}
+
+/// The receiving side of a [`Request`], resolving to the response once it is sent.
+///
+/// # Errors
+///
+/// Resolves to [`Error::ResponseDropped`] if the request is dropped without a response.
+pub struct Response<Rsp> {
+    request_type: &'static str,
+    receiver: futures::channel::oneshot::Receiver<Rsp>,
+}
+
+impl<Rsp> Future for Response<Rsp> {
+    type Output = Result<Rsp>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let request_type = self.request_type;
+        self.receiver
+            .poll_unpin(cx)
+            .map_err(|_| Error::ResponseDropped {
+                request_type,
+                response_type: std::any::type_name::<Rsp>(),
+            })
+    }
+}
+
+impl<Rsp> Debug for Response<Rsp> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Response({})", std::any::type_name::<Rsp>())
+    }
+}
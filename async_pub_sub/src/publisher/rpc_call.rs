@@ -0,0 +1,91 @@
+use std::{future::Future, time::Duration};
+
+use futures::{
+    future::{BoxFuture, Either},
+    pin_mut, FutureExt,
+};
+
+use super::Response;
+use crate::{Result, RpcError};
+
+/// Publishes an RPC request and waits for its response, until an optional deadline.
+///
+/// This is the call used by the clients generated by `rpc_interface`. The deadline covers
+/// both the publication of the request and the wait for its response. Once it elapses the
+/// response is dropped, which cancels the request: the server skips it if it did not start
+/// handling it yet, see [`crate::Request::is_cancelled`].
+///
+/// The deadline is any future resolving once the call must give up, so that it can be
+/// driven by the clock of the runtime, e.g. `tokio::time::sleep`, or by [`rpc_sleep`].
+///
+/// # Arguments
+/// * `method` - The name of the called method, used in the reported errors
+/// * `publish` - The publication of the request
+/// * `response` - The response of the published request
+/// * `deadline` - Resolves when the call times out, `None` to wait without a time limit
+///
+/// # Errors
+/// * [`RpcError::Publish`] if the request could not be published
+/// * [`RpcError::ServerDropped`] if the server dropped the request without responding
+/// * [`RpcError::Timeout`] if the deadline elapsed
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use async_pub_sub::{rpc_call, Publisher, PublisherImpl, Request, RpcError, SubscriberImpl};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut publisher = PublisherImpl::new("client", 10);
+///     let mut subscriber = SubscriberImpl::<Request<i32, i32>>::new("server");
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     // The server never responds.
+///     let (request, response) = Request::new(41);
+///     let deadline = Some(tokio::time::sleep(Duration::from_millis(10)));
+///     let result = rpc_call("add_one", publisher.publish(request), response, deadline).await;
+///
+///     assert_eq!(result, Err(RpcError::Timeout { method: "add_one" }));
+///     assert!(subscriber.receive().await.is_cancelled());
+/// }
+/// ```
+pub async fn rpc_call<Rsp>(
+    method: &'static str,
+    publish: impl Future<Output = Result<()>>,
+    response: Response<Rsp>,
+    deadline: Option<impl Future<Output = ()>>,
+) -> core::result::Result<Rsp, RpcError> {
+    let call = async move {
+        publish
+            .await
+            .map_err(|error| RpcError::publish_failed(method, error))?;
+        response
+            .await
+            .map_err(|error| RpcError::response_failed(method, error))
+    };
+
+    let Some(deadline) = deadline else {
+        return call.await;
+    };
+
+    pin_mut!(call);
+    pin_mut!(deadline);
+    match futures::future::select(call, deadline).await {
+        Either::Left((result, _)) => result,
+        Either::Right(((), _)) => Err(RpcError::Timeout { method }),
+    }
+}
+
+/// Returns a future resolving once the given duration elapsed, independently of any runtime.
+///
+/// This is the default timer of the clients generated by `rpc_interface`. It runs on the
+/// system clock, so it ignores a paused or mocked runtime clock: clients running on such a
+/// runtime override `rpc_sleep` with the timer of the runtime.
+///
+/// # Arguments
+/// * `duration` - How long to wait
+pub fn rpc_sleep(duration: Duration) -> BoxFuture<'static, ()> {
+    futures_timer::Delay::new(duration).boxed()
+}
@@ -0,0 +1,227 @@
+use std::{
+    fmt::Debug,
+    pin::{pin, Pin},
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::{
+    channel::{mpsc, oneshot},
+    future::{self, Either},
+    SinkExt, Stream, StreamExt,
+};
+
+use crate::{TraceContext, Traceable};
+
+/// A request whose response is a stream of items, sent through a bounded channel.
+///
+/// This is the request of the streaming methods of the interfaces generated by
+/// `rpc_interface`, i.e. the methods returning `impl Stream<Item = T>`. The server forwards
+/// the items of its stream with [`StreamRequest::forward`] until the stream ends or the
+/// requester drops its [`ResponseStream`].
+///
+/// Like [`crate::Request`], cloning a stream request clones its content, and the clones
+/// share the same response sender: only the first forwarded stream reaches the requester.
+///
+/// # Examples
+///
+/// ```
+/// use async_pub_sub::{futures::StreamExt, StreamRequest};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let (request, responses) = StreamRequest::new(3, 10);
+///     let count = request.content;
+///     request
+///         .forward(async_pub_sub::futures::stream::iter(0..count))
+///         .await;
+///
+///     assert_eq!(responses.collect::<Vec<_>>().await, vec![0, 1, 2]);
+/// }
+/// ```
+pub struct StreamRequest<Req, Item>
+where
+    Req: Debug,
+{
+    pub content: Req,
+    pub response_sender: StreamSender<Item>,
+    trace_context: TraceContext,
+}
+
+impl<Req, Item> StreamRequest<Req, Item>
+where
+    Req: Debug,
+{
+    /// Creates a stream request and the stream of its responses.
+    ///
+    /// # Arguments
+    /// * `content` - The content of the request
+    /// * `buffer_size` - How many items the server can send ahead of the requester
+    pub fn new(content: Req, buffer_size: usize) -> (Self, ResponseStream<Item>) {
+        let (items_sender, items) = mpsc::channel(buffer_size);
+        let (cancellation_sender, cancellation) = oneshot::channel();
+        (
+            Self {
+                content,
+                response_sender: StreamSender {
+                    channel: Arc::new(Mutex::new(Some(StreamChannel {
+                        items: items_sender,
+                        cancellation: cancellation_sender,
+                    }))),
+                },
+                trace_context: TraceContext::default(),
+            },
+            ResponseStream {
+                items,
+                _cancellation: cancellation,
+            },
+        )
+    }
+
+    /// Forwards the items of the given stream to the requester, see [`StreamSender::forward`].
+    pub async fn forward(self, stream: impl Stream<Item = Item>) {
+        self.response_sender.forward(stream).await
+    }
+
+    /// Returns true once the requester dropped its response stream, or once a clone of the
+    /// request forwarded a stream.
+    pub fn is_cancelled(&self) -> bool {
+        self.response_sender.is_cancelled()
+    }
+}
+
+impl<Req, Item> Clone for StreamRequest<Req, Item>
+where
+    Req: Clone + Debug,
+{
+    fn clone(&self) -> Self {
+        Self {
+            content: self.content.clone(),
+            response_sender: self.response_sender.clone(),
+            trace_context: self.trace_context.clone(),
+        }
+    }
+}
+
+impl<Req, Item> Traceable for StreamRequest<Req, Item>
+where
+    Req: Debug,
+{
+    fn trace_context(&self) -> &TraceContext {
+        &self.trace_context
+    }
+
+    fn trace_context_mut(&mut self) -> &mut TraceContext {
+        &mut self.trace_context
+    }
+}
+
+impl<Req, Item> Debug for StreamRequest<Req, Item>
+where
+    Req: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "inputs: {:?}", self.content)
+    }
+}
+
+/// The channel of a [`StreamRequest`], with the signal of its requester leaving.
+struct StreamChannel<Item> {
+    items: mpsc::Sender<Item>,
+    /// Canceled once the [`ResponseStream`] is dropped
+    cancellation: oneshot::Sender<()>,
+}
+
+/// The sending side of the responses of a [`StreamRequest`], shared by the clones of the
+/// request.
+pub struct StreamSender<Item> {
+    channel: Arc<Mutex<Option<StreamChannel<Item>>>>,
+}
+
+impl<Item> StreamSender<Item> {
+    /// Forwards the items of the given stream to the requester.
+    ///
+    /// Forwarding waits for the requester to make room in the channel, and stops once the
+    /// stream ends or the requester drops its [`ResponseStream`], even while the stream is
+    /// waiting for its next item. Nothing is forwarded if a clone of the request already
+    /// forwarded a stream.
+    ///
+    /// # Arguments
+    /// * `stream` - The items to send to the requester
+    pub async fn forward(self, stream: impl Stream<Item = Item>) {
+        let channel = self
+            .channel
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        let Some(StreamChannel {
+            mut items,
+            mut cancellation,
+        }) = channel
+        else {
+            log::debug!("stream response discarded: the request already got one");
+            return;
+        };
+
+        let mut stream = pin!(stream);
+        let forwarding = pin!(async move {
+            while let Some(item) = stream.next().await {
+                if items.send(item).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        if let Either::Right(_) = future::select(forwarding, cancellation.cancellation()).await {
+            log::debug!("stream response stopped: the requester is gone");
+        }
+    }
+
+    /// Returns true once the requester dropped its response stream, or once a clone of the
+    /// request forwarded a stream.
+    pub fn is_cancelled(&self) -> bool {
+        self.channel
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_ref()
+            .is_none_or(|channel| channel.cancellation.is_canceled())
+    }
+}
+
+impl<Item> Clone for StreamSender<Item> {
+    fn clone(&self) -> Self {
+        Self {
+            channel: self.channel.clone(),
+        }
+    }
+}
+
+impl<Item> Debug for StreamSender<Item> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StreamSender({})", std::any::type_name::<Item>())
+    }
+}
+
+/// The receiving side of a [`StreamRequest`], yielding the items forwarded by the server.
+///
+/// The stream ends once the server is done forwarding, or if it drops the request. Dropping
+/// the stream stops the server from forwarding.
+pub struct ResponseStream<Item> {
+    items: mpsc::Receiver<Item>,
+    /// Cancels the forwarding of the server once dropped
+    _cancellation: oneshot::Receiver<()>,
+}
+
+impl<Item> Stream for ResponseStream<Item> {
+    type Item = Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.items.poll_next_unpin(cx)
+    }
+}
+
+impl<Item> Debug for ResponseStream<Item> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ResponseStream({})", std::any::type_name::<Item>())
+    }
+}
@@ -0,0 +1,136 @@
+use std::future::Future;
+
+/// The tracing context a message was published in, carried along with the message.
+///
+/// The context stays empty unless the `tracing` feature is enabled and the message goes
+/// through a `TracingPublisherLayer`, which records the span the message is published from.
+/// On the subscriber side, [`TraceContext::instrument`] resumes the handling of the message
+/// inside a child span of that span, so that a trace follows the message across the channel.
+#[derive(Debug, Clone, Default)]
+pub struct TraceContext {
+    #[cfg(feature = "tracing")]
+    span: Option<tracing::Span>,
+}
+
+impl TraceContext {
+    /// Returns the span the message was published from, if it was recorded.
+    #[cfg(feature = "tracing")]
+    pub fn span(&self) -> Option<&tracing::Span> {
+        self.span.as_ref()
+    }
+
+    /// Records the span the message is published from.
+    #[cfg(feature = "tracing")]
+    pub fn set_span(&mut self, span: tracing::Span) {
+        self.span = Some(span);
+    }
+
+    /// Runs `future` inside a `receive` span, child of the span the message was published
+    /// from. The future runs unchanged when no span was recorded.
+    ///
+    /// # Arguments
+    /// * `subscriber` - The name of the subscriber handling the message
+    /// * `future` - The handling of the message
+    #[cfg(feature = "tracing")]
+    pub fn instrument<F>(
+        &self,
+        subscriber: &'static str,
+        future: F,
+    ) -> impl Future<Output = F::Output>
+    where
+        F: Future,
+    {
+        use tracing::Instrument;
+
+        let span = match &self.span {
+            Some(parent) => tracing::info_span!(parent: parent, "receive", subscriber),
+            None => tracing::Span::none(),
+        };
+        future.instrument(span)
+    }
+
+    /// Runs `future` unchanged, tracing is disabled.
+    ///
+    /// # Arguments
+    /// * `subscriber` - The name of the subscriber handling the message
+    /// * `future` - The handling of the message
+    #[cfg(not(feature = "tracing"))]
+    pub fn instrument<F>(
+        &self,
+        subscriber: &'static str,
+        future: F,
+    ) -> impl Future<Output = F::Output>
+    where
+        F: Future,
+    {
+        let _ = subscriber;
+        future
+    }
+}
+
+/// Messages carrying a [`TraceContext`].
+///
+/// [`crate::Request`] and the messages generated by `rpc_interface` are traceable. Other
+/// messages can be wrapped in a `Traced` message, available with the `tracing` feature.
+pub trait Traceable {
+    /// Returns the tracing context of the message.
+    fn trace_context(&self) -> &TraceContext;
+
+    /// Returns the tracing context of the message, to record the span it is published from.
+    fn trace_context_mut(&mut self) -> &mut TraceContext;
+}
+
+/// A message wrapper carrying the [`TraceContext`] of a message that cannot carry it itself.
+///
+/// Available with the `tracing` feature.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone)]
+pub struct Traced<M> {
+    /// The wrapped message
+    pub message: M,
+    trace_context: TraceContext,
+}
+
+#[cfg(feature = "tracing")]
+impl<M> Traced<M> {
+    /// Wraps a message with an empty tracing context.
+    pub fn new(message: M) -> Self {
+        Self {
+            message,
+            trace_context: TraceContext::default(),
+        }
+    }
+
+    /// Unwraps the message, dropping its tracing context.
+    pub fn into_inner(self) -> M {
+        self.message
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<M> From<M> for Traced<M> {
+    fn from(message: M) -> Self {
+        Self::new(message)
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<M> std::fmt::Display for Traced<M>
+where
+    M: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<M> Traceable for Traced<M> {
+    fn trace_context(&self) -> &TraceContext {
+        &self.trace_context
+    }
+
+    fn trace_context_mut(&mut self) -> &mut TraceContext {
+        &mut self.trace_context
+    }
+}
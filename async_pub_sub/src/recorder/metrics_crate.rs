@@ -0,0 +1,48 @@
+use metrics::Label;
+
+use super::MetricsRecorder;
+
+/// A [`MetricsRecorder`] forwarding to the [`metrics`] crate facade, so that the metrics
+/// reach whatever exporter is installed as the global `metrics` recorder.
+///
+/// Available with the `metrics` feature.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsCrateRecorder;
+
+impl MetricsCrateRecorder {
+    /// Creates a recorder forwarding to the global `metrics` recorder.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+fn to_labels(labels: &[(&'static str, &'static str)]) -> Vec<Label> {
+    labels
+        .iter()
+        .map(|&(name, value)| Label::from_static_parts(name, value))
+        .collect()
+}
+
+impl MetricsRecorder for MetricsCrateRecorder {
+    fn increment_counter(
+        &self,
+        name: &'static str,
+        labels: &[(&'static str, &'static str)],
+        value: u64,
+    ) {
+        metrics::counter!(name, to_labels(labels)).increment(value);
+    }
+
+    fn set_gauge(&self, name: &'static str, labels: &[(&'static str, &'static str)], value: f64) {
+        metrics::gauge!(name, to_labels(labels)).set(value);
+    }
+
+    fn record_histogram(
+        &self,
+        name: &'static str,
+        labels: &[(&'static str, &'static str)],
+        value: f64,
+    ) {
+        metrics::histogram!(name, to_labels(labels)).record(value);
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(feature = "metrics")]
+mod metrics_crate;
+mod prometheus;
+
+#[cfg(feature = "metrics")]
+pub use metrics_crate::MetricsCrateRecorder;
+pub use prometheus::PrometheusRecorder;
+
+/// The number of messages handed to a subscriber, labelled by `publisher` and `subscriber`.
+pub(crate) const MESSAGES_PUBLISHED: &str = "async_pub_sub_messages_published_total";
+/// The number of messages dropped by a publisher, labelled by `publisher`.
+pub(crate) const MESSAGES_DROPPED: &str = "async_pub_sub_messages_dropped_total";
+/// The number of failed publications, labelled by `publisher` and `error`.
+pub(crate) const PUBLISH_FAILURES: &str = "async_pub_sub_publish_failures_total";
+/// The time spent publishing a message in seconds, labelled by `publisher`.
+pub(crate) const PUBLISH_DURATION: &str = "async_pub_sub_publish_duration_seconds";
+/// The number of messages waiting to be received, labelled by `publisher` and `subscriber`.
+pub(crate) const QUEUE_DEPTH: &str = "async_pub_sub_queue_depth";
+/// The number of messages received by a subscriber, labelled by `subscriber`.
+pub(crate) const MESSAGES_RECEIVED: &str = "async_pub_sub_messages_received_total";
+
+/// A sink for the metrics reported by [`crate::MetricsPublisherLayer`] and
+/// [`crate::MetricsSubscriberLayer`].
+///
+/// Labels are given as `(name, value)` pairs. Recorders are cloned into every publisher
+/// and subscriber built from a layer, so clones are expected to share their storage.
+pub trait MetricsRecorder: Clone + Send + Sync + 'static {
+    /// Adds `value` to a counter.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the counter
+    /// * `labels` - The labels identifying the counter
+    /// * `value` - The amount to add
+    fn increment_counter(
+        &self,
+        name: &'static str,
+        labels: &[(&'static str, &'static str)],
+        value: u64,
+    );
+
+    /// Sets the current value of a gauge.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the gauge
+    /// * `labels` - The labels identifying the gauge
+    /// * `value` - The new value
+    fn set_gauge(&self, name: &'static str, labels: &[(&'static str, &'static str)], value: f64);
+
+    /// Records an observation in a histogram.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the histogram
+    /// * `labels` - The labels identifying the histogram
+    /// * `value` - The observed value
+    fn record_histogram(
+        &self,
+        name: &'static str,
+        labels: &[(&'static str, &'static str)],
+        value: f64,
+    );
+}
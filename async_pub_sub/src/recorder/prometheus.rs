@@ -0,0 +1,241 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use super::MetricsRecorder;
+
+/// The upper bounds of the histogram buckets, in seconds.
+const BUCKETS: [f64; 10] = [0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0];
+
+type Labels = Vec<(&'static str, &'static str)>;
+
+/// A [`MetricsRecorder`] keeping the metrics in memory and rendering them in the
+/// Prometheus text exposition format.
+///
+/// Clones share the same metrics, so a clone can be given to the metrics layers while
+/// another one serves [`PrometheusRecorder::render`], e.g. from an HTTP endpoint.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{
+///     MetricsPublisherLayer, PrometheusRecorder, Publisher, PublisherBuilder, PublisherImpl,
+///     SubscriberImpl,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let recorder = PrometheusRecorder::new();
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///     let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 10))
+///         .with_layer(MetricsPublisherLayer::new(recorder.clone()))
+///         .build();
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///     publisher.publish(42).await.unwrap();
+///
+///     let output = recorder.render();
+///     assert!(output.contains(
+///         r#"async_pub_sub_messages_published_total{publisher="publisher",subscriber="subscriber"} 1"#
+///     ));
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PrometheusRecorder {
+    families: Arc<Mutex<BTreeMap<&'static str, Family>>>,
+}
+
+#[derive(Debug)]
+enum Family {
+    Counter(BTreeMap<Labels, u64>),
+    Gauge(BTreeMap<Labels, f64>),
+    Histogram(BTreeMap<Labels, Histogram>),
+}
+
+#[derive(Debug, Default)]
+struct Histogram {
+    /// The number of observations per bucket, not cumulated
+    buckets: [u64; BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl PrometheusRecorder {
+    /// Creates a recorder without any metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renders every recorded metric in the Prometheus text exposition format.
+    ///
+    /// # Returns
+    /// The metrics sorted by name then labels, each family preceded by its `# TYPE` line
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+
+        for (name, family) in self.lock().iter() {
+            match family {
+                Family::Counter(values) => {
+                    let _ = writeln!(output, "# TYPE {} counter", name);
+                    for (labels, value) in values {
+                        let _ =
+                            writeln!(output, "{}{} {}", name, format_labels(labels, None), value);
+                    }
+                }
+                Family::Gauge(values) => {
+                    let _ = writeln!(output, "# TYPE {} gauge", name);
+                    for (labels, value) in values {
+                        let _ =
+                            writeln!(output, "{}{} {}", name, format_labels(labels, None), value);
+                    }
+                }
+                Family::Histogram(values) => {
+                    let _ = writeln!(output, "# TYPE {} histogram", name);
+                    for (labels, histogram) in values {
+                        let mut cumulated = 0;
+                        for (bound, count) in BUCKETS.iter().zip(histogram.buckets) {
+                            cumulated += count;
+                            let le = bound.to_string();
+                            let _ = writeln!(
+                                output,
+                                "{}_bucket{} {}",
+                                name,
+                                format_labels(labels, Some(le.as_str())),
+                                cumulated
+                            );
+                        }
+                        let _ = writeln!(
+                            output,
+                            "{}_bucket{} {}",
+                            name,
+                            format_labels(labels, Some("+Inf")),
+                            histogram.count
+                        );
+                        let _ = writeln!(
+                            output,
+                            "{}_sum{} {}",
+                            name,
+                            format_labels(labels, None),
+                            histogram.sum
+                        );
+                        let _ = writeln!(
+                            output,
+                            "{}_count{} {}",
+                            name,
+                            format_labels(labels, None),
+                            histogram.count
+                        );
+                    }
+                }
+            }
+        }
+
+        output
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<&'static str, Family>> {
+        self.families
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Applies `update` to the family called `name`, creating it with `create` if needed.
+    /// Updates of a name already used by a metric of another kind are ignored.
+    fn update(
+        &self,
+        name: &'static str,
+        create: fn() -> Family,
+        update: impl FnOnce(&mut Family) -> bool,
+    ) {
+        let mut families = self.lock();
+        let family = families.entry(name).or_insert_with(create);
+        if !update(family) {
+            log::warn!("metric {} is already registered with another type", name);
+        }
+    }
+}
+
+impl MetricsRecorder for PrometheusRecorder {
+    fn increment_counter(
+        &self,
+        name: &'static str,
+        labels: &[(&'static str, &'static str)],
+        value: u64,
+    ) {
+        self.update(
+            name,
+            || Family::Counter(BTreeMap::new()),
+            |family| match family {
+                Family::Counter(values) => {
+                    *values.entry(labels.to_vec()).or_default() += value;
+                    true
+                }
+                _ => false,
+            },
+        );
+    }
+
+    fn set_gauge(&self, name: &'static str, labels: &[(&'static str, &'static str)], value: f64) {
+        self.update(
+            name,
+            || Family::Gauge(BTreeMap::new()),
+            |family| match family {
+                Family::Gauge(values) => {
+                    values.insert(labels.to_vec(), value);
+                    true
+                }
+                _ => false,
+            },
+        );
+    }
+
+    fn record_histogram(
+        &self,
+        name: &'static str,
+        labels: &[(&'static str, &'static str)],
+        value: f64,
+    ) {
+        self.update(
+            name,
+            || Family::Histogram(BTreeMap::new()),
+            |family| match family {
+                Family::Histogram(values) => {
+                    let histogram = values.entry(labels.to_vec()).or_default();
+                    if let Some(bucket) = BUCKETS.iter().position(|bound| value <= *bound) {
+                        histogram.buckets[bucket] += 1;
+                    }
+                    histogram.sum += value;
+                    histogram.count += 1;
+                    true
+                }
+                _ => false,
+            },
+        );
+    }
+}
+
+/// Formats the labels as `{name="value",...}`, appending the `le` label of histogram buckets.
+fn format_labels(labels: &Labels, le: Option<&str>) -> String {
+    let mut pairs: Vec<String> = labels
+        .iter()
+        .map(|(name, value)| format!("{}=\"{}\"", name, escape(value)))
+        .collect();
+    if let Some(le) = le {
+        pairs.push(format!("le=\"{}\"", le));
+    }
+
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
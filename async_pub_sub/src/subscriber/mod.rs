@@ -1,5 +1,14 @@
+mod prioritized_streams;
 mod subscriber_impl;
+mod subscriber_middlewares;
 mod subscriber_trait;
+mod subscription;
 
+pub use prioritized_streams::SubscriptionOptions;
 pub use subscriber_impl::SubscriberImpl;
-pub use subscriber_trait::{SubscriberWrapper, Subscriber};
+pub use subscriber_middlewares::{
+    DebuggingSubscriberLayer, EnvelopeSubscriberLayer, LoggingSubscriberLayer,
+    MetricsSubscriberLayer, SubscriberBuilder,
+};
+pub use subscriber_trait::{Subscriber, SubscriberLayer, SubscriberWrapper};
+pub use subscription::Subscription;
@@ -0,0 +1,222 @@
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use super::subscription::DetachableStream;
+
+/// Defines how the messages of a subscription are scheduled against the other
+/// subscriptions of a [`crate::SubscriberImpl`].
+///
+/// Subscriptions with a higher priority are strictly drained first: a message of a lower
+/// priority subscription is only received when no higher priority subscription has a
+/// message queued. Subscriptions with the same priority are served in turn, each one
+/// delivering up to `weight` messages in a row.
+///
+/// The default options have a priority of `0` and a weight of `1`, i.e. subscriptions are
+/// served in a round-robin fashion.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{Publisher, PublisherImpl, SubscriberImpl, SubscriptionOptions};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut data = PublisherImpl::new("data", 10);
+///     let mut control = PublisherImpl::new("control", 10);
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///
+///     subscriber.subscribe_to(&mut data).unwrap();
+///     subscriber
+///         .subscribe_to_with(&mut control, SubscriptionOptions::new().with_priority(1))
+///         .unwrap();
+///
+///     data.publish("data").await.unwrap();
+///     control.publish("stop").await.unwrap();
+///
+///     assert_eq!(subscriber.receive().await, "stop");
+///     assert_eq!(subscriber.receive().await, "data");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubscriptionOptions {
+    /// The priority of the subscription, higher priorities are drained first
+    priority: i32,
+    /// How many messages the subscription delivers in a row among its priority level
+    weight: usize,
+}
+
+impl Default for SubscriptionOptions {
+    fn default() -> Self {
+        Self {
+            priority: 0,
+            weight: 1,
+        }
+    }
+}
+
+impl SubscriptionOptions {
+    /// Creates the default options: a priority of `0` and a weight of `1`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the priority of the subscription.
+    ///
+    /// # Arguments
+    /// * `priority` - The priority, subscriptions with a higher priority are drained first
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the weight of the subscription among the subscriptions of the same priority.
+    ///
+    /// # Arguments
+    /// * `weight` - How many messages the subscription delivers before the next subscription
+    ///   of the same priority is served
+    ///
+    /// # Panics
+    /// Panics if `weight` is 0.
+    pub fn with_weight(mut self, weight: usize) -> Self {
+        assert!(weight > 0, "subscription weight should be greater than 0");
+        self.weight = weight;
+        self
+    }
+
+    /// Returns the priority of the subscription.
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Returns the weight of the subscription.
+    pub fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+/// A subscribed message stream along with its publisher name and weight.
+struct SubscribedStream<Message> {
+    publisher_name: &'static str,
+    weight: usize,
+    stream: Arc<DetachableStream<Message>>,
+}
+
+/// The subscriptions sharing the same priority, served in a weighted round-robin.
+struct PriorityLevel<Message> {
+    priority: i32,
+    subscriptions: Vec<SubscribedStream<Message>>,
+    /// The index of the subscription being served
+    cursor: usize,
+    /// How many messages the subscription being served has delivered in a row
+    served: usize,
+}
+
+impl<Message> PriorityLevel<Message> {
+    /// Polls the subscriptions of the level in turn, starting from the one being served.
+    ///
+    /// Every subscription is polled before returning `Poll::Pending`, so that all of them
+    /// wake the task up. Ended subscriptions are removed.
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<(&'static str, Message)> {
+        let mut polled = 0;
+        while polled < self.subscriptions.len() {
+            let subscription = &self.subscriptions[self.cursor];
+            match subscription.stream.poll_next(cx) {
+                Poll::Ready(Some(message)) => {
+                    let publisher_name = subscription.publisher_name;
+                    self.served += 1;
+                    if self.served >= subscription.weight {
+                        self.advance();
+                    }
+                    return Poll::Ready((publisher_name, message));
+                }
+                Poll::Ready(None) => {
+                    self.subscriptions.remove(self.cursor);
+                    self.served = 0;
+                    if self.cursor >= self.subscriptions.len() {
+                        self.cursor = 0;
+                    }
+                }
+                Poll::Pending => {
+                    self.advance();
+                    polled += 1;
+                }
+            }
+        }
+        Poll::Pending
+    }
+
+    /// Moves on to the next subscription of the level.
+    fn advance(&mut self) {
+        self.served = 0;
+        self.cursor = (self.cursor + 1) % self.subscriptions.len();
+    }
+}
+
+/// Merges the message streams of several publishers, draining them by priority and serving
+/// the streams of the same priority in a weighted round-robin.
+pub(crate) struct PrioritizedStreams<Message> {
+    /// The priority levels, sorted by decreasing priority
+    levels: Vec<PriorityLevel<Message>>,
+}
+
+impl<Message> PrioritizedStreams<Message> {
+    pub(crate) fn new() -> Self {
+        Self { levels: Vec::new() }
+    }
+
+    /// Adds a message stream with the given scheduling options.
+    pub(crate) fn push(
+        &mut self,
+        publisher_name: &'static str,
+        stream: Arc<DetachableStream<Message>>,
+        options: SubscriptionOptions,
+    ) {
+        let subscription = SubscribedStream {
+            publisher_name,
+            weight: options.weight,
+            stream,
+        };
+
+        let index = self
+            .levels
+            .partition_point(|level| level.priority > options.priority);
+        match self.levels.get_mut(index) {
+            Some(level) if level.priority == options.priority => {
+                level.subscriptions.push(subscription)
+            }
+            _ => self.levels.insert(
+                index,
+                PriorityLevel {
+                    priority: options.priority,
+                    subscriptions: vec![subscription],
+                    cursor: 0,
+                    served: 0,
+                },
+            ),
+        }
+    }
+}
+
+impl<Message> Stream for PrioritizedStreams<Message> {
+    type Item = (&'static str, Message);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        for level in self.levels.iter_mut() {
+            if let Poll::Ready(message) = level.poll_next(cx) {
+                return Poll::Ready(Some(message));
+            }
+        }
+
+        self.levels.retain(|level| !level.subscriptions.is_empty());
+        if self.levels.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
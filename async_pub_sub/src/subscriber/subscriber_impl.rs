@@ -1,19 +1,30 @@
-use crate::{PublisherWrapper, Result, Subscriber};
-use futures::{stream::SelectAll, Stream, StreamExt};
-use std::{future::Future, pin::Pin};
+use super::{prioritized_streams::PrioritizedStreams, subscription::DetachableStream};
+use crate::{
+    PublisherWrapper, Result, ShutdownSignal, Subscriber, Subscription, SubscriptionOptions,
+};
+use futures::{
+    future::{pending, poll_fn},
+    FutureExt, StreamExt,
+};
+use std::{future::Future, task::Poll};
 
 /// A concrete implementation of the Subscriber trait that can receive messages from multiple publishers.
 ///
-/// This implementation uses a SelectAll stream to merge messages from multiple publishers
-/// into a single stream that can be read from sequentially.
+/// This implementation merges messages from multiple publishers into a single stream that
+/// can be read from sequentially. The subscriptions are drained by priority, and the ones
+/// of the same priority are served in turn, see [`SubscriptionOptions`]. Every message is tagged with the
+/// name of the publisher it comes from, see [`SubscriberImpl::receive_with_source`].
 pub struct SubscriberImpl<Message>
 where
     Message: Send + 'static,
 {
     /// The unique name identifier of this subscriber
     name: &'static str,
-    /// Combined stream of messages from all subscribed publishers
-    messages: SelectAll<Pin<Box<dyn Stream<Item = Message> + Send + Sync + 'static>>>,
+    /// Combined stream of messages from all subscribed publishers, tagged with the name of
+    /// their publisher
+    messages: PrioritizedStreams<Message>,
+    /// Signal that stops the subscriber once triggered, if any
+    shutdown: Option<ShutdownSignal>,
 }
 
 impl<Message> SubscriberImpl<Message>
@@ -28,29 +39,123 @@ where
     /// # Returns
     /// A new instance of SubscriberImpl
     pub fn new(name: &'static str) -> Self {
-        let messages = SelectAll::new();
-        Self { name, messages }
+        let messages = PrioritizedStreams::new();
+        Self {
+            name,
+            messages,
+            shutdown: None,
+        }
     }
 
-    /// Subscribes to a publisher to receive its messages.
+    /// Stops the subscriber once the given shutdown signal is triggered.
+    ///
+    /// After the shutdown, [`SubscriberImpl::recv`] keeps returning the messages that are
+    /// already queued, then returns `None` instead of waiting for new ones.
+    ///
+    /// # Parameters
+    /// * `shutdown` - The signal stopping the subscriber
+    pub fn with_shutdown(mut self, shutdown: ShutdownSignal) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    /// Subscribes to a publisher to receive its messages, with the default
+    /// [`SubscriptionOptions`].
     ///
     /// # Parameters
     /// * `publisher` - A mutable reference to any type implementing PublisherWrapper
     ///
     /// # Returns
-    /// A Result indicating success or failure of the subscription
-    pub fn subscribe_to(&mut self, publisher: &mut impl PublisherWrapper<Message>) -> Result<()> {
-        let stream = publisher.get_message_stream(self.name)?;
-        self.messages.push(stream);
-        Ok(())
+    /// A Result containing the handle of the subscription, that can be used to unsubscribe
+    pub fn subscribe_to(
+        &mut self,
+        publisher: &mut impl PublisherWrapper<Message>,
+    ) -> Result<Subscription> {
+        self.subscribe_to_with(publisher, SubscriptionOptions::default())
+    }
+
+    /// Subscribes to a publisher to receive its messages, scheduled against the other
+    /// subscriptions according to the given options.
+    ///
+    /// # Parameters
+    /// * `publisher` - A mutable reference to any type implementing PublisherWrapper
+    /// * `options` - The priority and weight of the subscription
+    ///
+    /// # Returns
+    /// A Result containing the handle of the subscription, that can be used to unsubscribe
+    pub fn subscribe_to_with(
+        &mut self,
+        publisher: &mut impl PublisherWrapper<Message>,
+        options: SubscriptionOptions,
+    ) -> Result<Subscription> {
+        let publisher_name = publisher.get_name();
+        let stream = DetachableStream::new(publisher.get_message_stream(self.name)?);
+        self.messages.push(publisher_name, stream.clone(), options);
+        Ok(Subscription::new(publisher_name, stream))
     }
 
     /// Asynchronously receives the next available message from any subscribed publisher.
     ///
+    /// Never resolves once every publisher is gone, see [`SubscriberImpl::recv`] to
+    /// detect the end of the stream.
+    ///
     /// # Returns
     /// The next message in the combined message stream
     pub async fn receive(&mut self) -> Message {
-        self.messages.select_next_some().await
+        let (_, message) = self.receive_with_source().await;
+        message
+    }
+
+    /// Asynchronously receives the next available message along with the name of the
+    /// publisher it comes from.
+    ///
+    /// The name is the one of the publisher at the time of the subscription, so that messages
+    /// can be handled differently depending on their source.
+    ///
+    /// Never resolves once every publisher is gone, see [`SubscriberImpl::recv_with_source`]
+    /// to detect the end of the stream.
+    ///
+    /// # Returns
+    /// The name of the publisher and the next message in the combined message stream
+    pub async fn receive_with_source(&mut self) -> (&'static str, Message) {
+        match self.messages.next().await {
+            Some(message) => message,
+            None => pending().await,
+        }
+    }
+
+    /// Asynchronously receives the next available message from any subscribed publisher.
+    ///
+    /// Queued messages are still delivered after their publisher has been dropped.
+    ///
+    /// # Returns
+    /// The next message in the combined message stream, or `None` once every subscribed
+    /// publisher has been dropped or closed (or if the subscriber is not subscribed to any
+    /// publisher), or once the shutdown signal is triggered and no message is queued
+    pub async fn recv(&mut self) -> Option<Message> {
+        let (_, message) = self.recv_with_source().await?;
+        Some(message)
+    }
+
+    /// Asynchronously receives the next available message along with the name of the
+    /// publisher it comes from.
+    ///
+    /// Behaves like [`SubscriberImpl::recv`] regarding dropped publishers and shutdown.
+    ///
+    /// # Returns
+    /// The name of the publisher and the next message in the combined message stream, or
+    /// `None` once the stream has ended
+    pub async fn recv_with_source(&mut self) -> Option<(&'static str, Message)> {
+        let Some(shutdown) = self.shutdown.as_mut() else {
+            return self.messages.next().await;
+        };
+
+        let messages = &mut self.messages;
+        poll_fn(|cx| match messages.poll_next_unpin(cx) {
+            Poll::Ready(message) => Poll::Ready(message),
+            Poll::Pending => shutdown.poll_unpin(cx).map(|_| None),
+        })
+        .await
     }
 }
 
@@ -69,10 +174,14 @@ where
     }
 
     fn subscribe_to(&mut self, publisher: &mut impl PublisherWrapper<Self::Message>) -> Result<()> {
-        SubscriberImpl::subscribe_to(self, publisher)
+        SubscriberImpl::subscribe_to(self, publisher).map(|_| ())
     }
 
     fn receive(&mut self) -> impl Future<Output = Message> {
         SubscriberImpl::receive(self)
     }
+
+    fn recv(&mut self) -> impl Future<Output = Option<Message>> {
+        SubscriberImpl::recv(self)
+    }
 }
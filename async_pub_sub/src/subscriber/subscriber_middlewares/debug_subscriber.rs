@@ -0,0 +1,99 @@
+use std::fmt::Debug;
+
+use crate::{PublisherWrapper, Result, Subscriber, SubscriberLayer};
+
+/// A middleware layer that adds debug logging capabilities to a subscriber.
+/// When messages are received, it will log them using the debug format.
+pub struct DebuggingSubscriberLayer;
+
+impl<S> SubscriberLayer<S> for DebuggingSubscriberLayer
+where
+    S: Subscriber + Send,
+    S::Message: Debug,
+{
+    type SubscriberType = DebugSubscriber<S>;
+
+    /// Creates a new `DebugSubscriber` by wrapping the provided subscriber.
+    ///
+    /// # Arguments
+    /// * `subscriber` - The underlying subscriber to wrap with debug logging
+    fn layer(&self, subscriber: S) -> Self::SubscriberType {
+        DebugSubscriber {
+            publisher_names: Vec::new(),
+            subscriber,
+        }
+    }
+}
+
+/// A subscriber wrapper that adds debug logging capabilities to an existing subscriber.
+/// It logs messages when they are received, showing the subscriber name, publisher names,
+/// and the debug representation of the message.
+pub struct DebugSubscriber<S>
+where
+    S: Subscriber,
+{
+    /// The names of the publishers this subscriber is subscribed to
+    publisher_names: Vec<&'static str>,
+    /// The underlying subscriber being wrapped
+    subscriber: S,
+}
+
+impl<S> Subscriber for DebugSubscriber<S>
+where
+    S: Subscriber,
+    S::Message: Debug,
+    Self: Send,
+{
+    type Message = S::Message;
+
+    /// Returns the name of the underlying subscriber
+    fn get_name(&self) -> &'static str {
+        self.subscriber.get_name()
+    }
+
+    /// Subscribes the underlying subscriber and stores the publisher's name for logging
+    fn subscribe_to(&mut self, publisher: &mut impl PublisherWrapper<Self::Message>) -> Result<()> {
+        self.subscriber.subscribe_to(publisher)?;
+        self.publisher_names.push(publisher.get_name());
+        log::info!(
+            "({}) <-> ({}): {}",
+            self.subscriber.get_name(),
+            publisher.get_name(),
+            std::any::type_name::<Self::Message>()
+        );
+        Ok(())
+    }
+
+    /// Receives a message while logging its debug representation.
+    ///
+    /// Logs the message in the format: "[subscriber_name] <- [publisher_names]: message_debug_format"
+    async fn receive(&mut self) -> Self::Message {
+        let message = self.subscriber.receive().await;
+        log::info!(
+            "[{}] <- [{}]: {:?}",
+            self.subscriber.get_name(),
+            self.publisher_names.join(", "),
+            message
+        );
+        message
+    }
+
+    /// Receives a message, logging it or the end of the stream
+    async fn recv(&mut self) -> Option<Self::Message> {
+        let message = self.subscriber.recv().await;
+        match &message {
+            Some(message) => log::info!(
+                "[{}] <- [{}]: {:?}",
+                self.subscriber.get_name(),
+                self.publisher_names.join(", "),
+                message
+            ),
+            None => log::info!(
+                "[{}] <- [{}]: end of stream",
+                self.subscriber.get_name(),
+                self.publisher_names.join(", ")
+            ),
+        }
+        message
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{Envelope, Metadata, PublisherWrapper, Result, Subscriber, SubscriberLayer};
+
+/// A subscriber middleware layer unwrapping the received [`Envelope`]s.
+///
+/// The layered subscriber still subscribes to publishers of envelopes, and `receive`
+/// still returns the envelopes. It adds [`EnvelopeSubscriber::receive_message`] and
+/// [`EnvelopeSubscriber::recv_message`], returning the bare messages while keeping the
+/// metadata of the last one at hand.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{
+///     Envelope, EnvelopeSubscriberLayer, Publisher, PublisherImpl, SubscriberBuilder,
+///     SubscriberImpl, Subscriber,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut publisher = PublisherImpl::new("publisher", 10);
+///     let mut subscriber = SubscriberBuilder::new(SubscriberImpl::new("subscriber"))
+///         .with_layer(EnvelopeSubscriberLayer)
+///         .build();
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///     publisher.publish(Envelope::new("sensor", 21)).await.unwrap();
+///
+///     assert_eq!(subscriber.receive_message().await, 21);
+///     assert_eq!(subscriber.last_metadata().unwrap().source(), "sensor");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct EnvelopeSubscriberLayer;
+
+impl<S, M> SubscriberLayer<S> for EnvelopeSubscriberLayer
+where
+    S: Subscriber<Message = Envelope<M>> + Send,
+    M: Send + 'static,
+{
+    type SubscriberType = EnvelopeSubscriber<S>;
+
+    /// Creates a new EnvelopeSubscriber by wrapping the provided subscriber.
+    fn layer(&self, subscriber: S) -> Self::SubscriberType {
+        EnvelopeSubscriber {
+            last_metadata: None,
+            subscriber,
+        }
+    }
+}
+
+/// A subscriber wrapper unwrapping the envelopes received by an existing subscriber.
+pub struct EnvelopeSubscriber<S> {
+    /// The metadata of the last message unwrapped
+    last_metadata: Option<Metadata>,
+    /// The underlying subscriber being wrapped
+    subscriber: S,
+}
+
+impl<S, M> EnvelopeSubscriber<S>
+where
+    S: Subscriber<Message = Envelope<M>> + Send,
+    M: Send + 'static,
+{
+    /// Receives the next message out of its envelope, keeping its metadata.
+    pub async fn receive_message(&mut self) -> M {
+        let envelope = self.subscriber.receive().await;
+        self.unwrap(envelope)
+    }
+
+    /// Receives the next message out of its envelope, keeping its metadata, or `None` once
+    /// every subscribed publisher is gone.
+    pub async fn recv_message(&mut self) -> Option<M> {
+        let envelope = self.subscriber.recv().await?;
+        Some(self.unwrap(envelope))
+    }
+
+    /// Returns the metadata of the last message received with
+    /// [`EnvelopeSubscriber::receive_message`] or [`EnvelopeSubscriber::recv_message`].
+    pub fn last_metadata(&self) -> Option<&Metadata> {
+        self.last_metadata.as_ref()
+    }
+
+    fn unwrap(&mut self, envelope: Envelope<M>) -> M {
+        let (metadata, message) = envelope.into_parts();
+        self.last_metadata = Some(metadata);
+        message
+    }
+}
+
+impl<S, M> Subscriber for EnvelopeSubscriber<S>
+where
+    S: Subscriber<Message = Envelope<M>> + Send,
+    M: Send + 'static,
+{
+    type Message = Envelope<M>;
+
+    /// Returns the name of the underlying subscriber
+    fn get_name(&self) -> &'static str {
+        self.subscriber.get_name()
+    }
+
+    /// Subscribes the underlying subscriber to the publisher
+    fn subscribe_to(&mut self, publisher: &mut impl PublisherWrapper<Self::Message>) -> Result<()> {
+        self.subscriber.subscribe_to(publisher)
+    }
+
+    /// Receives the next envelope
+    async fn receive(&mut self) -> Self::Message {
+        self.subscriber.receive().await
+    }
+
+    /// Receives the next envelope, or `None` once every subscribed publisher is gone
+    async fn recv(&mut self) -> Option<Self::Message> {
+        self.subscriber.recv().await
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{LogFormat, PublisherWrapper, Result, Subscriber, SubscriberLayer};
+
+/// A subscriber middleware layer that adds logging capabilities to any subscriber.
+/// This layer will log all messages that are received through the subscriber, formatted
+/// with [`LogFormat`].
+pub struct LoggingSubscriberLayer;
+
+/// Implementation of the SubscriberLayer trait for LoggingSubscriberLayer.
+/// This allows wrapping any subscriber that implements the Subscriber trait with logging functionality.
+impl<S> SubscriberLayer<S> for LoggingSubscriberLayer
+where
+    S: Subscriber + Send,
+    S::Message: LogFormat,
+{
+    type SubscriberType = LoggingSubscriber<S>;
+
+    /// Creates a new LoggingSubscriber by wrapping the provided subscriber.
+    fn layer(&self, subscriber: S) -> Self::SubscriberType {
+        LoggingSubscriber {
+            publisher_names: Vec::new(),
+            subscriber,
+        }
+    }
+}
+
+/// A subscriber wrapper that adds logging functionality to an existing subscriber.
+/// Logs all messages that are received through this subscriber.
+pub struct LoggingSubscriber<S>
+where
+    S: Subscriber,
+{
+    /// The names of the publishers this subscriber is subscribed to
+    publisher_names: Vec<&'static str>,
+    /// The underlying subscriber being wrapped
+    subscriber: S,
+}
+
+/// Implementation of the Subscriber trait for LoggingSubscriber.
+/// This implementation delegates all operations to the wrapped subscriber while adding logging.
+impl<S> Subscriber for LoggingSubscriber<S>
+where
+    S: Subscriber,
+    S::Message: LogFormat,
+    Self: Send,
+{
+    type Message = S::Message;
+
+    /// Returns the name of the underlying subscriber
+    fn get_name(&self) -> &'static str {
+        self.subscriber.get_name()
+    }
+
+    /// Subscribes the underlying subscriber and stores the publisher's name for logging
+    fn subscribe_to(&mut self, publisher: &mut impl PublisherWrapper<Self::Message>) -> Result<()> {
+        self.subscriber.subscribe_to(publisher)?;
+        self.publisher_names.push(publisher.get_name());
+        log::info!(
+            "({}) <-> ({}): {}",
+            self.subscriber.get_name(),
+            publisher.get_name(),
+            std::any::type_name::<Self::Message>()
+        );
+        Ok(())
+    }
+
+    /// Receives a message and logs the operation with destination subscriber and source publishers
+    async fn receive(&mut self) -> Self::Message {
+        let message = self.subscriber.receive().await;
+        log::info!(
+            "{}",
+            message.format_received(self.subscriber.get_name(), &self.publisher_names)
+        );
+        message
+    }
+
+    /// Receives a message, logging it or the end of the stream
+    async fn recv(&mut self) -> Option<Self::Message> {
+        let message = self.subscriber.recv().await;
+        match &message {
+            Some(message) => log::info!(
+                "{}",
+                message.format_received(self.subscriber.get_name(), &self.publisher_names)
+            ),
+            None => log::info!(
+                "[{}] <- [{}]: end of stream",
+                self.subscriber.get_name(),
+                self.publisher_names.join(", ")
+            ),
+        }
+        message
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{
+    recorder::{MetricsRecorder, MESSAGES_RECEIVED},
+    PublisherWrapper, Result, Subscriber, SubscriberLayer,
+};
+
+/// A subscriber middleware layer counting the received messages in a [`MetricsRecorder`].
+///
+/// The messages are counted in `async_pub_sub_messages_received_total`, labelled by
+/// `subscriber`.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{
+///     MetricsSubscriberLayer, PrometheusRecorder, Publisher, PublisherImpl, Subscriber,
+///     SubscriberBuilder, SubscriberImpl,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let recorder = PrometheusRecorder::new();
+///     let mut publisher = PublisherImpl::new("publisher", 10);
+///     let mut subscriber = SubscriberBuilder::new(SubscriberImpl::new("subscriber"))
+///         .with_layer(MetricsSubscriberLayer::new(recorder.clone()))
+///         .build();
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///     publisher.publish(42).await.unwrap();
+///     subscriber.receive().await;
+///
+///     assert!(recorder
+///         .render()
+///         .contains(r#"async_pub_sub_messages_received_total{subscriber="subscriber"} 1"#));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MetricsSubscriberLayer<R> {
+    /// The recorder receiving the metrics of every subscriber built from this layer
+    recorder: R,
+}
+
+impl<R> MetricsSubscriberLayer<R>
+where
+    R: MetricsRecorder,
+{
+    /// Creates a new metrics layer.
+    ///
+    /// # Arguments
+    /// * `recorder` - The recorder the metrics are reported to
+    pub fn new(recorder: R) -> Self {
+        Self { recorder }
+    }
+}
+
+impl<S, R> SubscriberLayer<S> for MetricsSubscriberLayer<R>
+where
+    S: Subscriber + Send,
+    R: MetricsRecorder,
+{
+    type SubscriberType = MetricsSubscriber<S, R>;
+
+    /// Creates a new MetricsSubscriber by wrapping the provided subscriber.
+    fn layer(&self, subscriber: S) -> Self::SubscriberType {
+        MetricsSubscriber {
+            recorder: self.recorder.clone(),
+            subscriber,
+        }
+    }
+}
+
+/// A subscriber wrapper counting the messages received by an existing subscriber.
+pub struct MetricsSubscriber<S, R> {
+    /// The recorder the metrics are reported to
+    recorder: R,
+    /// The underlying subscriber being wrapped
+    subscriber: S,
+}
+
+impl<S, R> MetricsSubscriber<S, R>
+where
+    S: Subscriber,
+    R: MetricsRecorder,
+{
+    fn record_received(&self) {
+        self.recorder.increment_counter(
+            MESSAGES_RECEIVED,
+            &[("subscriber", self.subscriber.get_name())],
+            1,
+        );
+    }
+}
+
+impl<S, R> Subscriber for MetricsSubscriber<S, R>
+where
+    S: Subscriber + Send,
+    R: MetricsRecorder,
+{
+    type Message = S::Message;
+
+    /// Returns the name of the underlying subscriber
+    fn get_name(&self) -> &'static str {
+        self.subscriber.get_name()
+    }
+
+    /// Subscribes the underlying subscriber to the publisher
+    fn subscribe_to(&mut self, publisher: &mut impl PublisherWrapper<Self::Message>) -> Result<()> {
+        self.subscriber.subscribe_to(publisher)
+    }
+
+    /// Receives a message and counts it
+    async fn receive(&mut self) -> Self::Message {
+        let message = self.subscriber.receive().await;
+        self.record_received();
+        message
+    }
+
+    /// Receives a message, counting it unless the stream ended
+    async fn recv(&mut self) -> Option<Self::Message> {
+        let message = self.subscriber.recv().await;
+        if message.is_some() {
+            self.record_received();
+        }
+        message
+    }
+}
@@ -0,0 +1,11 @@
+mod debug_subscriber;
+mod envelope_subscriber;
+mod logging_subscriber;
+mod metrics_subscriber;
+mod subscriber_builder;
+
+pub use debug_subscriber::DebuggingSubscriberLayer;
+pub use envelope_subscriber::EnvelopeSubscriberLayer;
+pub use logging_subscriber::LoggingSubscriberLayer;
+pub use metrics_subscriber::MetricsSubscriberLayer;
+pub use subscriber_builder::SubscriberBuilder;
@@ -0,0 +1,49 @@
+use crate::{Subscriber, SubscriberLayer};
+
+/// A builder pattern implementation for constructing a subscriber with middleware layers.
+/// This struct allows for composing multiple middleware layers around a base subscriber.
+pub struct SubscriberBuilder<S>
+where
+    S: Subscriber,
+{
+    subscriber: S,
+}
+
+impl<S> SubscriberBuilder<S>
+where
+    S: Subscriber,
+{
+    /// Creates a new `SubscriberBuilder` with a base subscriber.
+    ///
+    /// # Arguments
+    ///
+    /// * `subscriber` - The base subscriber implementation to wrap with middleware layers.
+    pub fn new(subscriber: S) -> Self {
+        Self { subscriber }
+    }
+
+    /// Adds a middleware layer to the subscriber.
+    ///
+    /// # Arguments
+    ///
+    /// * `layer` - The middleware layer to add.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `SubscriberBuilder` with the layer applied to the subscriber.
+    pub fn with_layer<Layer>(self, layer: Layer) -> SubscriberBuilder<Layer::SubscriberType>
+    where
+        Layer: SubscriberLayer<S>,
+    {
+        SubscriberBuilder::new(layer.layer(self.subscriber))
+    }
+
+    /// Finalizes the builder and returns the constructed subscriber with all applied layers.
+    ///
+    /// # Returns
+    ///
+    /// Returns the final subscriber with all middleware layers applied.
+    pub fn build(self) -> S {
+        self.subscriber
+    }
+}
@@ -30,6 +30,40 @@ pub trait Subscriber {
     /// # Returns
     /// A Future that resolves to the next message of type Message
     fn receive(&mut self) -> impl Future<Output = Self::Message> + Send;
+
+    /// Asynchronously receives the next message, or `None` once every subscribed
+    /// publisher has been closed or dropped.
+    ///
+    /// The default implementation never reports the end of the stream; implementations
+    /// able to detect it should override this method.
+    ///
+    /// # Returns
+    /// A Future that resolves to the next message, or `None` when no more message can arrive
+    fn recv(&mut self) -> impl Future<Output = Option<Self::Message>> + Send {
+        let message = self.receive();
+        async move { Some(message.await) }
+    }
+}
+
+/// A trait for creating layered subscribers.
+///
+/// This trait enables the creation of subscriber middleware that can wrap
+/// and extend the functionality of other subscribers.
+pub trait SubscriberLayer<InnerSubscriberType>
+where
+    InnerSubscriberType: Subscriber,
+{
+    /// The type of subscriber that this layer produces.
+    type SubscriberType: Subscriber;
+
+    /// Wraps an inner subscriber with this layer.
+    ///
+    /// # Parameters
+    /// * `subscriber` - The inner subscriber to wrap
+    ///
+    /// # Returns
+    /// A new subscriber wrapped with this layer's functionality.
+    fn layer(&self, subscriber: InnerSubscriberType) -> Self::SubscriberType;
 }
 
 /// A wrapper trait that provides a unified interface for working with Subscriber implementations.
@@ -63,6 +97,12 @@ where
     fn receive(&mut self) -> impl Future<Output = Message> {
         Subscriber::receive(self.get_subscriber_mut())
     }
+
+    /// Receives the next message asynchronously, or `None` once the publishers are gone.
+    /// Delegates to the underlying subscriber's recv implementation.
+    fn recv(&mut self) -> impl Future<Output = Option<Message>> {
+        Subscriber::recv(self.get_subscriber_mut())
+    }
 }
 
 /// Blanket implementation of SubscriberWrapper for any type that implements Subscriber.
@@ -0,0 +1,159 @@
+use std::{
+    fmt::Debug,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll},
+};
+
+use futures::{task::AtomicWaker, Stream, StreamExt};
+
+/// A message stream of a publisher.
+pub(crate) type MessageStream<Message> =
+    Pin<Box<dyn Stream<Item = Message> + Send + Sync + 'static>>;
+
+/// A handle on the subscription of a [`crate::SubscriberImpl`] to a publisher, returned by
+/// [`crate::SubscriberImpl::subscribe_to`].
+///
+/// Dropping the handle keeps the subscription alive, [`Subscription::unsubscribe`] detaches
+/// the message stream of the publisher from the subscriber. The publisher can then be bound
+/// to another subscriber, which receives the messages that were queued and not yet
+/// received. This allows to swap the consumer of a publisher without restarting its
+/// producer.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{Publisher, PublisherImpl, SubscriberImpl};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut publisher = PublisherImpl::new("publisher", 10);
+///     let mut old_subscriber = SubscriberImpl::new("old_subscriber");
+///     let mut new_subscriber = SubscriberImpl::new("new_subscriber");
+///
+///     let subscription = old_subscriber.subscribe_to(&mut publisher).unwrap();
+///     publisher.publish(1).await.unwrap();
+///     publisher.publish(2).await.unwrap();
+///     assert_eq!(old_subscriber.receive().await, 1);
+///
+///     subscription.unsubscribe();
+///     new_subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     assert_eq!(new_subscriber.receive().await, 2);
+///     assert_eq!(old_subscriber.recv().await, None);
+/// }
+/// ```
+pub struct Subscription {
+    /// The name of the publisher subscribed to
+    publisher_name: &'static str,
+    /// The message stream shared with the subscriber
+    stream: Arc<dyn Detach>,
+}
+
+impl Subscription {
+    /// Creates a handle on the given subscribed stream.
+    pub(crate) fn new(publisher_name: &'static str, stream: Arc<dyn Detach>) -> Self {
+        Self {
+            publisher_name,
+            stream,
+        }
+    }
+
+    /// Returns the name of the publisher subscribed to.
+    pub fn publisher_name(&self) -> &'static str {
+        self.publisher_name
+    }
+
+    /// Returns true until the subscription is detached or the publisher stream ends.
+    pub fn is_active(&self) -> bool {
+        !self.stream.is_detached()
+    }
+
+    /// Detaches the message stream of the publisher from the subscriber.
+    ///
+    /// The stream is dropped right away, so that the publisher can be bound again; the
+    /// messages it still has queued are kept for its next subscriber.
+    ///
+    /// # Returns
+    /// `false` if the subscription was not active anymore
+    pub fn unsubscribe(self) -> bool {
+        self.stream.detach()
+    }
+}
+
+impl Debug for Subscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subscription")
+            .field("publisher_name", &self.publisher_name)
+            .field("active", &self.is_active())
+            .finish()
+    }
+}
+
+/// The type-erased side of a [`DetachableStream`] used by the [`Subscription`] handles.
+pub(crate) trait Detach: Send + Sync {
+    /// Drops the stream and wakes its subscriber up, returns false if it was already gone.
+    fn detach(&self) -> bool;
+
+    /// Returns true once the stream has been dropped.
+    fn is_detached(&self) -> bool;
+}
+
+/// A message stream that can be dropped through its [`Subscription`] while its subscriber
+/// keeps polling it.
+pub(crate) struct DetachableStream<Message> {
+    stream: Mutex<Option<MessageStream<Message>>>,
+    /// Wakes the subscriber up once the stream is detached
+    waker: AtomicWaker,
+}
+
+impl<Message> DetachableStream<Message> {
+    pub(crate) fn new(stream: MessageStream<Message>) -> Arc<Self> {
+        Arc::new(Self {
+            stream: Mutex::new(Some(stream)),
+            waker: AtomicWaker::new(),
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<MessageStream<Message>>> {
+        // A panic while polling the stream leaves it in a state it is able to handle.
+        self.stream
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Polls the next message of the stream, which ends once the stream is detached.
+    pub(crate) fn poll_next(&self, cx: &mut Context<'_>) -> Poll<Option<Message>> {
+        self.waker.register(cx.waker());
+
+        let mut stream = self.lock();
+        let Some(inner) = stream.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        let message = inner.poll_next_unpin(cx);
+        if let Poll::Ready(None) = message {
+            *stream = None;
+        }
+        message
+    }
+}
+
+impl<Message> Detach for DetachableStream<Message>
+where
+    Message: Send,
+{
+    fn detach(&self) -> bool {
+        let stream = self.lock().take();
+        let detached = stream.is_some();
+
+        // The stream is dropped outside of the lock, releasing the publisher.
+        drop(stream);
+        self.waker.wake();
+        detached
+    }
+
+    fn is_detached(&self) -> bool {
+        self.lock().is_none()
+    }
+}
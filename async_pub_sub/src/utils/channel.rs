@@ -0,0 +1,316 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+};
+
+use futures::{future::poll_fn, Stream};
+
+/// Creates a bounded single-consumer channel.
+///
+/// Unlike `futures::channel::mpsc`, the capacity is a hard limit shared by every
+/// sender, and the sending side can decide what to do with a message when the
+/// queue is full (see [`Sender::try_send`]).
+///
+/// # Arguments
+/// * `capacity` - The maximum number of queued messages (at least one)
+pub(crate) fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            capacity: capacity.max(1),
+            sender_count: 1,
+            closed: false,
+            receiver_dropped: false,
+            receiver_waker: None,
+            sender_wakers: Vec::new(),
+        }),
+    });
+
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+/// What a sender does with a message that does not fit in a full queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FullQueue {
+    /// Evict the oldest queued message to make room for the new one.
+    DropOldest,
+    /// Discard the new message and keep the queue untouched.
+    DropNewest,
+    /// Hand the new message back to the caller.
+    Reject,
+}
+
+/// Outcome of a successful [`Sender::try_send`].
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum TrySent {
+    /// The message was queued without dropping anything.
+    Queued,
+    /// The message was queued after evicting the oldest one.
+    DroppedOldest,
+    /// The queue was full and the message was discarded.
+    DroppedNewest,
+}
+
+/// Reasons why [`Sender::try_send`] could not queue a message; the message is handed back.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum TrySendError<T> {
+    Full(T),
+    Disconnected(T),
+    Closed(T),
+}
+
+/// Reasons why [`Sender::send`] could not queue a message; the message is handed back.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum SendError<T> {
+    Disconnected(T),
+    Closed(T),
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+}
+
+struct State<T> {
+    queue: VecDeque<T>,
+    capacity: usize,
+    sender_count: usize,
+    closed: bool,
+    receiver_dropped: bool,
+    receiver_waker: Option<Waker>,
+    sender_wakers: Vec<Waker>,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        // A panic while holding the lock cannot leave the queue in an invalid state.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<T> State<T> {
+    fn push(&mut self, message: T) {
+        self.queue.push_back(message);
+        self.wake_receiver();
+    }
+
+    fn wake_senders(&mut self) {
+        self.sender_wakers.drain(..).for_each(Waker::wake);
+    }
+
+    fn is_full(&self) -> bool {
+        self.queue.len() >= self.capacity
+    }
+
+    fn wake_receiver(&mut self) {
+        if let Some(waker) = self.receiver_waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// The sending half of a [`channel`].
+pub(crate) struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Queues a message, waiting for room if the queue is full.
+    ///
+    /// Resolves to an error handing the message back if the receiver has been dropped
+    /// or the channel has been closed.
+    pub(crate) fn send(&self, message: T) -> SendFuture<'_, T> {
+        SendFuture {
+            sender: self,
+            message: Some(message),
+        }
+    }
+
+    /// Queues a message without waiting, applying `on_full` if there is no room.
+    pub(crate) fn try_send(
+        &self,
+        message: T,
+        on_full: FullQueue,
+    ) -> Result<TrySent, TrySendError<T>> {
+        let mut state = self.shared.lock();
+        if state.receiver_dropped {
+            return Err(TrySendError::Disconnected(message));
+        }
+        if state.closed {
+            return Err(TrySendError::Closed(message));
+        }
+
+        if !state.is_full() {
+            state.push(message);
+            return Ok(TrySent::Queued);
+        }
+
+        match on_full {
+            FullQueue::DropOldest => {
+                state.queue.pop_front();
+                state.push(message);
+                Ok(TrySent::DroppedOldest)
+            }
+            FullQueue::DropNewest => Ok(TrySent::DroppedNewest),
+            FullQueue::Reject => Err(TrySendError::Full(message)),
+        }
+    }
+
+    /// Returns true once the receiver has been dropped.
+    pub(crate) fn is_disconnected(&self) -> bool {
+        self.shared.lock().receiver_dropped
+    }
+
+    /// Returns true once the receiver has been dropped or the channel has been closed.
+    pub(crate) fn is_closed(&self) -> bool {
+        let state = self.shared.lock();
+        state.receiver_dropped || state.closed
+    }
+
+    /// Waits until the receiver is dropped or the channel is closed.
+    pub(crate) fn closed(&self) -> impl Future<Output = ()> + Send + '_
+    where
+        T: Send,
+    {
+        poll_fn(|cx| {
+            let mut state = self.shared.lock();
+            if state.receiver_dropped || state.closed {
+                return Poll::Ready(());
+            }
+
+            if !state.sender_wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                state.sender_wakers.push(cx.waker().clone());
+            }
+            Poll::Pending
+        })
+    }
+
+    /// Creates a new receiver once the previous one has been dropped.
+    ///
+    /// The messages queued before the previous receiver was dropped are kept for the new
+    /// one. Returns `None` if the previous receiver is still alive.
+    pub(crate) fn rebind(&self) -> Option<Receiver<T>> {
+        let mut state = self.shared.lock();
+        if !state.receiver_dropped {
+            return None;
+        }
+
+        state.receiver_dropped = false;
+        Some(Receiver {
+            shared: self.shared.clone(),
+        })
+    }
+
+    /// Closes the channel for every sender.
+    ///
+    /// Messages already queued are still delivered, then the receiver's stream ends.
+    pub(crate) fn close(&self) {
+        let mut state = self.shared.lock();
+        state.closed = true;
+        state.wake_receiver();
+        state.wake_senders();
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().sender_count += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.sender_count -= 1;
+        if state.sender_count == 0 {
+            state.wake_receiver();
+        }
+    }
+}
+
+/// Future returned by [`Sender::send`].
+pub(crate) struct SendFuture<'a, T> {
+    sender: &'a Sender<T>,
+    message: Option<T>,
+}
+
+impl<T> Unpin for SendFuture<'_, T> {}
+
+impl<T> Future for SendFuture<'_, T> {
+    type Output = Result<(), SendError<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let message = self
+            .message
+            .take()
+            .expect("SendFuture polled after completion");
+
+        let mut state = self.sender.shared.lock();
+        if state.receiver_dropped {
+            return Poll::Ready(Err(SendError::Disconnected(message)));
+        }
+        if state.closed {
+            return Poll::Ready(Err(SendError::Closed(message)));
+        }
+
+        if state.is_full() {
+            if !state.sender_wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                state.sender_wakers.push(cx.waker().clone());
+            }
+            drop(state);
+            self.message = Some(message);
+            return Poll::Pending;
+        }
+
+        state.push(message);
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// The receiving half of a [`channel`].
+///
+/// The stream ends once the queue is empty and every sender has been dropped or the
+/// channel has been closed.
+pub(crate) struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.shared.lock();
+        if let Some(message) = state.queue.pop_front() {
+            state.wake_senders();
+            return Poll::Ready(Some(message));
+        }
+
+        if state.sender_count == 0 || state.closed {
+            return Poll::Ready(None);
+        }
+
+        state.receiver_waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.receiver_dropped = true;
+        state.receiver_waker = None;
+        state.wake_senders();
+    }
+}
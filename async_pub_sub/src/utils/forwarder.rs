@@ -1,6 +1,10 @@
-use std::{fmt::Display, pin::Pin};
+use std::{
+    fmt::Display,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+};
 
-use crate::{Publisher, PublisherWrapper, Result, Subscriber, SubscriberImpl};
+use crate::{Error, Publisher, PublisherWrapper, Result, Subscriber, SubscriberImpl};
 use futures::{stream, FutureExt, Stream};
 
 // TODO: create logging forwarder using a middleware pattern
@@ -11,6 +15,9 @@ use futures::{stream, FutureExt, Stream};
 /// The forwarder acts as both a subscriber (to receive messages) and a publisher (to forward messages).
 /// It logs each message that passes through it, providing visibility into the message flow.
 ///
+/// Once the stream of its subscriber is dropped, e.g. after an unsubscription, the forwarder
+/// keeps its subscriptions and can be bound to another subscriber.
+///
 /// # Type Parameters
 /// * `Message` - The type of message being forwarded. Must implement `Display` and be `Send`.
 pub struct LoggingForwarder<Message>
@@ -21,8 +28,48 @@ where
     name: &'static str,
     /// The name of the subscriber this forwarder is connected to, if any
     subscriber_name: Option<&'static str>,
-    /// The internal subscriber implementation used to receive messages
+    /// The internal subscriber implementation used to receive messages, lent to the message
+    /// stream while a subscriber is bound
+    subscriber: SubscriberSlot<Message>,
+}
+
+/// The slot holding the internal subscriber of a [`LoggingForwarder`] while it is not bound.
+type SubscriberSlot<Message> = Arc<Mutex<Option<SubscriberImpl<Message>>>>;
+
+/// The internal subscriber of a [`LoggingForwarder`] lent to its message stream, put back
+/// in its slot once the stream is dropped.
+struct LentSubscriber<Message>
+where
+    Message: Send + 'static,
+{
     subscriber: Option<SubscriberImpl<Message>>,
+    slot: SubscriberSlot<Message>,
+}
+
+impl<Message> LentSubscriber<Message>
+where
+    Message: Send + 'static,
+{
+    async fn recv(&mut self) -> Option<Message> {
+        self.subscriber.as_mut()?.recv().await
+    }
+}
+
+impl<Message> Drop for LentSubscriber<Message>
+where
+    Message: Send + 'static,
+{
+    fn drop(&mut self) {
+        *lock(&self.slot) = self.subscriber.take();
+    }
+}
+
+fn lock<Message>(slot: &SubscriberSlot<Message>) -> MutexGuard<'_, Option<SubscriberImpl<Message>>>
+where
+    Message: Send + 'static,
+{
+    // The slot only holds the subscriber, it cannot be left in an invalid state.
+    slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
 }
 
 impl<Message> LoggingForwarder<Message>
@@ -37,7 +84,7 @@ where
         Self {
             name,
             subscriber_name: None,
-            subscriber: Some(SubscriberImpl::new(name)),
+            subscriber: Arc::new(Mutex::new(Some(SubscriberImpl::new(name)))),
         }
     }
 }
@@ -59,24 +106,23 @@ where
     /// * `publisher` - The publisher to subscribe to
     ///
     /// # Returns
-    /// * `Result<()>` - Ok if subscription successful, Err with description if failed
+    /// * `Result<()>` - Ok if subscription successful, [`Error::AlreadyForwarding`] if the
+    ///   forwarder already forwards its messages to its own subscriber
     fn subscribe_to(&mut self, publisher: &mut impl PublisherWrapper<Self::Message>) -> Result<()> {
-        let Some(subscriber) = self.subscriber.as_mut() else {
+        let mut subscriber = lock(&self.subscriber);
+        let Some(subscriber) = subscriber.as_mut() else {
             let subscriber_name = self
                 .subscriber_name
                 .expect("the subscriber name should be known at this point");
 
-            return Err(format!(
-                "{} forwarder has already been bound to {}, subscribe to {} before {} subscribes to it",
+            // Subscriptions must happen before the forwarder is bound to its own subscriber.
+            return Err(Error::already_forwarding::<Message>(
                 self.name,
                 subscriber_name,
-                self.name,
-                subscriber_name
-            )
-            .into());
+            ));
         };
 
-        subscriber.subscribe_to(publisher)
+        subscriber.subscribe_to(publisher).map(|_| ())
     }
 
     /// Not implemented for LoggingForwarder. Will panic if called.
@@ -97,10 +143,15 @@ where
     }
 
     /// Not implemented for LoggingForwarder. Will panic if called.
-    fn publish(&self, _message: Message) -> futures::future::BoxFuture<Result<()>> {
+    fn publish(&self, _message: Message) -> futures::future::BoxFuture<'_, Result<()>> {
         async move { panic!("LoggingForwarder does not implement publish method") }.boxed()
     }
 
+    /// Returns true while a subscriber is bound to the forwarder.
+    fn is_bound(&self) -> bool {
+        lock(&self.subscriber).is_none()
+    }
+
     /// Creates a message stream that logs messages as they pass through.
     ///
     /// # Arguments
@@ -108,27 +159,29 @@ where
     ///
     /// # Returns
     /// * `Result<Pin<Box<dyn Stream<Item = Message>>>>` - A stream of messages if successful,
-    ///    Err with description if the forwarder is already bound to another subscriber
+    ///   [`Error::AlreadyBound`] if the forwarder is already bound to another subscriber
     fn get_message_stream(
         &mut self,
         subscriber_name: &'static str,
     ) -> Result<Pin<Box<dyn Stream<Item = Message> + Send + Sync + 'static>>> {
-        let Some(subscriber) = self.subscriber.take() else {
-            return Err(format!(
-                "{} forwarder can only be bound to one subscriber (already bound to {})",
+        let Some(subscriber) = lock(&self.subscriber).take() else {
+            return Err(Error::already_bound::<Message>(
                 self.name,
                 self.subscriber_name
-                    .expect("the subscriber name should be known at this point")
-            )
-            .into());
+                    .expect("the subscriber name should be known at this point"),
+            ));
         };
         self.subscriber_name = Some(subscriber_name);
 
         let name = self.name;
+        let subscriber = LentSubscriber {
+            subscriber: Some(subscriber),
+            slot: self.subscriber.clone(),
+        };
         let stream = Box::pin(stream::unfold(
             subscriber,
             move |mut subscriber| async move {
-                let message = subscriber.receive().await;
+                let message = subscriber.recv().await?;
                 log::info!("[{}] -> [{}]: {}", name, subscriber_name, message);
                 Some((message, subscriber))
             },
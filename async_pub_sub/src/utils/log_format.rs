@@ -0,0 +1,36 @@
+use std::fmt::Display;
+
+/// Formats the messages logged by [`crate::LoggingPublisherLayer`] and
+/// [`crate::LoggingSubscriberLayer`].
+///
+/// Every `Display` message is logged with the names of the publisher and the subscriber,
+/// as `[publisher] -> [subscriber]: message`. An [`crate::Envelope`] is logged with its
+/// metadata instead.
+pub trait LogFormat {
+    /// Formats the message when it is published.
+    ///
+    /// # Arguments
+    /// * `publisher` - The name of the publisher
+    /// * `subscriber` - The name of the subscriber bound to the publisher
+    fn format_published(&self, publisher: &'static str, subscriber: &'static str) -> String;
+
+    /// Formats the message when it is received.
+    ///
+    /// # Arguments
+    /// * `subscriber` - The name of the subscriber
+    /// * `publishers` - The names of the publishers the subscriber is bound to
+    fn format_received(&self, subscriber: &'static str, publishers: &[&'static str]) -> String;
+}
+
+impl<T> LogFormat for T
+where
+    T: Display,
+{
+    fn format_published(&self, publisher: &'static str, subscriber: &'static str) -> String {
+        format!("[{}] -> [{}]: {}", publisher, subscriber, self)
+    }
+
+    fn format_received(&self, subscriber: &'static str, publishers: &[&'static str]) -> String {
+        format!("[{}] <- [{}]: {}", subscriber, publishers.join(", "), self)
+    }
+}
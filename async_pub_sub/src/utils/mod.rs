@@ -1,3 +1,8 @@
+pub(crate) mod channel;
 mod forwarder;
+mod log_format;
+mod shutdown;
 
 pub use forwarder::LoggingForwarder;
+pub use log_format::LogFormat;
+pub use shutdown::{Shutdown, ShutdownCoordinator, ShutdownSignal};
@@ -0,0 +1,269 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+};
+
+use futures::{future::poll_fn, FutureExt};
+
+/// The triggering side of a shutdown.
+///
+/// A `Shutdown` hands out [`ShutdownSignal`]s to the tasks that must stop. Once
+/// [`Shutdown::trigger`] is called every signal resolves, and [`Shutdown::stopped`]
+/// resolves when every signal has been dropped, i.e. when every task has stopped.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{PublisherImpl, Shutdown, Subscriber, SubscriberImpl};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let shutdown = Shutdown::new();
+///     let mut publisher = PublisherImpl::<i32>::new("publisher", 10);
+///     let mut subscriber = SubscriberImpl::new("subscriber").with_shutdown(shutdown.signal());
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     let consumer = tokio::spawn(async move {
+///         while let Some(message) = subscriber.recv().await {
+///             println!("received {}", message);
+///         }
+///     });
+///
+///     publisher.publish(42).await.unwrap();
+///     shutdown.shutdown().await;
+///     consumer.await.unwrap();
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct Shutdown {
+    shared: Arc<Shared>,
+}
+
+/// A future that resolves once the associated [`Shutdown`] is triggered.
+///
+/// Every live signal (including clones) keeps the shutdown from being reported as
+/// stopped, so tasks should drop their signal when they exit.
+pub struct ShutdownSignal {
+    shared: Arc<Shared>,
+}
+
+#[derive(Default)]
+struct Shared {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    triggered: bool,
+    signal_count: usize,
+    trigger_wakers: Vec<Waker>,
+    stopped_wakers: Vec<Waker>,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // A panic while holding the lock cannot leave the state inconsistent.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn register(wakers: &mut Vec<Waker>, waker: &Waker) {
+    if !wakers.iter().any(|w| w.will_wake(waker)) {
+        wakers.push(waker.clone());
+    }
+}
+
+impl Shutdown {
+    /// Creates a new, untriggered shutdown.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new signal resolving once this shutdown is triggered.
+    pub fn signal(&self) -> ShutdownSignal {
+        self.shared.lock().signal_count += 1;
+        ShutdownSignal {
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// Triggers the shutdown, resolving every associated signal.
+    pub fn trigger(&self) {
+        let mut state = self.shared.lock();
+        state.triggered = true;
+        state.trigger_wakers.drain(..).for_each(Waker::wake);
+    }
+
+    /// Returns true once the shutdown has been triggered.
+    pub fn is_triggered(&self) -> bool {
+        self.shared.lock().triggered
+    }
+
+    /// Waits until every signal of this shutdown has been dropped.
+    pub fn stopped(&self) -> impl Future<Output = ()> + Send + '_ {
+        poll_fn(|cx| {
+            let mut state = self.shared.lock();
+            if state.signal_count == 0 {
+                return Poll::Ready(());
+            }
+
+            register(&mut state.stopped_wakers, cx.waker());
+            Poll::Pending
+        })
+    }
+
+    /// Triggers the shutdown and waits until every signal has been dropped.
+    pub async fn shutdown(&self) {
+        self.trigger();
+        self.stopped().await
+    }
+}
+
+impl ShutdownSignal {
+    /// Returns true once the shutdown has been triggered.
+    pub fn is_triggered(&self) -> bool {
+        self.shared.lock().triggered
+    }
+
+    /// Runs a future until it completes or the shutdown is triggered.
+    ///
+    /// The future is polled before the signal, so a future that is ready when the
+    /// shutdown is triggered still completes.
+    ///
+    /// # Arguments
+    /// * `future` - The future to run
+    ///
+    /// # Returns
+    /// The output of the future, or `None` if the shutdown was triggered first
+    pub async fn until_shutdown<F: Future>(&self, future: F) -> Option<F::Output> {
+        let mut future = std::pin::pin!(future);
+        let mut signal = self.clone();
+
+        poll_fn(|cx| {
+            if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                return Poll::Ready(Some(output));
+            }
+
+            signal.poll_unpin(cx).map(|_| None)
+        })
+        .await
+    }
+}
+
+impl Future for ShutdownSignal {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.lock();
+        if state.triggered {
+            return Poll::Ready(());
+        }
+
+        register(&mut state.trigger_wakers, cx.waker());
+        Poll::Pending
+    }
+}
+
+impl Clone for ShutdownSignal {
+    fn clone(&self) -> Self {
+        self.shared.lock().signal_count += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl Drop for ShutdownSignal {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.signal_count -= 1;
+        if state.signal_count == 0 {
+            state.stopped_wakers.drain(..).for_each(Waker::wake);
+        }
+    }
+}
+
+/// Stops a topology of services stage by stage.
+///
+/// Each stage gets its own [`ShutdownSignal`]. [`ShutdownCoordinator::shutdown`] triggers
+/// the stages in the order they were added and waits for every signal of a stage to be
+/// dropped before moving to the next one. A typical topology adds the producers first,
+/// so that they close their publishers, then the consumers, which drain their queues
+/// before stopping.
+///
+/// # Example
+///
+/// ```
+/// use async_pub_sub::{PublisherImpl, ShutdownCoordinator, Subscriber, SubscriberImpl};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut coordinator = ShutdownCoordinator::new();
+///     let producer_signal = coordinator.add_stage("producers");
+///     let consumer_signal = coordinator.add_stage("consumers");
+///
+///     let mut publisher = PublisherImpl::<i32>::new("publisher", 10);
+///     let mut subscriber = SubscriberImpl::new("subscriber").with_shutdown(consumer_signal);
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     tokio::spawn(async move {
+///         let mut value = 0;
+///         while !producer_signal.is_triggered() {
+///             publisher.publish(value).await.unwrap();
+///             value += 1;
+///             tokio::task::yield_now().await;
+///         }
+///         publisher.close();
+///     });
+///
+///     let consumer = tokio::spawn(async move {
+///         let mut received = 0;
+///         while subscriber.recv().await.is_some() {
+///             received += 1;
+///         }
+///         received
+///     });
+///
+///     coordinator.shutdown().await;
+///     println!("received {} messages", consumer.await.unwrap());
+/// }
+/// ```
+#[derive(Default)]
+pub struct ShutdownCoordinator {
+    stages: Vec<(&'static str, Shutdown)>,
+}
+
+impl ShutdownCoordinator {
+    /// Creates a coordinator without any stage.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a stage stopped after every previously added stage.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the stage, used for logging
+    ///
+    /// # Returns
+    /// The signal of the stage, to clone into each of its tasks
+    pub fn add_stage(&mut self, name: &'static str) -> ShutdownSignal {
+        let shutdown = Shutdown::new();
+        let signal = shutdown.signal();
+        self.stages.push((name, shutdown));
+        signal
+    }
+
+    /// Stops every stage in order.
+    pub async fn shutdown(self) {
+        for (name, shutdown) in self.stages {
+            log::info!("({}): shutting down", name);
+            shutdown.shutdown().await;
+            log::info!("({}): stopped", name);
+        }
+    }
+}
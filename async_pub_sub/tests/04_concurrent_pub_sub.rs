@@ -20,7 +20,7 @@ async fn test_request_publisher() -> Result<()> {
     });
 
     // -- Check
-    tokio::try_join!(publisher_task, subscriber_task)?;
+    tokio::try_join!(publisher_task, subscriber_task).expect("tasks should not panic");
 
     Ok(())
 }
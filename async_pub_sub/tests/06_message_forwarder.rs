@@ -63,7 +63,7 @@ where
         self.name
     }
 
-    fn publish(&self, _message: Message) -> futures::future::BoxFuture<Result<()>> {
+    fn publish(&self, _message: Message) -> futures::future::BoxFuture<'_, Result<()>> {
         async move { panic!("LoggingForwarder does not implement publish method") }.boxed()
     }
 
@@ -119,7 +119,7 @@ async fn test_message_forwarder() -> Result<()> {
     });
 
     // -- Check
-    tokio::try_join!(publisher_task, subscriber_task)?;
+    tokio::try_join!(publisher_task, subscriber_task).expect("tasks should not panic");
 
     Ok(())
 }
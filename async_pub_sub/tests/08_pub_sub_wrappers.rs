@@ -37,7 +37,7 @@ impl Subscriber for Service {
     }
 
     fn subscribe_to(&mut self, publisher: &mut impl PublisherWrapper<Self::Message>) -> Result<()> {
-        self.subscriber.subscribe_to(publisher)
+        Subscriber::subscribe_to(&mut self.subscriber, publisher)
     }
 
     fn receive(&mut self) -> impl std::future::Future<Output = i32> {
@@ -52,7 +52,7 @@ impl Publisher for Service {
         Publisher::get_name(&self.publisher)
     }
 
-    fn publish(&self, message: String) -> futures::future::BoxFuture<Result<()>> {
+    fn publish(&self, message: String) -> futures::future::BoxFuture<'_, Result<()>> {
         Publisher::publish(&self.publisher, message)
     }
 
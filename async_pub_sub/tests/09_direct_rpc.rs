@@ -98,7 +98,7 @@ impl Subscriber for Service {
         &mut self,
         publisher: &mut impl async_pub_sub::PublisherWrapper<Self::Message>,
     ) -> Result<()> {
-        self.subscriber.subscribe_to(publisher)
+        Subscriber::subscribe_to(&mut self.subscriber, publisher)
     }
 
     fn receive(&mut self) -> impl std::future::Future<Output = ServiceRequest> {
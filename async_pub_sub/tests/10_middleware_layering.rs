@@ -39,7 +39,7 @@ where
         self.publisher.get_name()
     }
 
-    fn publish(&self, message: Self::Message) -> BoxFuture<async_pub_sub::Result<()>> {
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, async_pub_sub::Result<()>> {
         async move {
             let message_str = format!("{}", &message);
             let result = self.publisher.publish(message).await;
@@ -0,0 +1,131 @@
+use async_pub_sub::{BroadcastPublisher, OverflowPolicy, Publisher, Result, SubscriberImpl};
+use async_pub_sub_macros::routes;
+
+#[derive(Debug, Clone, PartialEq)]
+struct TimerNotification(u32);
+
+#[test_log::test(tokio::test)]
+async fn test_broadcast_to_multiple_subscribers() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = BroadcastPublisher::new("timer", 10);
+    let mut subscriber_a = SubscriberImpl::new("subscriber_a");
+    let mut subscriber_b = SubscriberImpl::new("subscriber_b");
+    let mut subscriber_c = SubscriberImpl::new("subscriber_c");
+
+    routes!(
+        publisher -> subscriber_a,
+        publisher -> subscriber_b,
+        publisher -> subscriber_c,
+    )?;
+
+    // -- Exec
+    publisher.publish(TimerNotification(1)).await?;
+    publisher.publish(TimerNotification(2)).await?;
+
+    // -- Check
+    for subscriber in [&mut subscriber_a, &mut subscriber_b, &mut subscriber_c] {
+        assert_eq!(subscriber.receive().await, TimerNotification(1));
+        assert_eq!(subscriber.receive().await, TimerNotification(2));
+    }
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_broadcast_drop_oldest_for_lagging_subscriber() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher =
+        BroadcastPublisher::new("publisher", 2).with_overflow_policy(OverflowPolicy::DropOldest);
+    let mut fast_subscriber = SubscriberImpl::new("fast_subscriber");
+    let mut slow_subscriber = SubscriberImpl::new("slow_subscriber");
+
+    fast_subscriber.subscribe_to(&mut publisher)?;
+    slow_subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    for value in 0..4 {
+        publisher.publish(value).await?;
+        assert_eq!(fast_subscriber.receive().await, value);
+    }
+
+    // -- Check
+    assert_eq!(slow_subscriber.receive().await, 2);
+    assert_eq!(slow_subscriber.receive().await, 3);
+    assert_eq!(publisher.dropped_count(), 2);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_broadcast_drop_newest_for_lagging_subscriber() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher =
+        BroadcastPublisher::new("publisher", 2).with_overflow_policy(OverflowPolicy::DropNewest);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    for value in 0..4 {
+        publisher.publish(value).await?;
+    }
+
+    // -- Check
+    assert_eq!(subscriber.receive().await, 0);
+    assert_eq!(subscriber.receive().await, 1);
+    assert_eq!(publisher.dropped_count(), 2);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_broadcast_wait_applies_backpressure() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = BroadcastPublisher::new("publisher", 1);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    let publisher_task = tokio::spawn(async move {
+        for value in 0..3 {
+            publisher
+                .publish(value)
+                .await
+                .expect("publish should succeed");
+        }
+        publisher
+    });
+
+    // -- Check
+    for value in 0..3 {
+        assert_eq!(subscriber.receive().await, value);
+    }
+    let publisher = publisher_task.await.expect("task should not panic");
+    assert_eq!(publisher.dropped_count(), 0);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_broadcast_prunes_dropped_subscribers() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = BroadcastPublisher::new("publisher", 1);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+    {
+        let mut dropped_subscriber = SubscriberImpl::<i32>::new("dropped_subscriber");
+        dropped_subscriber.subscribe_to(&mut publisher)?;
+        assert_eq!(publisher.subscriber_count(), 2);
+    }
+
+    // -- Exec
+    publisher.publish(42).await?;
+
+    // -- Check
+    assert_eq!(publisher.subscriber_count(), 1);
+    assert_eq!(subscriber.receive().await, 42);
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use async_pub_sub::{Error, LoggingForwarder, PublisherImpl, Request, Subscriber, SubscriberImpl};
+
+fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+#[test]
+fn test_error_is_send_and_sync() {
+    assert_send_sync::<Error>();
+}
+
+#[tokio::test]
+async fn test_already_bound_error() {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::<i32>::new("publisher", 1);
+    let mut first_subscriber = SubscriberImpl::new("first_subscriber");
+    let mut second_subscriber = SubscriberImpl::new("second_subscriber");
+
+    first_subscriber.subscribe_to(&mut publisher).unwrap();
+
+    // -- Exec
+    let error = second_subscriber.subscribe_to(&mut publisher).unwrap_err();
+
+    // -- Check
+    assert_eq!(
+        error,
+        Error::AlreadyBound {
+            publisher: "publisher",
+            subscriber: "first_subscriber",
+            message_type: "i32",
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_forwarder_already_bound_error() {
+    // -- Setup & Fixtures
+    let mut forwarder = LoggingForwarder::<i32>::new("forwarder");
+    let mut first_subscriber = SubscriberImpl::new("first_subscriber");
+    let mut second_subscriber = SubscriberImpl::new("second_subscriber");
+
+    first_subscriber.subscribe_to(&mut forwarder).unwrap();
+
+    // -- Exec
+    let error = second_subscriber.subscribe_to(&mut forwarder).unwrap_err();
+
+    // -- Check
+    assert!(matches!(
+        error,
+        Error::AlreadyBound {
+            publisher: "forwarder",
+            subscriber: "first_subscriber",
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_forwarder_already_forwarding_error() {
+    // -- Setup & Fixtures
+    let mut forwarder = LoggingForwarder::<i32>::new("forwarder");
+    let mut publisher = PublisherImpl::new("publisher", 1);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut forwarder).unwrap();
+
+    // -- Exec
+    let error = forwarder.subscribe_to(&mut publisher).unwrap_err();
+
+    // -- Check
+    assert_eq!(
+        error,
+        Error::AlreadyForwarding {
+            forwarder: "forwarder",
+            subscriber: "subscriber",
+            message_type: "i32",
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "forwarder forwarder (i32) is already forwarding to subscriber"
+    );
+}
+
+#[tokio::test]
+async fn test_disconnected_error() {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::<String>::new("publisher", 1);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher).unwrap();
+    drop(subscriber);
+
+    // -- Exec
+    let error = publisher.publish("hello".to_string()).await.unwrap_err();
+
+    // -- Check
+    match error {
+        Error::Disconnected {
+            publisher,
+            subscriber,
+            message_type,
+        } => {
+            assert_eq!(publisher, "publisher");
+            assert_eq!(subscriber, Some("subscriber"));
+            assert_eq!(message_type, std::any::type_name::<String>());
+        }
+        error => panic!("unexpected error: {}", error),
+    }
+}
+
+#[tokio::test]
+async fn test_response_dropped_error() {
+    // -- Setup & Fixtures
+    let (request, response) = Request::<i32, String>::new(42);
+
+    // -- Exec
+    drop(request);
+
+    // -- Check
+    assert_eq!(
+        response.await.unwrap_err(),
+        Error::ResponseDropped {
+            request_type: "i32",
+            response_type: std::any::type_name::<String>(),
+        }
+    );
+}
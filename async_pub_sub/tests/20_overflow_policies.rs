@@ -0,0 +1,155 @@
+use async_pub_sub::{
+    BroadcastPublisher, Error, LoggingPublisherLayer, OverflowPolicy, PublishOutcome, Publisher,
+    PublisherBuilder, PublisherImpl, Result, SubscriberImpl,
+};
+
+#[test_log::test(tokio::test)]
+async fn test_try_publish_block_policy_reports_full_channel() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::new("publisher", 1);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    let first = publisher.try_publish(1);
+    let second = publisher.try_publish(2);
+
+    // -- Check
+    assert_eq!(first, Ok(PublishOutcome::Delivered));
+    assert_eq!(
+        second,
+        Err(Error::channel_full::<i32>("publisher", Some("subscriber")))
+    );
+    assert_eq!(subscriber.receive().await, 1);
+    assert_eq!(publisher.dropped_count(), 0);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_drop_newest_policy() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher =
+        PublisherImpl::new("publisher", 2).with_overflow_policy(OverflowPolicy::DropNewest);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    let outcomes = (0..4)
+        .map(|value| publisher.try_publish(value))
+        .collect::<Result<Vec<_>>>()?;
+    publisher.publish(4).await?;
+
+    // -- Check
+    assert_eq!(
+        outcomes,
+        vec![
+            PublishOutcome::Delivered,
+            PublishOutcome::Delivered,
+            PublishOutcome::DroppedNewest,
+            PublishOutcome::DroppedNewest,
+        ]
+    );
+    assert_eq!(subscriber.receive().await, 0);
+    assert_eq!(subscriber.receive().await, 1);
+    assert_eq!(publisher.dropped_count(), 3);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_drop_oldest_policy() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher =
+        PublisherImpl::new("publisher", 2).with_overflow_policy(OverflowPolicy::DropOldest);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    for value in 0..3 {
+        publisher.publish(value).await?;
+    }
+    let outcome = publisher.try_publish(3)?;
+
+    // -- Check
+    assert_eq!(outcome, PublishOutcome::DroppedOldest);
+    assert!(outcome.is_dropped());
+    assert_eq!(subscriber.receive().await, 2);
+    assert_eq!(subscriber.receive().await, 3);
+    assert_eq!(publisher.dropped_count(), 2);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_error_policy_fails_publish() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher =
+        PublisherImpl::new("publisher", 1).with_overflow_policy(OverflowPolicy::Error);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(1).await?;
+    let error = publisher.publish(2).await.unwrap_err();
+
+    // -- Check
+    assert!(matches!(error, Error::ChannelFull { .. }));
+    assert_eq!(subscriber.receive().await, 1);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_try_publish_through_layers() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherBuilder::new(
+        PublisherImpl::new("publisher", 1).with_overflow_policy(OverflowPolicy::DropNewest),
+    )
+    .with_layer(LoggingPublisherLayer)
+    .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    let first = publisher.try_publish(1)?;
+    let second = publisher.try_publish(2)?;
+
+    // -- Check
+    assert_eq!(first, PublishOutcome::Delivered);
+    assert_eq!(second, PublishOutcome::DroppedNewest);
+    assert_eq!(subscriber.receive().await, 1);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_broadcast_try_publish_reports_drops() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher =
+        BroadcastPublisher::new("publisher", 1).with_overflow_policy(OverflowPolicy::DropOldest);
+    let mut fast_subscriber = SubscriberImpl::new("fast_subscriber");
+    let mut slow_subscriber = SubscriberImpl::new("slow_subscriber");
+
+    fast_subscriber.subscribe_to(&mut publisher)?;
+    slow_subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    let first = publisher.try_publish(1)?;
+    assert_eq!(fast_subscriber.receive().await, 1);
+    let second = publisher.try_publish(2)?;
+
+    // -- Check
+    assert_eq!(first, PublishOutcome::Delivered);
+    assert_eq!(second, PublishOutcome::DroppedOldest);
+    assert_eq!(fast_subscriber.receive().await, 2);
+    assert_eq!(slow_subscriber.receive().await, 2);
+    assert_eq!(publisher.dropped_count(), 1);
+
+    Ok(())
+}
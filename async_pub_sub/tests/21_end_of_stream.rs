@@ -0,0 +1,106 @@
+use async_pub_sub::{LoggingForwarder, PublisherImpl, Result, Subscriber, SubscriberImpl};
+
+mod interface {
+    use async_pub_sub_macros::rpc_interface;
+    #[rpc_interface]
+    pub trait RpcInterface {
+        async fn add_one(&self, value: i32) -> i32;
+    }
+}
+
+mod server {
+    use super::interface::RpcInterface;
+    use async_pub_sub::SubscriberImpl;
+    use async_pub_sub_macros::DeriveSubscriber;
+
+    use super::interface::RpcInterfaceMessage;
+
+    #[derive(DeriveSubscriber)]
+    pub struct RpcServer {
+        #[subscriber(RpcInterfaceMessage)]
+        pub subscriber: SubscriberImpl<RpcInterfaceMessage>,
+    }
+
+    impl RpcInterface for RpcServer {
+        async fn add_one(&self, value: i32) -> i32 {
+            value + 1
+        }
+    }
+}
+
+use interface::{RpcInterface, RpcInterfaceClient, RpcInterfaceMessage, RpcInterfaceServer};
+
+impl RpcInterfaceClient for PublisherImpl<RpcInterfaceMessage> {}
+
+#[test_log::test(tokio::test)]
+async fn test_recv_drains_queue_then_reports_end_of_stream() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher_a = PublisherImpl::new("publisher_a", 2);
+    let mut publisher_b = PublisherImpl::new("publisher_b", 2);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher_a)?;
+    subscriber.subscribe_to(&mut publisher_b)?;
+
+    // -- Exec
+    publisher_a.publish(1).await?;
+    publisher_b.publish(2).await?;
+    drop(publisher_a);
+
+    let first = subscriber.recv().await;
+    let second = subscriber.recv().await;
+    drop(publisher_b);
+    let end = subscriber.recv().await;
+
+    // -- Check
+    let mut received = vec![first, second];
+    received.sort();
+    assert_eq!(received, vec![Some(1), Some(2)]);
+    assert_eq!(end, None);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_forwarder_stream_ends_with_its_publisher() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::new("publisher", 1);
+    let mut forwarder = LoggingForwarder::new("forwarder");
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    forwarder.subscribe_to(&mut publisher)?;
+    subscriber.subscribe_to(&mut forwarder)?;
+
+    // -- Exec
+    publisher.publish(42).await?;
+    drop(publisher);
+
+    // -- Check
+    assert_eq!(subscriber.recv().await, Some(42));
+    assert_eq!(subscriber.recv().await, None);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_rpc_server_run_exits_when_clients_are_gone() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut rpc_server = server::RpcServer {
+        subscriber: SubscriberImpl::new("rpc_server"),
+    };
+    let mut rpc_client = PublisherImpl::<RpcInterfaceMessage>::new("rpc_client", 1);
+
+    rpc_server.subscribe_to(&mut rpc_client)?;
+
+    let server_task = tokio::spawn(async move { rpc_server.run().await });
+
+    // -- Exec
+    let response = RpcInterface::add_one(&rpc_client, 1).await;
+    drop(rpc_client);
+
+    // -- Check
+    assert_eq!(response, 2);
+    server_task.await.expect("server loop should exit cleanly");
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use std::sync::{Arc, Mutex};
+
+use async_pub_sub::{
+    BroadcastPublisher, Error, Publisher, PublisherImpl, Result, Shutdown, ShutdownCoordinator,
+    Subscriber, SubscriberImpl,
+};
+
+mod interface {
+    use async_pub_sub_macros::rpc_interface;
+    #[rpc_interface]
+    pub trait RpcInterface {
+        async fn add_one(&self, value: i32) -> i32;
+    }
+}
+
+mod server {
+    use super::interface::{RpcInterface, RpcInterfaceMessage};
+    use async_pub_sub::SubscriberImpl;
+    use async_pub_sub_macros::DeriveSubscriber;
+
+    #[derive(DeriveSubscriber)]
+    pub struct RpcServer {
+        #[subscriber(RpcInterfaceMessage)]
+        pub subscriber: SubscriberImpl<RpcInterfaceMessage>,
+    }
+
+    impl RpcInterface for RpcServer {
+        async fn add_one(&self, value: i32) -> i32 {
+            value + 1
+        }
+    }
+}
+
+use interface::{RpcInterface, RpcInterfaceClient, RpcInterfaceMessage, RpcInterfaceServer};
+
+impl RpcInterfaceClient for PublisherImpl<RpcInterfaceMessage> {}
+
+#[test_log::test(tokio::test)]
+async fn test_close_drains_queued_messages() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::new("publisher", 3);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(1).await?;
+    publisher.publish(2).await?;
+    publisher.close();
+    let error = publisher.publish(3).await.unwrap_err();
+
+    // -- Check
+    assert_eq!(error, Error::closed::<i32>("publisher"));
+    assert_eq!(subscriber.recv().await, Some(1));
+    assert_eq!(subscriber.recv().await, Some(2));
+    assert_eq!(subscriber.recv().await, None);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_broadcast_close() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = BroadcastPublisher::new("publisher", 2);
+    let mut subscriber_a = SubscriberImpl::new("subscriber_a");
+    let mut subscriber_b = SubscriberImpl::new("subscriber_b");
+
+    subscriber_a.subscribe_to(&mut publisher)?;
+    subscriber_b.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(1).await?;
+    Publisher::close(&publisher);
+
+    // -- Check
+    assert!(matches!(
+        publisher.try_publish(2),
+        Err(Error::Closed { .. })
+    ));
+    for subscriber in [&mut subscriber_a, &mut subscriber_b] {
+        assert_eq!(subscriber.recv().await, Some(1));
+        assert_eq!(subscriber.recv().await, None);
+    }
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_subscriber_stops_on_shutdown_after_queued_messages() -> Result<()> {
+    // -- Setup & Fixtures
+    let shutdown = Shutdown::new();
+    let mut publisher = PublisherImpl::new("publisher", 2);
+    let mut subscriber = SubscriberImpl::new("subscriber").with_shutdown(shutdown.signal());
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(1).await?;
+    shutdown.trigger();
+
+    // -- Check
+    assert_eq!(subscriber.recv().await, Some(1));
+    assert_eq!(subscriber.recv().await, None);
+
+    drop(subscriber);
+    shutdown.stopped().await;
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_rpc_server_run_until_shutdown() -> Result<()> {
+    // -- Setup & Fixtures
+    let shutdown = Shutdown::new();
+    let mut rpc_server = server::RpcServer {
+        subscriber: SubscriberImpl::new("rpc_server"),
+    };
+    let mut rpc_client = PublisherImpl::<RpcInterfaceMessage>::new("rpc_client", 1);
+
+    rpc_server.subscribe_to(&mut rpc_client)?;
+
+    let signal = shutdown.signal();
+    let server_task = tokio::spawn(async move { rpc_server.run_until_shutdown(signal).await });
+
+    // -- Exec
+    let response = RpcInterface::add_one(&rpc_client, 1).await;
+    shutdown.shutdown().await;
+
+    // -- Check
+    assert_eq!(response, 2);
+    server_task.await.expect("server loop should exit cleanly");
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_coordinator_stops_stages_in_order() -> Result<()> {
+    // -- Setup & Fixtures
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut coordinator = ShutdownCoordinator::new();
+    let producer_signal = coordinator.add_stage("producers");
+    let consumer_signal = coordinator.add_stage("consumers");
+
+    let mut publisher = PublisherImpl::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber").with_shutdown(consumer_signal);
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    let producer_events = events.clone();
+    let producer = tokio::spawn(async move {
+        for value in 0..5 {
+            publisher.publish(value).await.unwrap();
+        }
+        producer_signal.clone().await;
+        producer_events.lock().unwrap().push("producer stopped");
+        publisher.close();
+    });
+
+    let consumer_events = events.clone();
+    let consumer = tokio::spawn(async move {
+        let mut received = Vec::new();
+        while let Some(value) = subscriber.recv().await {
+            received.push(value);
+        }
+        consumer_events.lock().unwrap().push("consumer stopped");
+        received
+    });
+
+    // -- Exec
+    coordinator.shutdown().await;
+
+    // -- Check
+    producer.await.expect("producer should not panic");
+    assert_eq!(
+        consumer.await.expect("consumer should not panic"),
+        vec![0, 1, 2, 3, 4]
+    );
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec!["producer stopped", "consumer stopped"]
+    );
+
+    Ok(())
+}
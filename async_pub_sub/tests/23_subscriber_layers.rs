@@ -0,0 +1,48 @@
+use async_pub_sub::{
+    DebuggingSubscriberLayer, LoggingSubscriberLayer, PublisherImpl, Result, Subscriber,
+    SubscriberBuilder, SubscriberImpl, SubscriberLayer,
+};
+
+#[test_log::test(tokio::test)]
+async fn test_subscriber_layer() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::new("publisher", 10);
+    let mut subscriber = LoggingSubscriberLayer.layer(SubscriberImpl::new("subscriber"));
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(42).await?;
+    let message = subscriber.receive().await;
+
+    // -- Check
+    assert_eq!(message, 42);
+    assert_eq!(subscriber.get_name(), "subscriber");
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_subscriber_builder() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher_a = PublisherImpl::new("publisher_a", 10);
+    let mut publisher_b = PublisherImpl::new("publisher_b", 10);
+    let mut subscriber = SubscriberBuilder::new(SubscriberImpl::new("subscriber"))
+        .with_layer(DebuggingSubscriberLayer)
+        .with_layer(LoggingSubscriberLayer)
+        .build();
+
+    subscriber.subscribe_to(&mut publisher_a)?;
+    subscriber.subscribe_to(&mut publisher_b)?;
+
+    // -- Exec
+    publisher_a.publish("hello".to_string()).await?;
+    drop(publisher_a);
+    drop(publisher_b);
+
+    // -- Check
+    assert_eq!(subscriber.recv().await, Some("hello".to_string()));
+    assert_eq!(subscriber.recv().await, None);
+
+    Ok(())
+}
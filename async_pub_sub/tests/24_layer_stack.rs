@@ -0,0 +1,143 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use async_pub_sub::{
+    Either, LayerStack, LoggingPublisherLayer, LoggingSubscriberLayer, Publisher, PublisherBuilder,
+    PublisherImpl, PublisherLayer, Result, Subscriber, SubscriberImpl,
+};
+use futures::{future::BoxFuture, Stream};
+
+/// Records the name of the layer each time a message goes through it.
+#[derive(Clone)]
+struct RecordingLayer {
+    name: &'static str,
+    records: Arc<Mutex<Vec<&'static str>>>,
+}
+
+struct RecordingPublisher<P> {
+    layer: RecordingLayer,
+    publisher: P,
+}
+
+impl<P> PublisherLayer<P> for RecordingLayer
+where
+    P: Publisher,
+{
+    type PublisherType = RecordingPublisher<P>;
+
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        RecordingPublisher {
+            layer: self.clone(),
+            publisher,
+        }
+    }
+}
+
+impl<P> Publisher for RecordingPublisher<P>
+where
+    P: Publisher,
+{
+    type Message = P::Message;
+
+    fn get_name(&self) -> &'static str {
+        self.publisher.get_name()
+    }
+
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        self.layer.records.lock().unwrap().push(self.layer.name);
+        self.publisher.publish(message)
+    }
+
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        self.publisher.get_message_stream(subscriber_name)
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn test_layer_stack_order() -> Result<()> {
+    // -- Setup & Fixtures
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let layer = |name| RecordingLayer {
+        name,
+        records: records.clone(),
+    };
+    let layers = LayerStack::new()
+        .with_layer(layer("inner"))
+        .with_layer(layer("outer"));
+
+    let mut publisher = layers.build_publisher(PublisherImpl::new("publisher", 1));
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(42).await?;
+
+    // -- Check
+    assert_eq!(subscriber.receive().await, 42);
+    assert_eq!(*records.lock().unwrap(), vec!["outer", "inner"]);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_layer_stack_is_reusable() -> Result<()> {
+    // -- Setup & Fixtures
+    let layers = LayerStack::new().with_layer(LoggingPublisherLayer);
+
+    let mut publisher_a = layers.build_publisher(PublisherImpl::new("publisher_a", 1));
+    let mut publisher_b = PublisherBuilder::new(PublisherImpl::new("publisher_b", 1))
+        .with_layer(layers)
+        .build();
+    let mut subscriber = LayerStack::new()
+        .with_layer(LoggingSubscriberLayer)
+        .build_subscriber(SubscriberImpl::new("subscriber"));
+
+    subscriber.subscribe_to(&mut publisher_a)?;
+    subscriber.subscribe_to(&mut publisher_b)?;
+
+    // -- Exec
+    publisher_a.publish(1).await?;
+    publisher_b.publish(2).await?;
+
+    // -- Check
+    let mut received = vec![subscriber.receive().await, subscriber.receive().await];
+    received.sort();
+    assert_eq!(received, vec![1, 2]);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_option_layer() -> Result<()> {
+    // -- Setup & Fixtures
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let enabled = LayerStack::new().with_option_layer(Some(RecordingLayer {
+        name: "enabled",
+        records: records.clone(),
+    }));
+    let disabled = LayerStack::new().with_option_layer(None::<RecordingLayer>);
+
+    let mut enabled_publisher = enabled.build_publisher(PublisherImpl::new("enabled", 1));
+    let mut disabled_publisher = disabled.build_publisher(PublisherImpl::new("disabled", 1));
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut enabled_publisher)?;
+    subscriber.subscribe_to(&mut disabled_publisher)?;
+
+    // -- Exec
+    enabled_publisher.publish(1).await?;
+    disabled_publisher.publish(2).await?;
+
+    // -- Check
+    assert!(matches!(enabled_publisher, Either::Left(_)));
+    assert!(matches!(disabled_publisher, Either::Right(_)));
+    assert_eq!(*records.lock().unwrap(), vec!["enabled"]);
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use async_pub_sub::{
+    FilterPublisherLayer, LayerStack, LoggingPublisherLayer, PublishOutcome, Publisher,
+    PublisherImpl, PublisherLayer, Result, SubscriberImpl,
+};
+
+#[test_log::test(tokio::test)]
+async fn test_filter_publisher() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = FilterPublisherLayer::new(|value: &i32| value % 2 == 0)
+        .layer(PublisherImpl::new("publisher", 10));
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    for value in 0..5 {
+        publisher.publish(value).await?;
+    }
+    let outcome = publisher.try_publish(5)?;
+    publisher.close();
+
+    // -- Check
+    assert_eq!(outcome, PublishOutcome::Filtered);
+    assert!(outcome.is_dropped());
+    assert_eq!(publisher.dropped_count(), 3);
+
+    let mut received = Vec::new();
+    while let Some(value) = subscriber.recv().await {
+        received.push(value);
+    }
+    assert_eq!(received, vec![0, 2, 4]);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_filter_layer_counts_every_publisher() -> Result<()> {
+    // -- Setup & Fixtures
+    let filter = FilterPublisherLayer::new(|message: &String| !message.is_empty());
+    let layers = LayerStack::new()
+        .with_layer(filter.clone())
+        .with_layer(LoggingPublisherLayer);
+
+    let mut publisher_a = layers.build_publisher(PublisherImpl::new("publisher_a", 10));
+    let mut publisher_b = layers.build_publisher(PublisherImpl::new("publisher_b", 10));
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher_a)?;
+    subscriber.subscribe_to(&mut publisher_b)?;
+
+    // -- Exec
+    publisher_a.publish(String::new()).await?;
+    publisher_b.publish(String::new()).await?;
+    publisher_b.publish("hello".to_string()).await?;
+
+    // -- Check
+    assert_eq!(subscriber.receive().await, "hello");
+    assert_eq!(filter.dropped_count(), 2);
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use async_pub_sub::{
+    Error, FilterMapPublisherLayer, MapPublisherLayer, PublishOutcome, Publisher, PublisherImpl,
+    PublisherLayer, Result, Subscriber, SubscriberImpl,
+};
+use async_pub_sub_macros::{routes, DerivePublisher, DeriveSubscriber};
+
+#[derive(Debug, Clone, PartialEq)]
+enum SensorMessage {
+    Temperature(i32),
+    Offline,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum MonitorMessage {
+    Reading(i32),
+}
+
+#[derive(DerivePublisher)]
+struct SensorService<P>
+where
+    P: Publisher<Message = SensorMessage>,
+{
+    publisher: P,
+}
+
+#[derive(DeriveSubscriber)]
+struct MonitorService {
+    #[subscriber(MonitorMessage)]
+    subscriber: SubscriberImpl<MonitorMessage>,
+}
+
+#[test_log::test(tokio::test)]
+async fn test_map_publisher() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    routes!(publisher -> subscriber)?;
+
+    let publisher = MapPublisherLayer::new(|value: i32| value * 2).layer(publisher);
+
+    // -- Exec
+    publisher.publish(1).await?;
+    let outcome = publisher.try_publish(2)?;
+    publisher.close();
+
+    // -- Check
+    assert_eq!(outcome, PublishOutcome::Delivered);
+    assert_eq!(subscriber.recv().await, Some(2));
+    assert_eq!(subscriber.recv().await, Some(4));
+    assert_eq!(subscriber.recv().await, None);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_services_with_different_message_types() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut monitor_publisher = PublisherImpl::new("sensor", 10);
+    let mut monitor = MonitorService {
+        subscriber: SubscriberImpl::new("monitor"),
+    };
+
+    routes!(monitor_publisher -> monitor: MonitorMessage)?;
+
+    let layer = FilterMapPublisherLayer::new(|message| match message {
+        SensorMessage::Temperature(value) => Some(MonitorMessage::Reading(value)),
+        SensorMessage::Offline => None,
+    });
+    let sensor = SensorService {
+        publisher: layer.layer(monitor_publisher),
+    };
+
+    // -- Exec
+    sensor.publish(SensorMessage::Temperature(20)).await?;
+    sensor.publish(SensorMessage::Offline).await?;
+    let outcome = sensor.try_publish(SensorMessage::Offline)?;
+    sensor.publish(SensorMessage::Temperature(21)).await?;
+
+    // -- Check
+    assert_eq!(outcome, PublishOutcome::Filtered);
+    assert_eq!(sensor.publisher.dropped_count(), 2);
+    assert_eq!(layer.dropped_count(), 2);
+    assert_eq!(monitor.receive().await, MonitorMessage::Reading(20));
+    assert_eq!(monitor.receive().await, MonitorMessage::Reading(21));
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_subscribing_to_mapped_publisher_fails() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut mapped = MapPublisherLayer::new(|value: i32| value.to_string())
+        .layer(PublisherImpl::new("mapped", 10));
+    let mut filter_mapped = FilterMapPublisherLayer::new(|value: String| value.parse::<i32>().ok())
+        .layer(PublisherImpl::new("filter_mapped", 10));
+    let mut subscriber = SubscriberImpl::new("subscriber");
+    let mut other_subscriber = SubscriberImpl::new("other_subscriber");
+
+    // -- Exec
+    let error = routes!(mapped -> subscriber).err();
+    let other_error = other_subscriber.subscribe_to(&mut filter_mapped).err();
+
+    // -- Check
+    assert_eq!(error, Some(Error::not_subscribable::<i32>("mapped")));
+    assert_eq!(
+        other_error,
+        Some(Error::not_subscribable::<String>("filter_mapped"))
+    );
+    assert_eq!(
+        error.unwrap().to_string(),
+        "mapped publisher (i32) cannot be subscribed to"
+    );
+
+    Ok(())
+}
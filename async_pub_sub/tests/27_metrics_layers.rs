@@ -0,0 +1,226 @@
+use std::sync::{Arc, Mutex};
+
+use async_pub_sub::{
+    BroadcastPublisher, FilterPublisherLayer, MetricsPublisherLayer, MetricsRecorder,
+    MetricsSubscriberLayer, OverflowPolicy, PrometheusRecorder, Publisher, PublisherBuilder,
+    PublisherImpl, Result, Subscriber, SubscriberBuilder, SubscriberImpl,
+};
+
+type Counter = (&'static str, Vec<(&'static str, &'static str)>, u64);
+
+#[derive(Debug, Clone, Default)]
+struct CountingRecorder {
+    counters: Arc<Mutex<Vec<Counter>>>,
+}
+
+impl MetricsRecorder for CountingRecorder {
+    fn increment_counter(
+        &self,
+        name: &'static str,
+        labels: &[(&'static str, &'static str)],
+        value: u64,
+    ) {
+        self.counters
+            .lock()
+            .unwrap()
+            .push((name, labels.to_vec(), value));
+    }
+
+    fn set_gauge(&self, _name: &'static str, _labels: &[(&'static str, &'static str)], _: f64) {}
+
+    fn record_histogram(
+        &self,
+        _name: &'static str,
+        _labels: &[(&'static str, &'static str)],
+        _: f64,
+    ) {
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn test_metrics_publisher_layer() -> Result<()> {
+    // -- Setup & Fixtures
+    let recorder = PrometheusRecorder::new();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+    let mut publisher = PublisherBuilder::new(
+        PublisherImpl::new("publisher", 2).with_overflow_policy(OverflowPolicy::Error),
+    )
+    .with_layer(MetricsPublisherLayer::new(recorder.clone()))
+    .build();
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(1).await?;
+    publisher.publish(2).await?;
+    let error = publisher.publish(3).await;
+    subscriber.receive().await;
+
+    // -- Check
+    assert!(error.is_err());
+
+    let output = recorder.render();
+    assert!(output.contains("# TYPE async_pub_sub_messages_published_total counter\n"));
+    assert!(output.contains(
+        "async_pub_sub_messages_published_total{publisher=\"publisher\",subscriber=\"subscriber\"} 2\n"
+    ));
+    assert!(output.contains(
+        "async_pub_sub_publish_failures_total{publisher=\"publisher\",error=\"channel_full\"} 1\n"
+    ));
+    assert!(output.contains("# TYPE async_pub_sub_queue_depth gauge\n"));
+    assert!(output.contains(
+        "async_pub_sub_queue_depth{publisher=\"publisher\",subscriber=\"subscriber\"} 1\n"
+    ));
+    assert!(output.contains("# TYPE async_pub_sub_publish_duration_seconds histogram\n"));
+    assert!(output.contains(
+        "async_pub_sub_publish_duration_seconds_bucket{publisher=\"publisher\",le=\"+Inf\"} 3\n"
+    ));
+    assert!(output
+        .contains("async_pub_sub_publish_duration_seconds_count{publisher=\"publisher\"} 3\n"));
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_metrics_per_subscriber() -> Result<()> {
+    // -- Setup & Fixtures
+    let recorder = PrometheusRecorder::new();
+    let mut publisher = PublisherBuilder::new(BroadcastPublisher::new("publisher", 10))
+        .with_layer(MetricsPublisherLayer::new(recorder.clone()))
+        .build();
+    let mut subscriber_a = SubscriberBuilder::new(SubscriberImpl::new("subscriber_a"))
+        .with_layer(MetricsSubscriberLayer::new(recorder.clone()))
+        .build();
+    let mut subscriber_b = SubscriberBuilder::new(SubscriberImpl::new("subscriber_b"))
+        .with_layer(MetricsSubscriberLayer::new(recorder.clone()))
+        .build();
+
+    subscriber_a.subscribe_to(&mut publisher)?;
+    subscriber_b.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    for value in 0..3 {
+        publisher.publish(value).await?;
+    }
+    for _ in 0..3 {
+        subscriber_a.receive().await;
+    }
+    subscriber_b.receive().await;
+
+    // -- Check
+    let output = recorder.render();
+    for expected in [
+        "async_pub_sub_messages_published_total{publisher=\"publisher\",subscriber=\"subscriber_a\"} 3\n",
+        "async_pub_sub_messages_published_total{publisher=\"publisher\",subscriber=\"subscriber_b\"} 3\n",
+        "async_pub_sub_queue_depth{publisher=\"publisher\",subscriber=\"subscriber_a\"} 0\n",
+        "async_pub_sub_queue_depth{publisher=\"publisher\",subscriber=\"subscriber_b\"} 2\n",
+        "async_pub_sub_messages_received_total{subscriber=\"subscriber_a\"} 3\n",
+        "async_pub_sub_messages_received_total{subscriber=\"subscriber_b\"} 1\n",
+    ] {
+        assert!(output.contains(expected), "missing {:?} in\n{}", expected, output);
+    }
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_custom_metrics_recorder() -> Result<()> {
+    // -- Setup & Fixtures
+    let recorder = CountingRecorder::default();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+    let mut publisher = PublisherBuilder::new(
+        PublisherImpl::new("publisher", 1).with_overflow_policy(OverflowPolicy::DropNewest),
+    )
+    .with_layer(MetricsPublisherLayer::new(recorder.clone()))
+    .build();
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.try_publish(1)?;
+    publisher.try_publish(2)?;
+
+    // -- Check
+    let counters = recorder.counters.lock().unwrap().clone();
+    assert_eq!(
+        counters,
+        vec![
+            (
+                "async_pub_sub_messages_published_total",
+                vec![("publisher", "publisher"), ("subscriber", "subscriber")],
+                1
+            ),
+            (
+                "async_pub_sub_messages_dropped_total",
+                vec![("publisher", "publisher")],
+                1
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_metrics_follow_publish_outcome() -> Result<()> {
+    // -- Setup & Fixtures
+    let recorder = PrometheusRecorder::new();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+    let mut publisher = PublisherBuilder::new(
+        PublisherImpl::new("publisher", 1).with_overflow_policy(OverflowPolicy::DropNewest),
+    )
+    .with_layer(FilterPublisherLayer::new(|value: &i32| *value >= 0))
+    .with_layer(MetricsPublisherLayer::new(recorder.clone()))
+    .build();
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(1).await?;
+    publisher.publish(2).await?;
+    publisher.publish(-1).await?;
+    subscriber.receive().await;
+
+    // -- Check
+    let output = recorder.render();
+    for expected in [
+        "async_pub_sub_messages_published_total{publisher=\"publisher\",subscriber=\"subscriber\"} 1\n",
+        "async_pub_sub_messages_dropped_total{publisher=\"publisher\"} 1\n",
+        "async_pub_sub_queue_depth{publisher=\"publisher\",subscriber=\"subscriber\"} 0\n",
+    ] {
+        assert!(output.contains(expected), "missing {:?} in\n{}", expected, output);
+    }
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_metrics_forget_unsubscribed_subscriber() -> Result<()> {
+    // -- Setup & Fixtures
+    let recorder = CountingRecorder::default();
+    let mut first = SubscriberImpl::new("first");
+    let mut second = SubscriberImpl::new("second");
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 1))
+        .with_layer(MetricsPublisherLayer::new(recorder.clone()))
+        .build();
+
+    let subscription = first.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    subscription.unsubscribe();
+    second.subscribe_to(&mut publisher)?;
+    publisher.publish(1).await?;
+
+    // -- Check
+    let counters = recorder.counters.lock().unwrap().clone();
+    assert_eq!(
+        counters,
+        vec![(
+            "async_pub_sub_messages_published_total",
+            vec![("publisher", "publisher"), ("subscriber", "second")],
+            1
+        )]
+    );
+
+    Ok(())
+}
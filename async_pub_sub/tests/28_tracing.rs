@@ -0,0 +1,202 @@
+#![cfg(feature = "tracing")]
+
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use async_pub_sub::{
+    Publisher, PublisherBuilder, PublisherImpl, Result, Subscriber, SubscriberImpl, Traceable,
+    Traced, TracingPublisherLayer,
+};
+use tracing::Instrument;
+
+mod interface {
+    use async_pub_sub_macros::rpc_interface;
+
+    #[rpc_interface]
+    pub trait PersistencyInterface {
+        async fn get_data(&self) -> Vec<u8>;
+        async fn store_data(&mut self, data: Vec<u8>);
+    }
+}
+
+mod client {
+    use super::interface::{PersistencyInterfaceClient, PersistencyInterfaceMessage};
+    use async_pub_sub::Publisher;
+    use async_pub_sub_macros::DerivePublisher;
+
+    #[derive(DerivePublisher)]
+    pub struct PersistencyClient<P>
+    where
+        P: Publisher<Message = PersistencyInterfaceMessage>,
+    {
+        pub publisher: P,
+    }
+
+    impl<P> PersistencyInterfaceClient for PersistencyClient<P> where
+        P: Publisher<Message = PersistencyInterfaceMessage>
+    {
+    }
+}
+
+mod server {
+    use super::interface::{PersistencyInterface, PersistencyInterfaceMessage};
+    use async_pub_sub::SubscriberImpl;
+    use async_pub_sub_macros::DeriveSubscriber;
+
+    #[derive(DeriveSubscriber)]
+    pub struct PersistencyService {
+        pub data: Vec<u8>,
+        #[subscriber(PersistencyInterfaceMessage)]
+        pub subscriber: SubscriberImpl<PersistencyInterfaceMessage>,
+    }
+
+    impl PersistencyInterface for PersistencyService {
+        async fn get_data(&self) -> Vec<u8> {
+            tracing::info!("getting data");
+            self.data.clone()
+        }
+
+        async fn store_data(&mut self, data: Vec<u8>) {
+            tracing::info!("storing data");
+            self.data = data;
+        }
+    }
+}
+
+use client::PersistencyClient;
+use interface::{PersistencyInterface, PersistencyInterfaceServer};
+use server::PersistencyService;
+
+/// Collects the formatted traces, so that the spans of each event can be checked.
+#[derive(Clone, Default)]
+struct TraceBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl TraceBuffer {
+    fn lines(&self) -> Vec<String> {
+        String::from_utf8(self.0.lock().unwrap().clone())
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+impl io::Write for TraceBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn trace_collector(buffer: &TraceBuffer) -> tracing::subscriber::DefaultGuard {
+    let buffer = buffer.clone();
+    let collector = tracing_subscriber::fmt()
+        .with_writer(move || buffer.clone())
+        .with_ansi(false)
+        .with_target(false)
+        .without_time()
+        .finish();
+    tracing::subscriber::set_default(collector)
+}
+
+#[test_log::test(tokio::test)]
+async fn test_rpc_request_is_traced_from_client_to_server() -> Result<()> {
+    // -- Setup & Fixtures
+    let buffer = TraceBuffer::default();
+    let _guard = trace_collector(&buffer);
+
+    let mut server = PersistencyService {
+        data: Vec::new(),
+        subscriber: SubscriberImpl::new("persistency"),
+    };
+    let mut client = PersistencyClient {
+        publisher: PublisherBuilder::new(PublisherImpl::new("client", 10))
+            .with_layer(TracingPublisherLayer)
+            .build(),
+    };
+
+    server.subscribe_to(&mut client)?;
+
+    // -- Exec
+    let client_task = async move {
+        client
+            .store_data(vec![1, 2, 3])
+            .instrument(tracing::info_span!("request", id = 1))
+            .await;
+
+        async {
+            let data = client.get_data().await;
+            tracing::info!("received {:?}", data);
+        }
+        .instrument(tracing::info_span!("request", id = 2))
+        .await;
+    };
+    tokio::join!(server.run(), client_task);
+
+    // -- Check
+    let lines = buffer.lines();
+    let expected = [
+        r#"request{id=1}:receive{subscriber="persistency"}: storing data"#,
+        r#"request{id=2}:receive{subscriber="persistency"}: getting data"#,
+        r#"request{id=2}: received [1, 2, 3]"#,
+    ];
+    for expected in expected {
+        assert!(
+            lines.iter().any(|line| line.ends_with(expected)),
+            "missing {:?} in {:#?}",
+            expected,
+            lines
+        );
+    }
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_traced_message_resumes_in_child_span() -> Result<()> {
+    // -- Setup & Fixtures
+    let buffer = TraceBuffer::default();
+    let _guard = trace_collector(&buffer);
+
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 10))
+        .with_layer(TracingPublisherLayer)
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher
+        .publish(Traced::new(42))
+        .instrument(tracing::info_span!("producer"))
+        .await?;
+    publisher.try_publish(Traced::new(43))?;
+
+    let first = subscriber.receive().await;
+    let second = subscriber.receive().await;
+    for message in [first, second] {
+        message
+            .trace_context()
+            .instrument("subscriber", async {
+                tracing::info!("handling {}", message);
+            })
+            .await;
+    }
+
+    // -- Check
+    let lines = buffer.lines();
+    assert!(lines
+        .iter()
+        .any(|line| line.ends_with(r#"producer:receive{subscriber="subscriber"}: handling 42"#)));
+    assert!(lines
+        .iter()
+        .any(|line| line.ends_with(r#" handling 43"#) && !line.contains("producer")));
+
+    Ok(())
+}
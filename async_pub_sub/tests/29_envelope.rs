@@ -0,0 +1,124 @@
+use std::time::SystemTime;
+
+use async_pub_sub::{
+    Envelope, EnvelopePublisherLayer, EnvelopeSubscriberLayer, LogFormat, LoggingPublisherLayer,
+    LoggingSubscriberLayer, Publisher, PublisherImpl, PublisherLayer, Result, Subscriber,
+    SubscriberBuilder, SubscriberImpl,
+};
+
+#[test_log::test(tokio::test)]
+async fn test_envelope_publisher_wraps_messages() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut inner =
+        LoggingPublisherLayer.layer(PublisherImpl::<Envelope<String>>::new("publisher", 10));
+    let mut subscriber = SubscriberBuilder::new(SubscriberImpl::new("subscriber"))
+        .with_layer(LoggingSubscriberLayer)
+        .with_layer(EnvelopeSubscriberLayer)
+        .build();
+
+    subscriber.subscribe_to(&mut inner)?;
+    let publisher = EnvelopePublisherLayer::new()
+        .with_header("origin", "test")
+        .layer(inner);
+
+    // -- Exec
+    let before = SystemTime::now();
+    publisher.publish("hello".to_string()).await?;
+    publisher.try_publish("world".to_string())?;
+
+    // -- Check
+    assert_eq!(subscriber.receive_message().await, "hello");
+    let first = subscriber.last_metadata().unwrap().clone();
+    assert_eq!(subscriber.receive_message().await, "world");
+    let second = subscriber.last_metadata().unwrap().clone();
+
+    assert!(first.id() < second.id());
+    assert_eq!(first.source(), "publisher");
+    assert_eq!(first.header("origin"), Some("test"));
+    assert_eq!(first.correlation_id(), None);
+    assert!(first.timestamp() >= before);
+    assert!(second.timestamp() >= first.timestamp());
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_envelope_correlation() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut requests = PublisherImpl::<Envelope<String>>::new("client", 10);
+    let mut responses = PublisherImpl::<Envelope<usize>>::new("server", 10);
+    let mut server = SubscriberImpl::new("server");
+    let mut client = SubscriberImpl::new("client");
+
+    server.subscribe_to(&mut requests)?;
+    client.subscribe_to(&mut responses)?;
+
+    let requests = EnvelopePublisherLayer::new().layer(requests);
+    let responses = EnvelopePublisherLayer::new()
+        .with_header("status", "ok")
+        .layer(responses);
+
+    // -- Exec
+    requests.publish("hello".to_string()).await?;
+
+    let request = server.receive().await;
+    let response = Envelope::new("server", request.message.len())
+        .with_correlation_id(request.metadata().id())
+        .with_header("status", "done");
+    responses.publish_envelope(response).await?;
+
+    // -- Check
+    let response = client.receive().await;
+    assert_eq!(
+        response.metadata().correlation_id(),
+        Some(request.metadata().id())
+    );
+    assert_eq!(response.metadata().header("status"), Some("done"));
+    assert_eq!(response.into_message(), 5);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_plain_subscriber_receives_unwrapped_messages() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher =
+        EnvelopePublisherLayer::new().layer(PublisherImpl::<Envelope<i32>>::new("publisher", 10));
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(42).await?;
+
+    // -- Check
+    assert_eq!(subscriber.receive().await, 42);
+
+    Ok(())
+}
+
+#[test]
+fn test_log_format() {
+    // -- Setup & Fixtures
+    let request = Envelope::new("client", "ping");
+    let response = Envelope::new("server", "pong")
+        .with_correlation_id(request.metadata().id())
+        .with_header("status", "ok");
+
+    // -- Exec
+    let plain = "pong".format_published("server", "client");
+    let published = response.format_published("server", "client");
+    let received = response.format_received("client", &["server"]);
+
+    // -- Check
+    assert_eq!(plain, "[server] -> [client]: pong");
+    assert!(published.starts_with(&format!("{} [server] @", response.metadata().id())));
+    assert!(published.ends_with(&format!(
+        " (re {}) {{status=ok}} -> [client]: pong",
+        request.metadata().id()
+    )));
+    assert_eq!(
+        received,
+        format!("[client] <- {}: pong", response.metadata())
+    );
+}
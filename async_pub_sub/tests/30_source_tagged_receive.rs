@@ -0,0 +1,53 @@
+use async_pub_sub::{
+    LoggingPublisherLayer, Publisher, PublisherBuilder, PublisherImpl, Result, SubscriberImpl,
+};
+
+#[test_log::test(tokio::test)]
+async fn test_receive_with_source() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher_a = PublisherImpl::new("publisher_a", 10);
+    let mut publisher_b = PublisherImpl::new("publisher_b", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher_a)?;
+    subscriber.subscribe_to(&mut publisher_b)?;
+
+    // -- Exec
+    publisher_a.publish(1).await?;
+    publisher_b.publish(2).await?;
+
+    let mut received = vec![
+        subscriber.receive_with_source().await,
+        subscriber.receive_with_source().await,
+    ];
+
+    // -- Check
+    received.sort();
+    assert_eq!(received, vec![("publisher_a", 1), ("publisher_b", 2)]);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_recv_with_source_reports_end_of_stream() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 10))
+        .with_layer(LoggingPublisherLayer)
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish("hello").await?;
+    drop(publisher);
+
+    let message = subscriber.recv_with_source().await;
+    let end = subscriber.recv_with_source().await;
+
+    // -- Check
+    assert_eq!(message, Some(("publisher", "hello")));
+    assert_eq!(end, None);
+
+    Ok(())
+}
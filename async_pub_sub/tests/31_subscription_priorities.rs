@@ -0,0 +1,125 @@
+use async_pub_sub::{PublisherImpl, Result, SubscriberImpl, SubscriptionOptions};
+
+#[test_log::test(tokio::test)]
+async fn test_high_priority_subscription_is_drained_first() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut data = PublisherImpl::new("data", 100);
+    let mut timer = PublisherImpl::new("timer", 10);
+    let mut shutdown = PublisherImpl::new("shutdown", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut data)?;
+    subscriber.subscribe_to_with(&mut timer, SubscriptionOptions::new().with_priority(1))?;
+    subscriber.subscribe_to_with(&mut shutdown, SubscriptionOptions::new().with_priority(2))?;
+
+    // -- Exec
+    for value in 0..50 {
+        data.publish(value).await?;
+    }
+    timer.publish(100).await?;
+    timer.publish(101).await?;
+    shutdown.publish(200).await?;
+
+    let mut received = Vec::new();
+    for _ in 0..53 {
+        received.push(subscriber.receive_with_source().await);
+    }
+
+    // -- Check
+    let mut expected = vec![("shutdown", 200), ("timer", 100), ("timer", 101)];
+    expected.extend((0..50).map(|value| ("data", value)));
+    assert_eq!(received, expected);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_weighted_round_robin() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut heavy = PublisherImpl::new("heavy", 10);
+    let mut light = PublisherImpl::new("light", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to_with(&mut heavy, SubscriptionOptions::new().with_weight(3))?;
+    subscriber.subscribe_to(&mut light)?;
+
+    // -- Exec
+    for value in 0..6 {
+        heavy.publish(value).await?;
+        light.publish(value).await?;
+    }
+
+    let mut received = Vec::new();
+    for _ in 0..8 {
+        let (source, _) = subscriber.receive_with_source().await;
+        received.push(source);
+    }
+
+    // -- Check
+    assert_eq!(
+        received,
+        vec!["heavy", "heavy", "heavy", "light", "heavy", "heavy", "heavy", "light"]
+    );
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_default_subscriptions_are_served_in_turn() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut flooding = PublisherImpl::new("flooding", 100);
+    let mut quiet = PublisherImpl::new("quiet", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut flooding)?;
+    subscriber.subscribe_to(&mut quiet)?;
+
+    // -- Exec
+    for value in 0..50 {
+        flooding.publish(value).await?;
+    }
+    quiet.publish(100).await?;
+
+    let first = subscriber.receive_with_source().await;
+    let second = subscriber.receive_with_source().await;
+
+    // -- Check
+    assert_eq!(first, ("flooding", 0));
+    assert_eq!(second, ("quiet", 100));
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_lower_priority_is_received_once_higher_priority_ends() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut low = PublisherImpl::new("low", 10);
+    let mut high = PublisherImpl::new("high", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut low)?;
+    subscriber.subscribe_to_with(&mut high, SubscriptionOptions::new().with_priority(1))?;
+
+    // -- Exec
+    low.publish(1).await?;
+    high.publish(2).await?;
+    drop(high);
+
+    let first = subscriber.recv().await;
+    let second = subscriber.recv().await;
+    drop(low);
+    let end = subscriber.recv().await;
+
+    // -- Check
+    assert_eq!(first, Some(2));
+    assert_eq!(second, Some(1));
+    assert_eq!(end, None);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "subscription weight should be greater than 0")]
+fn test_zero_weight_panics() {
+    SubscriptionOptions::new().with_weight(0);
+}
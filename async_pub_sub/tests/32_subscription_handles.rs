@@ -0,0 +1,118 @@
+use async_pub_sub::{Error, LoggingForwarder, PublisherImpl, Result, Subscriber, SubscriberImpl};
+
+#[test_log::test(tokio::test)]
+async fn test_rebind_keeps_queued_messages() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::new("publisher", 10);
+    let mut old_subscriber = SubscriberImpl::new("old_subscriber");
+    let mut new_subscriber = SubscriberImpl::new("new_subscriber");
+
+    let subscription = old_subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    for value in 1..=3 {
+        publisher.publish(value).await?;
+    }
+    let first = old_subscriber.receive().await;
+
+    assert_eq!(subscription.publisher_name(), "publisher");
+    assert!(subscription.unsubscribe());
+    let unbound = publisher.publish(4).await;
+
+    new_subscriber.subscribe_to(&mut publisher)?;
+    publisher.publish(5).await?;
+
+    // -- Check
+    assert_eq!(first, 1);
+    assert_eq!(old_subscriber.recv().await, None);
+    assert!(matches!(
+        unbound,
+        Err(Error::Disconnected {
+            subscriber: Some("old_subscriber"),
+            ..
+        })
+    ));
+    assert_eq!(new_subscriber.receive().await, 2);
+    assert_eq!(new_subscriber.receive().await, 3);
+    assert_eq!(new_subscriber.receive().await, 5);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_unsubscribe_wakes_running_subscriber() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::new("publisher", 10);
+    let mut old_subscriber = SubscriberImpl::new("old_subscriber");
+    let mut new_subscriber = SubscriberImpl::new("new_subscriber");
+
+    let subscription = old_subscriber.subscribe_to(&mut publisher)?;
+    let old_consumer = tokio::spawn(async move {
+        let mut received = Vec::new();
+        while let Some(message) = old_subscriber.recv().await {
+            received.push(message);
+        }
+        received
+    });
+
+    // -- Exec
+    publisher.publish(1).await?;
+    tokio::task::yield_now().await;
+
+    subscription.unsubscribe();
+    new_subscriber.subscribe_to(&mut publisher)?;
+    publisher.publish(2).await?;
+
+    // -- Check
+    assert_eq!(old_consumer.await.unwrap(), vec![1]);
+    assert_eq!(new_subscriber.receive().await, 2);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_forwarder_can_be_rebound() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::new("publisher", 10);
+    let mut forwarder = LoggingForwarder::new("forwarder");
+    let mut old_subscriber = SubscriberImpl::new("old_subscriber");
+    let mut new_subscriber = SubscriberImpl::new("new_subscriber");
+
+    forwarder.subscribe_to(&mut publisher)?;
+    let subscription = old_subscriber.subscribe_to(&mut forwarder)?;
+
+    // -- Exec
+    publisher.publish(1).await?;
+    let first = old_subscriber.receive().await;
+
+    subscription.unsubscribe();
+    new_subscriber.subscribe_to(&mut forwarder)?;
+    publisher.publish(2).await?;
+
+    // -- Check
+    assert_eq!(first, 1);
+    assert_eq!(new_subscriber.receive().await, 2);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_subscription_is_inactive_once_stream_ends() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::<i32>::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    let subscription = subscriber.subscribe_to(&mut publisher)?;
+    assert!(subscription.is_active());
+
+    // -- Exec
+    drop(publisher);
+    let end = subscriber.recv().await;
+
+    // -- Check
+    assert_eq!(end, None);
+    assert!(!subscription.is_active());
+    assert!(!subscription.unsubscribe());
+
+    Ok(())
+}
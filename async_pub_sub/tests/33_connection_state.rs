@@ -0,0 +1,119 @@
+use async_pub_sub::{
+    BroadcastPublisher, LoggingPublisherLayer, Publisher, PublisherBuilder, PublisherImpl, Result,
+    SubscriberImpl,
+};
+use futures::FutureExt;
+
+#[test_log::test(tokio::test)]
+async fn test_publisher_impl_connection_state() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::<i32>::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+    let mut new_subscriber = SubscriberImpl::new("new_subscriber");
+
+    // -- Exec & Check
+    assert!(!publisher.is_bound());
+    assert!(!publisher.is_closed());
+
+    subscriber.subscribe_to(&mut publisher)?;
+    assert!(publisher.is_bound());
+    assert!(!publisher.is_closed());
+    assert!(publisher.closed().now_or_never().is_none());
+
+    let closed = tokio::spawn(async move {
+        publisher.closed().await;
+        publisher
+    });
+    drop(subscriber);
+    let mut publisher = closed.await.unwrap();
+    assert!(!publisher.is_bound());
+    assert!(publisher.is_closed());
+
+    new_subscriber.subscribe_to(&mut publisher)?;
+    assert!(publisher.is_bound());
+    assert!(!publisher.is_closed());
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_close_resolves_closed() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::<i32>::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.close();
+
+    // -- Check
+    assert!(publisher.is_bound());
+    assert!(publisher.is_closed());
+    assert!(publisher.closed().now_or_never().is_some());
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_layered_publisher_connection_state() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherBuilder::new(PublisherImpl::<i32>::new("publisher", 10))
+        .with_layer(LoggingPublisherLayer)
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    // -- Exec & Check
+    assert!(!Publisher::is_bound(&publisher));
+
+    let subscription = subscriber.subscribe_to(&mut publisher)?;
+    assert!(Publisher::is_bound(&publisher));
+
+    subscription.unsubscribe();
+    Publisher::closed(&publisher).await;
+    assert!(Publisher::is_closed(&publisher));
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_boxed_publisher_connection_state() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher: Box<dyn Publisher<Message = i32> + Send + Sync> =
+        Box::new(PublisherImpl::new("publisher", 10));
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    drop(subscriber);
+
+    // -- Check
+    publisher.closed().await;
+    assert!(publisher.is_closed());
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_broadcast_publisher_connection_state() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = BroadcastPublisher::<i32>::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    // -- Exec & Check
+    assert!(!Publisher::is_bound(&publisher));
+
+    subscriber.subscribe_to(&mut publisher)?;
+    assert!(Publisher::is_bound(&publisher));
+
+    drop(subscriber);
+    assert!(!Publisher::is_bound(&publisher));
+    assert!(!publisher.is_closed());
+
+    publisher.close();
+    publisher.closed().await;
+    assert!(publisher.is_closed());
+
+    Ok(())
+}
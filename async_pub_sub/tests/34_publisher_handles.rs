@@ -0,0 +1,114 @@
+use async_pub_sub::{
+    Error, LoggingPublisherLayer, Publisher, PublisherBuilder, PublisherHandle, PublisherImpl,
+    Result, SubscriberImpl,
+};
+
+#[test_log::test(tokio::test)]
+async fn test_handles_publish_from_several_tasks() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 20))
+        .with_layer(LoggingPublisherLayer)
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+    let handle = PublisherHandle::new(publisher);
+
+    // -- Exec
+    let producers: Vec<_> = (0..4)
+        .map(|producer| {
+            let handle = handle.clone();
+            tokio::spawn(async move {
+                for value in 0..5 {
+                    handle.publish(producer * 10 + value).await?;
+                }
+                Result::Ok(())
+            })
+        })
+        .collect();
+    drop(handle);
+
+    for producer in producers {
+        producer.await.unwrap()?;
+    }
+
+    let mut received = Vec::new();
+    while let Some(value) = subscriber.recv().await {
+        received.push(value);
+    }
+
+    // -- Check
+    received.sort();
+    let expected: Vec<_> = (0..4)
+        .flat_map(|producer| (0..5).map(move |value| producer * 10 + value))
+        .collect();
+    assert_eq!(received, expected);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_weak_handle_does_not_keep_publisher_alive() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+    let handle = PublisherHandle::new(publisher);
+    let weak_handle = handle.downgrade();
+
+    // -- Exec
+    weak_handle.upgrade().unwrap().publish(1).await?;
+    drop(handle);
+
+    // -- Check
+    assert!(weak_handle.upgrade().is_none());
+    assert_eq!(subscriber.recv().await, Some(1));
+    assert_eq!(subscriber.recv().await, None);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_handle_reports_connection_state() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherImpl::<i32>::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+    let handle = PublisherHandle::new(publisher);
+    let other_handle = handle.clone();
+
+    // -- Exec
+    drop(subscriber);
+
+    // -- Check
+    assert_eq!(other_handle.get_name(), "publisher");
+    handle.closed().await;
+    assert!(other_handle.is_closed());
+    assert!(!other_handle.is_bound());
+
+    Ok(())
+}
+
+#[test_log::test]
+fn test_subscribing_to_shared_handle_fails() {
+    // -- Setup & Fixtures
+    let mut handle = PublisherHandle::new(PublisherImpl::<i32>::new("publisher", 10));
+    let other_handle = handle.clone();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    // -- Exec
+    let cloned_error = subscriber.subscribe_to(&mut handle).err();
+    drop(other_handle);
+    let _weak_handle = handle.downgrade();
+    let downgraded_error = subscriber.subscribe_to(&mut handle).err();
+
+    // -- Check
+    assert_eq!(cloned_error, Some(Error::shared::<i32>("publisher")));
+    assert_eq!(downgraded_error, Some(Error::shared::<i32>("publisher")));
+    assert_eq!(
+        cloned_error.unwrap().to_string(),
+        "publisher publisher (i32) is in use elsewhere and cannot be bound"
+    );
+}
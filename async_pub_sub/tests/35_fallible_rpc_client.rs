@@ -0,0 +1,141 @@
+use async_pub_sub::{Error, PublisherImpl, Result, RpcError, Subscriber, SubscriberImpl};
+
+mod interface {
+    use async_pub_sub_macros::rpc_interface;
+
+    #[rpc_interface]
+    pub trait RpcInterface {
+        async fn add_one(&self, value: i32) -> i32;
+    }
+}
+
+mod client {
+    use super::interface::{RpcInterfaceClient, RpcInterfaceMessage};
+    use async_pub_sub::Publisher;
+    use async_pub_sub_macros::DerivePublisher;
+
+    #[derive(DerivePublisher)]
+    pub struct RpcClient<P>
+    where
+        P: Publisher<Message = RpcInterfaceMessage>,
+    {
+        pub publisher: P,
+    }
+
+    impl<P> RpcInterfaceClient for RpcClient<P> where P: Publisher<Message = RpcInterfaceMessage> {}
+}
+
+mod server {
+    use super::interface::{RpcInterface, RpcInterfaceMessage};
+    use async_pub_sub::SubscriberImpl;
+    use async_pub_sub_macros::DeriveSubscriber;
+
+    #[derive(DeriveSubscriber)]
+    pub struct RpcServer {
+        #[subscriber(RpcInterfaceMessage)]
+        pub subscriber: SubscriberImpl<RpcInterfaceMessage>,
+    }
+
+    impl RpcInterface for RpcServer {
+        async fn add_one(&self, value: i32) -> i32 {
+            value + 1
+        }
+    }
+}
+
+use interface::{RpcInterfaceClient, RpcInterfaceServer};
+
+#[test_log::test(tokio::test)]
+async fn test_try_method_returns_response() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut rpc_client = client::RpcClient {
+        publisher: PublisherImpl::new("rpc_client", 1),
+    };
+    let mut rpc_server = server::RpcServer {
+        subscriber: SubscriberImpl::new("rpc_server"),
+    };
+
+    rpc_server.subscribe_to(&mut rpc_client)?;
+    tokio::spawn(async move { rpc_server.run().await });
+
+    // -- Exec
+    let response = rpc_client.try_add_one(41).await;
+
+    // -- Check
+    assert_eq!(response, Ok(42));
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_try_method_reports_dropped_request() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut rpc_client = client::RpcClient {
+        publisher: PublisherImpl::new("rpc_client", 1),
+    };
+    let mut subscriber = SubscriberImpl::new("rpc_server");
+
+    subscriber.subscribe_to(&mut rpc_client)?;
+
+    // The server receives the request and drops it without responding.
+    tokio::spawn(async move {
+        let request = subscriber.receive().await;
+        drop(request);
+    });
+
+    // -- Exec
+    let response = rpc_client.try_add_one(41).await;
+
+    // -- Check
+    assert_eq!(response, Err(RpcError::ServerDropped { method: "add_one" }));
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_try_method_reports_publish_failure() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut rpc_client = client::RpcClient {
+        publisher: PublisherImpl::new("rpc_client", 1),
+    };
+    let mut subscriber = SubscriberImpl::new("rpc_server");
+
+    subscriber.subscribe_to(&mut rpc_client)?;
+    drop(subscriber);
+
+    // -- Exec
+    let response = rpc_client.try_add_one(41).await;
+
+    // -- Check
+    let Err(error) = response else {
+        panic!("the request should not be published");
+    };
+    assert!(matches!(
+        error,
+        RpcError::Publish {
+            method: "add_one",
+            error: Error::Disconnected { .. },
+        }
+    ));
+    assert_eq!(error.method(), "add_one");
+    assert!(error
+        .to_string()
+        .starts_with("failed to publish add_one request"));
+
+    Ok(())
+}
+
+#[test_log::test]
+fn test_timeout_errors_map_to_rpc_timeout() {
+    // -- Setup & Fixtures
+    let timeout = Error::timeout::<i32>("rpc_client");
+
+    // -- Exec
+    let publish_error = RpcError::publish_failed("add_one", timeout.clone());
+    let response_error = RpcError::response_failed("add_one", timeout);
+
+    // -- Check
+    assert_eq!(publish_error, RpcError::Timeout { method: "add_one" });
+    assert_eq!(response_error, RpcError::Timeout { method: "add_one" });
+    assert_eq!(publish_error.to_string(), "add_one request timed out");
+}
@@ -0,0 +1,186 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use async_pub_sub::{PublisherImpl, Request, Result, RpcError, Subscriber, SubscriberImpl};
+
+mod interface {
+    use async_pub_sub_macros::rpc_interface;
+
+    #[rpc_interface]
+    pub trait RpcInterface {
+        async fn add_one(&self, value: i32) -> i32;
+    }
+}
+
+mod client {
+    use std::time::Duration;
+
+    use super::interface::{RpcInterfaceClient, RpcInterfaceMessage};
+    use async_pub_sub::{
+        futures::{future::BoxFuture, FutureExt},
+        Publisher,
+    };
+    use async_pub_sub_macros::DerivePublisher;
+
+    #[derive(DerivePublisher)]
+    pub struct RpcClient<P>
+    where
+        P: Publisher<Message = RpcInterfaceMessage>,
+    {
+        pub publisher: P,
+        pub timeout: Option<Duration>,
+    }
+
+    impl<P> RpcInterfaceClient for RpcClient<P>
+    where
+        P: Publisher<Message = RpcInterfaceMessage>,
+    {
+        fn rpc_timeout(&self) -> Option<Duration> {
+            self.timeout
+        }
+
+        // The deadlines follow the clock of the runtime, which the tests pause.
+        fn rpc_sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+            tokio::time::sleep(duration).boxed()
+        }
+    }
+}
+
+mod server {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use super::interface::{RpcInterface, RpcInterfaceMessage};
+    use async_pub_sub::SubscriberImpl;
+    use async_pub_sub_macros::DeriveSubscriber;
+
+    #[derive(DeriveSubscriber)]
+    pub struct RpcServer {
+        #[subscriber(RpcInterfaceMessage)]
+        pub subscriber: SubscriberImpl<RpcInterfaceMessage>,
+        pub handled: Arc<AtomicUsize>,
+    }
+
+    impl RpcInterface for RpcServer {
+        async fn add_one(&self, value: i32) -> i32 {
+            self.handled.fetch_add(1, Ordering::Relaxed);
+            value + 1
+        }
+    }
+}
+
+use interface::{RpcInterfaceClient, RpcInterfaceServer};
+
+fn setup(
+    timeout: Option<Duration>,
+) -> Result<(
+    client::RpcClient<PublisherImpl<interface::RpcInterfaceMessage>>,
+    server::RpcServer,
+)> {
+    let mut rpc_client = client::RpcClient {
+        publisher: PublisherImpl::new("rpc_client", 10),
+        timeout,
+    };
+    let mut rpc_server = server::RpcServer {
+        subscriber: SubscriberImpl::new("rpc_server"),
+        handled: Arc::new(AtomicUsize::new(0)),
+    };
+
+    rpc_server.subscribe_to(&mut rpc_client)?;
+
+    Ok((rpc_client, rpc_server))
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_call_timeout_skips_cancelled_request() -> Result<()> {
+    // -- Setup & Fixtures
+    let (rpc_client, mut rpc_server) = setup(None)?;
+    let handled = rpc_server.handled.clone();
+
+    // -- Exec
+    // The server is not running yet, the call times out.
+    let response = rpc_client
+        .try_add_one_with_timeout(41, Duration::from_millis(10))
+        .await;
+
+    drop(rpc_client);
+    rpc_server.run().await;
+
+    // -- Check
+    assert_eq!(response, Err(RpcError::Timeout { method: "add_one" }));
+    assert_eq!(handled.load(Ordering::Relaxed), 0);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_client_default_timeout() -> Result<()> {
+    // -- Setup & Fixtures
+    let (rpc_client, mut rpc_server) = setup(Some(Duration::from_millis(10)))?;
+    let handled = rpc_server.handled.clone();
+
+    // -- Exec
+    let timed_out = rpc_client.try_add_one(41).await;
+
+    let server_task = tokio::spawn(async move { rpc_server.run().await });
+    let response = rpc_client.try_add_one(41).await;
+
+    drop(rpc_client);
+    server_task.await.expect("server loop should exit cleanly");
+
+    // -- Check
+    assert_eq!(timed_out, Err(RpcError::Timeout { method: "add_one" }));
+    assert_eq!(response, Ok(42));
+    assert_eq!(handled.load(Ordering::Relaxed), 1);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_dropped_call_cancels_request() -> Result<()> {
+    // -- Setup & Fixtures
+    let (rpc_client, mut rpc_server) = setup(None)?;
+    let handled = rpc_server.handled.clone();
+
+    // -- Exec
+    // The call is dropped once its request is published, before the server handles it.
+    tokio::select! {
+        _ = rpc_client.try_add_one(41) => panic!("the call should not complete"),
+        _ = tokio::task::yield_now() => {}
+    }
+
+    drop(rpc_client);
+    rpc_server.run().await;
+
+    // -- Check
+    assert_eq!(handled.load(Ordering::Relaxed), 0);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_request_cancellation() {
+    // -- Setup & Fixtures
+    let (request, response) = Request::<i32, i32>::new(41);
+    let clone = request.clone();
+
+    // -- Exec
+    let cancelled_before = request.is_cancelled();
+    drop(response);
+    let cancelled_after = request.is_cancelled();
+
+    // -- Check
+    assert!(!cancelled_before);
+    assert!(cancelled_after);
+    assert!(clone.is_cancelled());
+
+    // Responding to a gone requester does not panic.
+    request.respond(42);
+}
@@ -0,0 +1,274 @@
+use std::{
+    sync::{atomic::Ordering, Arc},
+    time::Duration,
+};
+
+use async_pub_sub::{PublisherImpl, Result, Shutdown, SubscriberImpl, SubscriberWrapper};
+use tokio::time::Instant;
+
+mod interface {
+    use async_pub_sub_macros::rpc_interface;
+
+    #[rpc_interface]
+    pub trait StoreInterface {
+        async fn read(&self) -> i32;
+        async fn write(&mut self, value: i32);
+    }
+
+    // The methods share the names of the request handlers of a concurrent server.
+    #[rpc_interface]
+    pub trait HandlerInterface {
+        async fn handle_shared_request(&self, value: i32) -> i32;
+        async fn handle_exclusive_request(&mut self, value: i32) -> i32;
+    }
+}
+
+mod client {
+    use super::interface::{
+        HandlerInterfaceClient, HandlerInterfaceMessage, StoreInterfaceClient,
+        StoreInterfaceMessage,
+    };
+    use async_pub_sub::PublisherImpl;
+    use async_pub_sub_macros::DerivePublisher;
+
+    #[derive(DerivePublisher)]
+    pub struct StoreClient {
+        #[publisher(StoreInterfaceMessage)]
+        pub publisher: PublisherImpl<StoreInterfaceMessage>,
+    }
+
+    impl StoreInterfaceClient for StoreClient {}
+
+    #[derive(DerivePublisher)]
+    pub struct HandlerClient {
+        #[publisher(HandlerInterfaceMessage)]
+        pub publisher: PublisherImpl<HandlerInterfaceMessage>,
+    }
+
+    impl HandlerInterfaceClient for HandlerClient {}
+}
+
+mod server {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use super::interface::{HandlerInterface, StoreInterface};
+
+    /// Tracks how many requests are handled at the same time.
+    #[derive(Default)]
+    pub struct Concurrency {
+        pub current: AtomicUsize,
+        pub max: AtomicUsize,
+        /// The number of requests in flight when a write started
+        pub during_write: AtomicUsize,
+    }
+
+    pub struct Store {
+        pub value: i32,
+        pub concurrency: Arc<Concurrency>,
+    }
+
+    impl StoreInterface for Store {
+        async fn read(&self) -> i32 {
+            let current = self.concurrency.current.fetch_add(1, Ordering::SeqCst) + 1;
+            self.concurrency.max.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.concurrency.current.fetch_sub(1, Ordering::SeqCst);
+            self.value
+        }
+
+        async fn write(&mut self, value: i32) {
+            let current = self.concurrency.current.load(Ordering::SeqCst);
+            self.concurrency
+                .during_write
+                .fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.value = value;
+        }
+    }
+
+    pub struct Handler {
+        pub offset: i32,
+    }
+
+    impl HandlerInterface for Handler {
+        async fn handle_shared_request(&self, value: i32) -> i32 {
+            value + self.offset
+        }
+
+        async fn handle_exclusive_request(&mut self, value: i32) -> i32 {
+            self.offset = value;
+            self.offset
+        }
+    }
+}
+
+use interface::{
+    HandlerInterfaceClient, HandlerInterfaceConcurrentServer, StoreInterfaceClient,
+    StoreInterfaceConcurrentServer,
+};
+
+fn setup() -> Result<(
+    client::StoreClient,
+    SubscriberImpl<interface::StoreInterfaceMessage>,
+    server::Store,
+)> {
+    let mut store_client = client::StoreClient {
+        publisher: PublisherImpl::new("store_client", 10),
+    };
+    let mut subscriber = SubscriberImpl::new("store_server");
+    let store = server::Store {
+        value: 0,
+        concurrency: Arc::new(server::Concurrency::default()),
+    };
+
+    SubscriberWrapper::subscribe_to(&mut subscriber, &mut store_client)?;
+
+    Ok((store_client, subscriber, store))
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_shared_requests_run_concurrently_up_to_limit() -> Result<()> {
+    // -- Setup & Fixtures
+    let (store_client, mut subscriber, mut store) = setup()?;
+    let concurrency = store.concurrency.clone();
+
+    let server_task = tokio::spawn(async move {
+        store.run_concurrently(&mut subscriber, 2).await;
+    });
+
+    // -- Exec
+    let start = Instant::now();
+    let responses = tokio::join!(
+        store_client.read(),
+        store_client.read(),
+        store_client.read(),
+        store_client.read(),
+    );
+    let elapsed = start.elapsed();
+
+    drop(store_client);
+    server_task.await.expect("server loop should exit cleanly");
+
+    // -- Check
+    assert_eq!(responses, (0, 0, 0, 0));
+    assert_eq!(concurrency.max.load(Ordering::SeqCst), 2);
+    assert_eq!(elapsed, Duration::from_millis(100));
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_exclusive_requests_are_serialized() -> Result<()> {
+    // -- Setup & Fixtures
+    let (store_client, mut subscriber, mut store) = setup()?;
+    let mut writer_client = client::StoreClient {
+        publisher: PublisherImpl::new("writer_client", 10),
+    };
+    let concurrency = store.concurrency.clone();
+
+    SubscriberWrapper::subscribe_to(&mut subscriber, &mut writer_client)?;
+
+    let server_task = tokio::spawn(async move {
+        store.run_concurrently(&mut subscriber, 8).await;
+        store.value
+    });
+
+    // -- Exec
+    let (before, (), after) = tokio::join!(store_client.read(), writer_client.write(42), async {
+        // The second read is published once the write is queued.
+        tokio::task::yield_now().await;
+        store_client.read().await
+    });
+
+    drop(store_client);
+    drop(writer_client);
+    let value = server_task.await.expect("server loop should exit cleanly");
+
+    // -- Check
+    assert_eq!((before, after), (0, 42));
+    assert_eq!(value, 42);
+    assert_eq!(concurrency.during_write.load(Ordering::SeqCst), 0);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_shutdown_drains_in_flight_requests() -> Result<()> {
+    // -- Setup & Fixtures
+    let (store_client, mut subscriber, mut store) = setup()?;
+    let shutdown = Shutdown::new();
+    let signal = shutdown.signal();
+
+    let server_task = tokio::spawn(async move {
+        store
+            .run_concurrently_until_shutdown(signal, &mut subscriber, 2)
+            .await;
+    });
+
+    // -- Exec
+    let start = Instant::now();
+    let (responses, ()) = tokio::join!(
+        async { tokio::join!(store_client.read(), store_client.read()) },
+        async {
+            // The reads are in flight when the shutdown is triggered.
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            shutdown.shutdown().await;
+        }
+    );
+    let elapsed = start.elapsed();
+
+    // The client is still alive, the server stopped because of the shutdown.
+    server_task.await.expect("server loop should exit cleanly");
+
+    // -- Check
+    assert_eq!(responses, (0, 0));
+    assert_eq!(elapsed, Duration::from_millis(50));
+    assert!(store_client.publisher.is_closed());
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_methods_named_like_request_handlers() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut handler_client = client::HandlerClient {
+        publisher: PublisherImpl::new("handler_client", 10),
+    };
+    let mut subscriber = SubscriberImpl::new("handler_server");
+    let mut handler = server::Handler { offset: 1 };
+
+    SubscriberWrapper::subscribe_to(&mut subscriber, &mut handler_client)?;
+
+    let server_task = tokio::spawn(async move {
+        handler.run_concurrently(&mut subscriber, 2).await;
+    });
+
+    // -- Exec
+    let shared = handler_client.handle_shared_request(41).await;
+    let exclusive = handler_client.handle_exclusive_request(10).await;
+    let shared_after = handler_client.handle_shared_request(41).await;
+
+    drop(handler_client);
+    server_task.await.expect("server loop should exit cleanly");
+
+    // -- Check
+    assert_eq!((shared, exclusive, shared_after), (42, 10, 51));
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+#[should_panic(expected = "max_in_flight should be greater than 0")]
+async fn test_zero_in_flight_limit_panics() {
+    // -- Setup & Fixtures
+    let (_store_client, mut subscriber, mut store) = setup().unwrap();
+
+    // -- Exec
+    store.run_concurrently(&mut subscriber, 0).await;
+}
@@ -0,0 +1,168 @@
+use async_pub_sub::{
+    futures::StreamExt, PublisherImpl, Result, RpcError, SubscriberImpl, SubscriberWrapper,
+};
+
+mod interface {
+    use async_pub_sub::futures::Stream;
+    use async_pub_sub_macros::rpc_interface;
+
+    #[rpc_interface]
+    pub trait RpcInterface {
+        async fn count_to(&self, limit: u32) -> impl Stream<Item = u32>;
+        async fn watch(&self) -> impl Stream<Item = u32>;
+        async fn add_one(&self, value: u32) -> u32;
+    }
+}
+
+mod client {
+    use super::interface::{RpcInterfaceClient, RpcInterfaceMessage};
+    use async_pub_sub::Publisher;
+    use async_pub_sub_macros::DerivePublisher;
+
+    #[derive(DerivePublisher)]
+    pub struct RpcClient<P>
+    where
+        P: Publisher<Message = RpcInterfaceMessage>,
+    {
+        pub publisher: P,
+    }
+
+    impl<P> RpcInterfaceClient for RpcClient<P>
+    where
+        P: Publisher<Message = RpcInterfaceMessage>,
+    {
+        fn stream_buffer_size(&self) -> usize {
+            2
+        }
+    }
+}
+
+mod server {
+    use super::interface::RpcInterface;
+    use async_pub_sub::futures::{stream, Stream, StreamExt};
+
+    pub struct RpcServer;
+
+    impl RpcInterface for RpcServer {
+        async fn count_to(&self, limit: u32) -> impl Stream<Item = u32> {
+            stream::iter(0..limit)
+        }
+
+        async fn watch(&self) -> impl Stream<Item = u32> {
+            // A first event, then nothing until the watcher leaves.
+            stream::iter([1]).chain(stream::pending())
+        }
+
+        async fn add_one(&self, value: u32) -> u32 {
+            value + 1
+        }
+    }
+}
+
+use interface::{RpcInterfaceClient, RpcInterfaceConcurrentServer};
+
+fn setup() -> Result<(
+    client::RpcClient<PublisherImpl<interface::RpcInterfaceMessage>>,
+    SubscriberImpl<interface::RpcInterfaceMessage>,
+)> {
+    let mut rpc_client = client::RpcClient {
+        publisher: PublisherImpl::new("rpc_client", 10),
+    };
+    let mut subscriber = SubscriberImpl::new("rpc_server");
+
+    SubscriberWrapper::subscribe_to(&mut subscriber, &mut rpc_client)?;
+
+    Ok((rpc_client, subscriber))
+}
+
+fn spawn_server(
+    mut subscriber: SubscriberImpl<interface::RpcInterfaceMessage>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move { server::RpcServer.run_concurrently(&mut subscriber, 8).await })
+}
+
+#[test_log::test(tokio::test)]
+async fn test_stream_response() -> Result<()> {
+    // -- Setup & Fixtures
+    let (rpc_client, subscriber) = setup()?;
+    let server_task = spawn_server(subscriber);
+
+    // -- Exec
+    // The stream is longer than the response channel.
+    let responses: Vec<_> = rpc_client.count_to(5).await.collect().await;
+
+    drop(rpc_client);
+    server_task.await.expect("server loop should exit cleanly");
+
+    // -- Check
+    assert_eq!(responses, vec![0, 1, 2, 3, 4]);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_dropped_stream_stops_forwarding() -> Result<()> {
+    // -- Setup & Fixtures
+    let (rpc_client, subscriber) = setup()?;
+    let server_task = spawn_server(subscriber);
+
+    // -- Exec
+    let mut events = rpc_client.watch().await;
+    let first_event = events.next().await;
+    drop(events);
+
+    // The server stops once the client is gone, which requires the stream to stop too.
+    drop(rpc_client);
+    server_task.await.expect("server loop should exit cleanly");
+
+    // -- Check
+    assert_eq!(first_event, Some(1));
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_streams_do_not_block_other_requests() -> Result<()> {
+    // -- Setup & Fixtures
+    let (rpc_client, subscriber) = setup()?;
+    let server_task = spawn_server(subscriber);
+
+    // -- Exec
+    // A stream that never ends, and one whose client stops reading once its channel is full.
+    let mut events = rpc_client.watch().await;
+    let first_event = events.next().await;
+    let stalled = rpc_client.count_to(100).await;
+
+    let response = rpc_client.add_one(41).await;
+
+    drop((events, stalled));
+    drop(rpc_client);
+    server_task.await.expect("server loop should exit cleanly");
+
+    // -- Check
+    assert_eq!(first_event, Some(1));
+    assert_eq!(response, 42);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_stream_request_publish_failure() -> Result<()> {
+    // -- Setup & Fixtures
+    let (rpc_client, subscriber) = setup()?;
+    drop(subscriber);
+
+    // -- Exec
+    let responses = rpc_client.try_count_to(5).await;
+
+    // -- Check
+    assert!(matches!(
+        responses,
+        Err(RpcError::Publish {
+            method: "count_to",
+            ..
+        })
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use async_pub_sub::{Error, PublisherImpl, Result, RpcError, Subscriber, SubscriberImpl};
+
+mod interface {
+    use async_pub_sub_macros::rpc_interface;
+
+    #[rpc_interface]
+    pub trait RpcInterface {
+        #[notify]
+        async fn push(&mut self, value: i32);
+        #[notify]
+        async fn clear(&mut self);
+        async fn values(&self) -> Vec<i32>;
+    }
+}
+
+mod client {
+    use super::interface::{RpcInterfaceClient, RpcInterfaceMessage};
+    use async_pub_sub::Publisher;
+    use async_pub_sub_macros::DerivePublisher;
+
+    #[derive(DerivePublisher)]
+    pub struct RpcClient<P>
+    where
+        P: Publisher<Message = RpcInterfaceMessage>,
+    {
+        pub publisher: P,
+    }
+
+    impl<P> RpcInterfaceClient for RpcClient<P> where P: Publisher<Message = RpcInterfaceMessage> {}
+}
+
+mod server {
+    use super::interface::{RpcInterface, RpcInterfaceMessage};
+    use async_pub_sub::SubscriberImpl;
+    use async_pub_sub_macros::DeriveSubscriber;
+
+    #[derive(DeriveSubscriber)]
+    pub struct RpcServer {
+        #[subscriber(RpcInterfaceMessage)]
+        pub subscriber: SubscriberImpl<RpcInterfaceMessage>,
+        pub values: Vec<i32>,
+    }
+
+    impl RpcInterface for RpcServer {
+        async fn push(&mut self, value: i32) {
+            self.values.push(value);
+        }
+
+        async fn clear(&mut self) {
+            self.values.clear();
+        }
+
+        async fn values(&self) -> Vec<i32> {
+            self.values.clone()
+        }
+    }
+}
+
+use interface::{RpcInterfaceClient, RpcInterfaceMessage, RpcInterfaceServer};
+
+fn setup() -> Result<(
+    client::RpcClient<PublisherImpl<RpcInterfaceMessage>>,
+    server::RpcServer,
+)> {
+    let mut rpc_client = client::RpcClient {
+        publisher: PublisherImpl::new("rpc_client", 10),
+    };
+    let mut rpc_server = server::RpcServer {
+        subscriber: SubscriberImpl::new("rpc_server"),
+        values: Vec::new(),
+    };
+
+    rpc_server.subscribe_to(&mut rpc_client)?;
+
+    Ok((rpc_client, rpc_server))
+}
+
+#[test_log::test(tokio::test)]
+async fn test_notifications_resolve_once_published() -> Result<()> {
+    // -- Setup & Fixtures
+    let (mut rpc_client, mut rpc_server) = setup()?;
+
+    // -- Exec
+    // The server is not running, the notifications are only queued.
+    rpc_client.push(1).await;
+    rpc_client.clear().await;
+    rpc_client.push(2).await;
+    rpc_client.push(3).await;
+
+    drop(rpc_client);
+    rpc_server.run().await;
+
+    // -- Check
+    assert_eq!(rpc_server.values, vec![2, 3]);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_notifications_are_handled_in_order_with_requests() -> Result<()> {
+    // -- Setup & Fixtures
+    let (mut rpc_client, mut rpc_server) = setup()?;
+    let server_task = tokio::spawn(async move { rpc_server.run().await });
+
+    // -- Exec
+    rpc_client.push(1).await;
+    rpc_client.push(2).await;
+    let values = rpc_client.values().await;
+
+    drop(rpc_client);
+    server_task.await.expect("server loop should exit cleanly");
+
+    // -- Check
+    assert_eq!(values, vec![1, 2]);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_notification_publish_failure() -> Result<()> {
+    // -- Setup & Fixtures
+    let (mut rpc_client, rpc_server) = setup()?;
+    drop(rpc_server);
+
+    // -- Exec
+    let result = rpc_client.try_push(1).await;
+
+    // -- Check
+    assert!(matches!(
+        result,
+        Err(RpcError::Publish {
+            method: "push",
+            error: Error::Disconnected { .. },
+        })
+    ));
+
+    Ok(())
+}
@@ -97,8 +97,34 @@ pub fn derive_publisher(input: TokenStream) -> TokenStream {
 ///
 /// This macro generates:
 /// - Message enums for RPC communication
-/// - Client traits
-/// - Server traits
+/// - Client traits, with a `try_*` variant of every method returning
+///   `Result<_, async_pub_sub::RpcError>` instead of panicking
+/// - Server traits, whose `run` method handles one request at a time, for interfaces
+///   without streaming methods
+/// - Concurrent server traits, whose `run_concurrently` method handles the requests of
+///   `&self` methods concurrently, up to a limit of in-flight requests, while the requests
+///   of `&mut self` methods are handled one at a time; `run_concurrently_until_shutdown`
+///   stops receiving requests once a shutdown is triggered and drains the in-flight ones
+///
+/// Client calls wait for their response for at most the timeout returned by the
+/// `rpc_timeout` method of the client trait, which can be overridden and defaults to no
+/// limit; `try_*_with_timeout` methods set the timeout of a single call. Timeouts are
+/// measured by the `rpc_sleep` method, which defaults to a runtime-agnostic timer and can be
+/// overridden to use the clock of the runtime, e.g. `tokio::time::sleep`. Servers skip the
+/// requests whose caller stopped waiting before they are handled.
+///
+/// Methods returning `impl Stream<Item = T>` stream their responses: their client methods
+/// resolve to an `async_pub_sub::ResponseStream<T>`, fed through a channel of
+/// `stream_buffer_size` items, and servers forward the stream until it ends or the client
+/// drops it. Streaming methods take `&self`, and their interfaces are served with
+/// `run_concurrently`, which forwards the streams alongside the other requests: a stream
+/// holds one of the in-flight requests until it ends, and the requests of `&mut self`
+/// methods wait for it.
+///
+/// Methods marked with `#[notify]` are one-way notifications: they cannot return a value,
+/// their messages carry no response channel and their client methods resolve as soon as
+/// the notification is published. Servers dispatch them to the interface trait like the
+/// other requests.
 ///
 /// # Examples
 /// ```rust
@@ -107,6 +133,8 @@ pub fn derive_publisher(input: TokenStream) -> TokenStream {
 /// #[rpc_interface]
 /// trait MyRpcInterface {
 ///     async fn my_method(&self, arg: i32) -> String;
+///     #[notify]
+///     async fn my_notification(&mut self, arg: i32);
 /// }
 /// ```
 #[proc_macro_attribute]
@@ -42,6 +42,26 @@ pub(crate) fn derive_publisher_impl(input: DeriveInput) -> TokenStream {
                     async_pub_sub::Publisher::publish(&self.#field_name, message)
                 }
 
+                fn try_publish(&self, message: Self::Message) -> async_pub_sub::Result<async_pub_sub::PublishOutcome> {
+                    async_pub_sub::Publisher::try_publish(&self.#field_name, message)
+                }
+
+                fn close(&self) {
+                    async_pub_sub::Publisher::close(&self.#field_name)
+                }
+
+                fn is_bound(&self) -> bool {
+                    async_pub_sub::Publisher::is_bound(&self.#field_name)
+                }
+
+                fn is_closed(&self) -> bool {
+                    async_pub_sub::Publisher::is_closed(&self.#field_name)
+                }
+
+                fn closed(&self) -> async_pub_sub::futures::future::BoxFuture<()> {
+                    async_pub_sub::Publisher::closed(&self.#field_name)
+                }
+
                 fn get_message_stream(
                     &mut self,
                     subscriber_name: &'static str,
@@ -13,6 +13,7 @@ pub(crate) fn generate_rpc_interface(input: Item) -> TokenStream {
     let message_enum_name = format_ident!("{}Message", trait_name);
     let client_trait_name = format_ident!("{}Client", trait_name);
     let server_trait_name = format_ident!("{}Server", trait_name);
+    let concurrent_server_trait_name = format_ident!("{}ConcurrentServer", trait_name);
 
     let methods: Vec<_> = input
         .items
@@ -26,45 +27,105 @@ pub(crate) fn generate_rpc_interface(input: Item) -> TokenStream {
         })
         .collect();
 
+    for method in methods.iter().filter(|method| is_notification(method)) {
+        if !matches!(method.sig.output, syn::ReturnType::Default) {
+            panic!(
+                "The #[notify] method {} should not return a value",
+                method.sig.ident
+            );
+        }
+    }
+
+    for method in methods
+        .iter()
+        .filter(|method| stream_item_type(method).is_some())
+    {
+        if !is_shared(method) {
+            panic!(
+                "The streaming method {} should take &self, so that its stream is forwarded alongside the other requests",
+                method.sig.ident
+            );
+        }
+    }
+
+    // The #[notify] attributes are only meaningful to this macro.
+    let mut emitted_trait = input.clone();
+    for item in emitted_trait.items.iter_mut() {
+        if let syn::TraitItem::Fn(method) = item {
+            method.attrs.retain(|attr| !attr.path().is_ident("notify"));
+        }
+    }
+
+    let has_streams = methods
+        .iter()
+        .any(|method| stream_item_type(method).is_some());
+
+    // Streaming methods send their responses through a bounded channel.
+    let stream_buffer_size = if has_streams {
+        quote! {
+            fn stream_buffer_size(&self) -> usize {
+                16
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let enum_variants = generate_enum_variants(&methods);
+    let clone_impl = generate_clone_impl(&message_enum_name, &methods);
+    let traceable_impl = generate_traceable_impl(&message_enum_name, &methods);
     let client_methods = generate_client_methods(&message_enum_name, &methods);
     let trait_impl_for_client =
         generate_trait_impl_for_client(&trait_name, &client_trait_name, &methods);
-    let server_impl = generate_server_impl(&message_enum_name, &trait_name, &methods);
-    let server_trait_impl =
-        generate_server_trait_impl(&server_trait_name, &message_enum_name, &trait_name);
+    let server_trait = if has_streams {
+        // A server handling one request at a time would handle no other request while
+        // forwarding a stream, so streaming interfaces only get a concurrent server.
+        quote! {}
+    } else {
+        generate_server_trait(&server_trait_name, &message_enum_name, &trait_name, &methods)
+    };
+    let concurrent_server_trait = generate_concurrent_server_trait(
+        &concurrent_server_trait_name,
+        &message_enum_name,
+        &trait_name,
+        &methods,
+    );
 
     let expanded = quote! {
         #[allow(async_fn_in_trait)]
-        #input
+        #emitted_trait
 
         #[derive(Debug)]
         pub enum #message_enum_name {
             #(#enum_variants)*
         }
 
-        pub trait #client_trait_name: async_pub_sub::PublisherWrapper<#message_enum_name> {
-            #(#client_methods)*
-        }
+        #clone_impl
 
-        #trait_impl_for_client
+        #traceable_impl
 
-        pub trait #server_trait_name: async_pub_sub::SubscriberWrapper<#message_enum_name> + #trait_name {
-            async fn run(&mut self) {
-                loop {
-                    let request = self.receive().await;
-                    self.handle_request(request).await;
-                }
+        pub trait #client_trait_name: async_pub_sub::PublisherWrapper<#message_enum_name> {
+            fn rpc_timeout(&self) -> Option<std::time::Duration> {
+                None
             }
 
-            async fn handle_request(&mut self, request: #message_enum_name) {
-                match request {
-                    #(#server_impl)*
-                }
+            fn rpc_sleep(
+                &self,
+                duration: std::time::Duration,
+            ) -> async_pub_sub::futures::future::BoxFuture<'static, ()> {
+                async_pub_sub::rpc_sleep(duration)
             }
+
+            #stream_buffer_size
+
+            #(#client_methods)*
         }
 
-        #server_trait_impl
+        #trait_impl_for_client
+
+        #server_trait
+
+        #concurrent_server_trait
     };
 
     expanded.into()
@@ -74,38 +135,139 @@ fn generate_enum_variants<'a>(
     methods: &'a [&'a syn::TraitItemFn],
 ) -> impl Iterator<Item = proc_macro2::TokenStream> + 'a {
     methods.iter().map(|method| {
-        let name = &method.sig.ident;
-        let variant_name = format_ident!("{}", name.to_string().to_upper_camel_case());
+        let variant_name = variant_name(method);
+        let request_type = request_type(method);
 
-        let input_types: Vec<_> = method
-            .sig
-            .inputs
-            .iter()
-            .filter_map(|input| match input {
-                syn::FnArg::Typed(pat_type) => Some(&pat_type.ty),
-                syn::FnArg::Receiver(_) => None, // ignore self
-            })
-            .collect();
+        quote! {
+            #variant_name(#request_type),
+        }
+    })
+}
 
-        let input_types = if input_types.is_empty() {
-            quote! { () }
-        } else if input_types.len() == 1 {
-            let ty = input_types
-                .first()
-                .expect("input_types should not be empty");
-            quote! { #ty }
-        } else {
-            quote! { (#(#input_types),*) }
-        };
+/// Implements `Clone` for the message enum whenever every request content is `Clone`.
+fn generate_clone_impl(
+    message_enum_name: &syn::Ident,
+    methods: &[&syn::TraitItemFn],
+) -> proc_macro2::TokenStream {
+    let request_types = methods.iter().map(|method| request_type(method));
+    let variant_names: Vec<_> = methods.iter().map(|method| variant_name(method)).collect();
 
-        let output_type = match &method.sig.output {
-            syn::ReturnType::Type(_, ty) => quote! { #ty },
-            syn::ReturnType::Default => quote! { () },
-        };
+    // The higher-ranked bounds are only checked when the impl is used, so the message
+    // enum stays valid when some request content is not `Clone`.
+    quote! {
+        impl Clone for #message_enum_name
+        where
+            #(for<'__rpc> #request_types: Clone,)*
+        {
+            fn clone(&self) -> Self {
+                match *self {
+                    #(Self::#variant_names(ref request) => Self::#variant_names(request.clone()),)*
+                }
+            }
+        }
+    }
+}
 
-        quote! {
-            #variant_name(async_pub_sub::Request<#input_types, #output_type>),
+/// Implements `Traceable` for the message enum, forwarding to the request of each variant.
+fn generate_traceable_impl(
+    message_enum_name: &syn::Ident,
+    methods: &[&syn::TraitItemFn],
+) -> proc_macro2::TokenStream {
+    let variant_names: Vec<_> = methods.iter().map(|method| variant_name(method)).collect();
+
+    quote! {
+        impl async_pub_sub::Traceable for #message_enum_name {
+            fn trace_context(&self) -> &async_pub_sub::TraceContext {
+                match *self {
+                    #(Self::#variant_names(ref request) => async_pub_sub::Traceable::trace_context(request),)*
+                }
+            }
+
+            fn trace_context_mut(&mut self) -> &mut async_pub_sub::TraceContext {
+                match *self {
+                    #(Self::#variant_names(ref mut request) => async_pub_sub::Traceable::trace_context_mut(request),)*
+                }
+            }
+        }
+    }
+}
+
+fn variant_name(method: &syn::TraitItemFn) -> syn::Ident {
+    format_ident!("{}", method.sig.ident.to_string().to_upper_camel_case())
+}
+
+fn request_type(method: &syn::TraitItemFn) -> proc_macro2::TokenStream {
+    let input_types: Vec<_> = method
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            syn::FnArg::Typed(pat_type) => Some(&pat_type.ty),
+            syn::FnArg::Receiver(_) => None, // ignore self
+        })
+        .collect();
+
+    let input_types = if input_types.is_empty() {
+        quote! { () }
+    } else if input_types.len() == 1 {
+        let ty = input_types
+            .first()
+            .expect("input_types should not be empty");
+        quote! { #ty }
+    } else {
+        quote! { (#(#input_types),*) }
+    };
+
+    if is_notification(method) {
+        return quote! { async_pub_sub::Notification<#input_types> };
+    }
+    if let Some(item_type) = stream_item_type(method) {
+        return quote! { async_pub_sub::StreamRequest<#input_types, #item_type> };
+    }
+
+    let output_type = match &method.sig.output {
+        syn::ReturnType::Type(_, ty) => quote! { #ty },
+        syn::ReturnType::Default => quote! { () },
+    };
+
+    quote! { async_pub_sub::Request<#input_types, #output_type> }
+}
+
+/// Returns true if the method is a one-way notification, marked with `#[notify]`.
+fn is_notification(method: &syn::TraitItemFn) -> bool {
+    method
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("notify"))
+}
+
+/// Returns the item type of a streaming method, i.e. a method returning
+/// `impl Stream<Item = T>`.
+fn stream_item_type(method: &syn::TraitItemFn) -> Option<&syn::Type> {
+    let syn::ReturnType::Type(_, ty) = &method.sig.output else {
+        return None;
+    };
+    let syn::Type::ImplTrait(impl_trait) = &**ty else {
+        return None;
+    };
+
+    impl_trait.bounds.iter().find_map(|bound| {
+        let syn::TypeParamBound::Trait(trait_bound) = bound else {
+            return None;
+        };
+        let segment = trait_bound.path.segments.last()?;
+        if segment.ident != "Stream" {
+            return None;
         }
+        let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+            return None;
+        };
+        arguments.args.iter().find_map(|argument| match argument {
+            syn::GenericArgument::AssocType(assoc_type) if assoc_type.ident == "Item" => {
+                Some(&assoc_type.ty)
+            }
+            _ => None,
+        })
     })
 }
 
@@ -144,23 +306,133 @@ fn generate_client_methods<'a>(
             quote! { (#(#request_content),*) }
         };
 
-        let publish_failure_message = format!("failed to publish {} request", name);
-        let response_failure_message = format!("failed to receive {} response", name);
+        let arg_names = arg_names(method);
+        let try_name = format_ident!("try_{}", name);
+        let method_name = name.to_string();
+
+        // Notifications resolve as soon as they are published.
+        if is_notification(method) {
+            return quote! {
+                fn #name(#args) -> impl std::future::Future<Output = ()> {
+                    async move {
+                        self.#try_name(#(#arg_names),*)
+                            .await
+                            .unwrap_or_else(|error| panic!("{}", error))
+                    }
+                }
+
+                fn #try_name(#args) -> impl std::future::Future<
+                    Output = core::result::Result<(), async_pub_sub::RpcError>,
+                > {
+                    async move {
+                        let notification = async_pub_sub::Notification::new(#request_content);
+                        self.publish(#message_enum_name::#variant_name(notification))
+                            .await
+                            .map_err(|error| async_pub_sub::RpcError::publish_failed(#method_name, error))
+                    }
+                }
+            };
+        }
+
+        // Streaming methods resolve to the stream of responses once the request is published.
+        if let Some(item_type) = stream_item_type(method) {
+            return quote! {
+                fn #name(#args) -> impl std::future::Future<Output = async_pub_sub::ResponseStream<#item_type>> {
+                    async move {
+                        self.#try_name(#(#arg_names),*)
+                            .await
+                            .unwrap_or_else(|error| panic!("{}", error))
+                    }
+                }
+
+                fn #try_name(#args) -> impl std::future::Future<
+                    Output = core::result::Result<async_pub_sub::ResponseStream<#item_type>, async_pub_sub::RpcError>,
+                > {
+                    async move {
+                        let (request, responses) =
+                            async_pub_sub::StreamRequest::new(#request_content, self.stream_buffer_size());
+                        self.publish(#message_enum_name::#variant_name(request))
+                            .await
+                            .map_err(|error| async_pub_sub::RpcError::publish_failed(#method_name, error))?;
+                        Ok(responses)
+                    }
+                }
+            };
+        }
+
+        // The fallible variants report the failures instead of panicking, the call timeout
+        // defaults to the one of the client.
+        let try_with_timeout_name = format_ident!("try_{}_with_timeout", name);
+        let try_function_signature = quote! {
+            #try_name(#args) -> impl std::future::Future<
+                Output = core::result::Result<#output_type, async_pub_sub::RpcError>,
+            >
+        };
+        let try_with_timeout_function_signature = quote! {
+            #try_with_timeout_name(#args, call_timeout: std::time::Duration) -> impl std::future::Future<
+                Output = core::result::Result<#output_type, async_pub_sub::RpcError>,
+            >
+        };
 
         quote! {
             fn #function_signature {
                 async move {
-                    let (request, response) = async_pub_sub::Request::new(#request_content);
-                    self.publish(#message_enum_name::#variant_name(request))
+                    self.#try_name(#(#arg_names),*)
                         .await
-                        .expect(#publish_failure_message);
-                    response.await.expect(#response_failure_message)
+                        .unwrap_or_else(|error| panic!("{}", error))
+                }
+            }
+
+            fn #try_function_signature {
+                async move {
+                    let (request, response) = async_pub_sub::Request::new(#request_content);
+                    async_pub_sub::rpc_call(
+                        #method_name,
+                        self.publish(#message_enum_name::#variant_name(request)),
+                        response,
+                        self.rpc_timeout().map(|timeout| self.rpc_sleep(timeout)),
+                    )
+                    .await
+                }
+            }
+
+            fn #try_with_timeout_function_signature {
+                async move {
+                    let (request, response) = async_pub_sub::Request::new(#request_content);
+                    async_pub_sub::rpc_call(
+                        #method_name,
+                        self.publish(#message_enum_name::#variant_name(request)),
+                        response,
+                        Some(self.rpc_sleep(call_timeout)),
+                    )
+                    .await
                 }
             }
         }
     })
 }
 
+/// Returns the names of the arguments of a method, excluding `self`.
+fn arg_names(method: &syn::TraitItemFn) -> Vec<&syn::Ident> {
+    method
+        .sig
+        .inputs
+        .iter()
+        .skip(1)
+        .map(|arg| {
+            if let syn::FnArg::Typed(pat_type) = arg {
+                if let syn::Pat::Ident(pat_ident) = &*pat_type.pat {
+                    &pat_ident.ident
+                } else {
+                    panic!("Expected identifier pattern for argument")
+                }
+            } else {
+                panic!("Expected typed argument")
+            }
+        })
+        .collect()
+}
+
 fn generate_trait_impl_for_client(
     trait_name: &syn::Ident,
     client_trait_name: &syn::Ident,
@@ -171,22 +443,7 @@ fn generate_trait_impl_for_client(
         let args = &method.sig.inputs;
         let output = &method.sig.output;
 
-        // Extract argument names excluding &self
-        let arg_names: Vec<_> = args
-            .iter()
-            .skip(1)
-            .map(|arg| {
-                if let syn::FnArg::Typed(pat_type) = arg {
-                    if let syn::Pat::Ident(pat_ident) = &*pat_type.pat {
-                        &pat_ident.ident
-                    } else {
-                        panic!("Expected identifier pattern for argument")
-                    }
-                } else {
-                    panic!("Expected typed argument")
-                }
-            })
-            .collect();
+        let arg_names = arg_names(method);
 
         let function_signature = quote! { #name(#args) #output };
 
@@ -226,15 +483,25 @@ fn generate_server_impl<'a>(
             })
             .collect();
 
-        let function_call = if arg_names.is_empty() {
-            quote! { let response = <Self as #trait_name>::#name(self).await; }
+        let (unpack_content, call) = if arg_names.is_empty() {
+            (
+                quote! {},
+                quote! { <Self as #trait_name>::#name(self).await },
+            )
         } else if arg_names.len() == 1 {
-            quote! { let response = <Self as #trait_name>::#name(self, content).await; }
+            (
+                quote! {},
+                quote! { <Self as #trait_name>::#name(self, content).await },
+            )
         } else {
-            quote! {
-                let (#(#arg_names),*) = content;
-                let response = <Self as #trait_name>::#name(self, #(#arg_names),*).await;
-            }
+            (
+                quote! { let (#(#arg_names),*) = content; },
+                quote! { <Self as #trait_name>::#name(self, #(#arg_names),*).await },
+            )
+        };
+        let function_call = quote! {
+            #unpack_content
+            let response = #call;
         };
 
         let content = if arg_names.is_empty() {
@@ -243,28 +510,261 @@ fn generate_server_impl<'a>(
             quote! { content }
         };
 
+        if is_notification(method) {
+            return quote! {
+                #message_enum_name::#variant_name(notification) => {
+                    let async_pub_sub::Notification { #content, .. } = notification;
+                    #unpack_content
+                    #call;
+                }
+            };
+        }
+
+        // Streams are forwarded until they end or their caller drops them.
+        if stream_item_type(method).is_some() {
+            return quote! {
+                #message_enum_name::#variant_name(req) => {
+                    let async_pub_sub::StreamRequest {
+                        #content,
+                        response_sender,
+                        ..
+                    } = req;
+                    if !response_sender.is_cancelled() {
+                        #function_call
+                        response_sender.forward(response).await;
+                    }
+                }
+            };
+        }
+
         quote! {
             #message_enum_name::#variant_name(req) => {
                 let async_pub_sub::Request {
                     #content,
                     response_sender,
+                    ..
                 } = req;
-                #function_call
-                response_sender.send(response).expect("failed to send response");
+                // Requests whose caller is gone are skipped, late responses are discarded.
+                if !response_sender.is_cancelled() {
+                    #function_call
+                    let _ = response_sender.send(response);
+                }
             }
         }
     })
 }
 
-fn generate_server_trait_impl(
+/// Generates the server trait, handling one request at a time, implemented by every type
+/// implementing the interface and subscribing to its messages.
+fn generate_server_trait(
     server_trait_name: &syn::Ident,
     message_enum_name: &syn::Ident,
     trait_name: &syn::Ident,
+    methods: &[&syn::TraitItemFn],
 ) -> proc_macro2::TokenStream {
+    let server_impl = generate_server_impl(message_enum_name, trait_name, methods);
+
     quote! {
+        pub trait #server_trait_name: async_pub_sub::SubscriberWrapper<#message_enum_name> + #trait_name {
+            async fn run(&mut self) {
+                while let Some(request) = self.recv().await {
+                    self.handle_request(request).await;
+                }
+            }
+
+            async fn run_until_shutdown(&mut self, shutdown: async_pub_sub::ShutdownSignal) {
+                while let Some(Some(request)) = shutdown.until_shutdown(self.recv()).await {
+                    self.handle_request(request).await;
+                }
+            }
+
+            async fn handle_request(&mut self, request: #message_enum_name) {
+                let trace_context = async_pub_sub::Traceable::trace_context(&request).clone();
+                let subscriber_name =
+                    <Self as async_pub_sub::SubscriberWrapper<#message_enum_name>>::get_name(self);
+
+                trace_context
+                    .instrument(subscriber_name, async move {
+                        match request {
+                            #(#server_impl)*
+                        }
+                    })
+                    .await
+            }
+        }
+
         impl<T> #server_trait_name for T where
             T: #trait_name + async_pub_sub::SubscriberWrapper<#message_enum_name>
         {
         }
     }
 }
+
+/// Generates the concurrent server trait, implemented by every type implementing the
+/// interface.
+///
+/// Requests of `&self` methods are handled concurrently, up to the given limit. A request of
+/// a `&mut self` method waits for the in-flight requests and is handled alone. The requests
+/// are received from a subscriber given apart from the server, so that receiving does not
+/// borrow the server mutably while requests are in flight.
+fn generate_concurrent_server_trait(
+    concurrent_server_trait_name: &syn::Ident,
+    message_enum_name: &syn::Ident,
+    trait_name: &syn::Ident,
+    methods: &[&syn::TraitItemFn],
+) -> proc_macro2::TokenStream {
+    let shared_methods: Vec<_> = methods
+        .iter()
+        .copied()
+        .filter(|method| is_shared(method))
+        .collect();
+    let shared_variant_names: Vec<_> = shared_methods
+        .iter()
+        .map(|method| variant_name(method))
+        .collect();
+
+    let shared_server_impl = generate_server_impl(message_enum_name, trait_name, &shared_methods);
+    let server_impl = generate_server_impl(message_enum_name, trait_name, methods);
+
+    let is_shared_request = if shared_variant_names.is_empty() {
+        quote! { false }
+    } else {
+        quote! { matches!(request, #(#message_enum_name::#shared_variant_names(_))|*) }
+    };
+    let exclusive_arm = if shared_methods.len() < methods.len() {
+        quote! { _ => unreachable!("requests of &mut self methods are handled exclusively"), }
+    } else {
+        quote! {}
+    };
+    // Receiving stops once the shutdown is triggered, as if the channel were closed.
+    let receive = quote! {
+        async {
+            match shutdown {
+                Some(shutdown) => shutdown.until_shutdown(subscriber.recv()).await.flatten(),
+                None => subscriber.recv().await,
+            }
+        }
+    };
+
+    quote! {
+        pub trait #concurrent_server_trait_name: #trait_name {
+            async fn run_concurrently(
+                &mut self,
+                subscriber: &mut impl async_pub_sub::SubscriberWrapper<#message_enum_name>,
+                max_in_flight: usize,
+            ) {
+                self.__run_concurrently(subscriber, max_in_flight, None).await
+            }
+
+            async fn run_concurrently_until_shutdown(
+                &mut self,
+                shutdown: async_pub_sub::ShutdownSignal,
+                subscriber: &mut impl async_pub_sub::SubscriberWrapper<#message_enum_name>,
+                max_in_flight: usize,
+            ) {
+                self.__run_concurrently(subscriber, max_in_flight, Some(&shutdown)).await
+            }
+
+            // The helpers below are implementation details of `run_concurrently`, prefixed so
+            // that they do not clash with the methods of the interface.
+            #[doc(hidden)]
+            async fn __run_concurrently(
+                &mut self,
+                subscriber: &mut impl async_pub_sub::SubscriberWrapper<#message_enum_name>,
+                max_in_flight: usize,
+                shutdown: Option<&async_pub_sub::ShutdownSignal>,
+            ) {
+                assert!(max_in_flight > 0, "max_in_flight should be greater than 0");
+                let subscriber_name =
+                    async_pub_sub::SubscriberWrapper::<#message_enum_name>::get_name(subscriber);
+
+                loop {
+                    let mut exclusive_request = None;
+                    let mut closed = false;
+                    {
+                        let server = &*self;
+                        let mut in_flight = async_pub_sub::futures::stream::FuturesUnordered::new();
+
+                        loop {
+                            if in_flight.len() >= max_in_flight {
+                                async_pub_sub::futures::StreamExt::next(&mut in_flight).await;
+                                continue;
+                            }
+
+                            let received = if in_flight.is_empty() {
+                                #receive.await
+                            } else {
+                                let next_request = core::pin::pin!(#receive);
+                                let next_response =
+                                    async_pub_sub::futures::StreamExt::next(&mut in_flight);
+                                match async_pub_sub::futures::future::select(next_request, next_response)
+                                    .await
+                                {
+                                    async_pub_sub::futures::future::Either::Left((received, _)) => received,
+                                    async_pub_sub::futures::future::Either::Right(_) => continue,
+                                }
+                            };
+
+                            let Some(request) = received else {
+                                closed = true;
+                                break;
+                            };
+                            if #is_shared_request {
+                                in_flight.push(server.__handle_shared_request(request, subscriber_name));
+                            } else {
+                                exclusive_request = Some(request);
+                                break;
+                            }
+                        }
+
+                        while async_pub_sub::futures::StreamExt::next(&mut in_flight).await.is_some() {}
+                    }
+
+                    if closed {
+                        break;
+                    }
+                    if let Some(request) = exclusive_request {
+                        self.__handle_exclusive_request(request, subscriber_name).await;
+                    }
+                }
+            }
+
+            #[doc(hidden)]
+            async fn __handle_shared_request(&self, request: #message_enum_name, subscriber_name: &'static str) {
+                let trace_context = async_pub_sub::Traceable::trace_context(&request).clone();
+
+                trace_context
+                    .instrument(subscriber_name, async move {
+                        match request {
+                            #(#shared_server_impl)*
+                            #exclusive_arm
+                        }
+                    })
+                    .await
+            }
+
+            #[doc(hidden)]
+            async fn __handle_exclusive_request(&mut self, request: #message_enum_name, subscriber_name: &'static str) {
+                let trace_context = async_pub_sub::Traceable::trace_context(&request).clone();
+
+                trace_context
+                    .instrument(subscriber_name, async move {
+                        match request {
+                            #(#server_impl)*
+                        }
+                    })
+                    .await
+            }
+        }
+
+        impl<T> #concurrent_server_trait_name for T where T: #trait_name {}
+    }
+}
+
+/// Returns true if the method takes `&self`, so that its requests can be handled concurrently.
+fn is_shared(method: &syn::TraitItemFn) -> bool {
+    method
+        .sig
+        .receiver()
+        .is_some_and(|receiver| receiver.reference.is_some() && receiver.mutability.is_none())
+}
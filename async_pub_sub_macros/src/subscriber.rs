@@ -43,6 +43,10 @@ pub(crate) fn derive_subscriber_impl(input: DeriveInput) -> TokenStream {
                 fn receive(&mut self) -> impl std::future::Future<Output = Self::Message> {
                     async_pub_sub::Subscriber::receive(&mut self.#field_name)
                 }
+
+                fn recv(&mut self) -> impl std::future::Future<Output = Option<Self::Message>> {
+                    async_pub_sub::Subscriber::recv(&mut self.#field_name)
+                }
             }
         }
     } else {
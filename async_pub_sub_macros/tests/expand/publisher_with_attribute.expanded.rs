@@ -16,6 +16,24 @@ impl async_pub_sub::Publisher for TestPublisherA {
     ) -> async_pub_sub::futures::future::BoxFuture<async_pub_sub::Result<()>> {
         async_pub_sub::Publisher::publish(&self.publisher_a, message)
     }
+    fn try_publish(
+        &self,
+        message: Self::Message,
+    ) -> async_pub_sub::Result<async_pub_sub::PublishOutcome> {
+        async_pub_sub::Publisher::try_publish(&self.publisher_a, message)
+    }
+    fn close(&self) {
+        async_pub_sub::Publisher::close(&self.publisher_a)
+    }
+    fn is_bound(&self) -> bool {
+        async_pub_sub::Publisher::is_bound(&self.publisher_a)
+    }
+    fn is_closed(&self) -> bool {
+        async_pub_sub::Publisher::is_closed(&self.publisher_a)
+    }
+    fn closed(&self) -> async_pub_sub::futures::future::BoxFuture<()> {
+        async_pub_sub::Publisher::closed(&self.publisher_a)
+    }
     fn get_message_stream(
         &mut self,
         subscriber_name: &'static str,
@@ -49,6 +67,24 @@ impl async_pub_sub::Publisher for TestPublisherB {
     ) -> async_pub_sub::futures::future::BoxFuture<async_pub_sub::Result<()>> {
         async_pub_sub::Publisher::publish(&self.publisher_b, message)
     }
+    fn try_publish(
+        &self,
+        message: Self::Message,
+    ) -> async_pub_sub::Result<async_pub_sub::PublishOutcome> {
+        async_pub_sub::Publisher::try_publish(&self.publisher_b, message)
+    }
+    fn close(&self) {
+        async_pub_sub::Publisher::close(&self.publisher_b)
+    }
+    fn is_bound(&self) -> bool {
+        async_pub_sub::Publisher::is_bound(&self.publisher_b)
+    }
+    fn is_closed(&self) -> bool {
+        async_pub_sub::Publisher::is_closed(&self.publisher_b)
+    }
+    fn closed(&self) -> async_pub_sub::futures::future::BoxFuture<()> {
+        async_pub_sub::Publisher::closed(&self.publisher_b)
+    }
     fn get_message_stream(
         &mut self,
         subscriber_name: &'static str,
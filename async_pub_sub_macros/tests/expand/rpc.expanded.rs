@@ -1,5 +1,11 @@
+#![feature(prelude_import)]
 #![allow(unused_imports)]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2021::*;
+use async_pub_sub::futures::Stream;
 use async_pub_sub_macros::rpc_interface;
+
 #[allow(async_fn_in_trait)]
 pub trait RpcInterface {
     async fn add_one(&self, value: i32) -> i32;
@@ -7,6 +13,7 @@ pub trait RpcInterface {
     async fn prefix_with_bar(&self, string: String) -> String;
     async fn get_toto(&self) -> String;
     async fn set_tata(&mut self, tata: String);
+    async fn notify_titi(&mut self, titi: String);
 }
 pub enum RpcInterfaceMessage {
     AddOne(async_pub_sub::Request<i32, i32>),
@@ -14,6 +21,7 @@ pub enum RpcInterfaceMessage {
     PrefixWithBar(async_pub_sub::Request<String, String>),
     GetToto(async_pub_sub::Request<(), String>),
     SetTata(async_pub_sub::Request<String, ()>),
+    NotifyTiti(async_pub_sub::Notification<String>),
 }
 #[automatically_derived]
 impl ::core::fmt::Debug for RpcInterfaceMessage {
@@ -27,76 +35,288 @@ impl ::core::fmt::Debug for RpcInterfaceMessage {
                 ::core::fmt::Formatter::debug_tuple_field1_finish(f, "Add", &__self_0)
             }
             RpcInterfaceMessage::PrefixWithBar(__self_0) => {
-                ::core::fmt::Formatter::debug_tuple_field1_finish(
-                    f,
-                    "PrefixWithBar",
-                    &__self_0,
-                )
+                ::core::fmt::Formatter::debug_tuple_field1_finish(f, "PrefixWithBar", &__self_0)
             }
             RpcInterfaceMessage::GetToto(__self_0) => {
-                ::core::fmt::Formatter::debug_tuple_field1_finish(
-                    f,
-                    "GetToto",
-                    &__self_0,
-                )
+                ::core::fmt::Formatter::debug_tuple_field1_finish(f, "GetToto", &__self_0)
             }
             RpcInterfaceMessage::SetTata(__self_0) => {
-                ::core::fmt::Formatter::debug_tuple_field1_finish(
-                    f,
-                    "SetTata",
-                    &__self_0,
-                )
+                ::core::fmt::Formatter::debug_tuple_field1_finish(f, "SetTata", &__self_0)
+            }
+            RpcInterfaceMessage::NotifyTiti(__self_0) => {
+                ::core::fmt::Formatter::debug_tuple_field1_finish(f, "NotifyTiti", &__self_0)
+            }
+        }
+    }
+}
+impl Clone for RpcInterfaceMessage
+where
+    for<'__rpc> async_pub_sub::Request<i32, i32>: Clone,
+    for<'__rpc> async_pub_sub::Request<(i32, i32), i32>: Clone,
+    for<'__rpc> async_pub_sub::Request<String, String>: Clone,
+    for<'__rpc> async_pub_sub::Request<(), String>: Clone,
+    for<'__rpc> async_pub_sub::Request<String, ()>: Clone,
+    for<'__rpc> async_pub_sub::Notification<String>: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            Self::AddOne(ref request) => Self::AddOne(request.clone()),
+            Self::Add(ref request) => Self::Add(request.clone()),
+            Self::PrefixWithBar(ref request) => Self::PrefixWithBar(request.clone()),
+            Self::GetToto(ref request) => Self::GetToto(request.clone()),
+            Self::SetTata(ref request) => Self::SetTata(request.clone()),
+            Self::NotifyTiti(ref request) => Self::NotifyTiti(request.clone()),
+        }
+    }
+}
+impl async_pub_sub::Traceable for RpcInterfaceMessage {
+    fn trace_context(&self) -> &async_pub_sub::TraceContext {
+        match *self {
+            Self::AddOne(ref request) => async_pub_sub::Traceable::trace_context(request),
+            Self::Add(ref request) => async_pub_sub::Traceable::trace_context(request),
+            Self::PrefixWithBar(ref request) => async_pub_sub::Traceable::trace_context(request),
+            Self::GetToto(ref request) => async_pub_sub::Traceable::trace_context(request),
+            Self::SetTata(ref request) => async_pub_sub::Traceable::trace_context(request),
+            Self::NotifyTiti(ref request) => async_pub_sub::Traceable::trace_context(request),
+        }
+    }
+    fn trace_context_mut(&mut self) -> &mut async_pub_sub::TraceContext {
+        match *self {
+            Self::AddOne(ref mut request) => async_pub_sub::Traceable::trace_context_mut(request),
+            Self::Add(ref mut request) => async_pub_sub::Traceable::trace_context_mut(request),
+            Self::PrefixWithBar(ref mut request) => {
+                async_pub_sub::Traceable::trace_context_mut(request)
+            }
+            Self::GetToto(ref mut request) => async_pub_sub::Traceable::trace_context_mut(request),
+            Self::SetTata(ref mut request) => async_pub_sub::Traceable::trace_context_mut(request),
+            Self::NotifyTiti(ref mut request) => {
+                async_pub_sub::Traceable::trace_context_mut(request)
             }
         }
     }
 }
 pub trait RpcInterfaceClient: async_pub_sub::PublisherWrapper<RpcInterfaceMessage> {
+    fn rpc_timeout(&self) -> Option<std::time::Duration> {
+        None
+    }
+    fn rpc_sleep(
+        &self,
+        duration: std::time::Duration,
+    ) -> async_pub_sub::futures::future::BoxFuture<'static, ()> {
+        async_pub_sub::rpc_sleep(duration)
+    }
     fn add_one(&self, value: i32) -> impl std::future::Future<Output = i32> {
+        async move {
+            self.try_add_one(value).await.unwrap_or_else(|error| {
+                ::core::panicking::panic_display(&error);
+            })
+        }
+    }
+    fn try_add_one(
+        &self,
+        value: i32,
+    ) -> impl std::future::Future<Output = core::result::Result<i32, async_pub_sub::RpcError>> {
         async move {
             let (request, response) = async_pub_sub::Request::new(value);
-            self.publish(RpcInterfaceMessage::AddOne(request))
-                .await
-                .expect("failed to publish add_one request");
-            response.await.expect("failed to receive add_one response")
+            async_pub_sub::rpc_call(
+                "add_one",
+                self.publish(RpcInterfaceMessage::AddOne(request)),
+                response,
+                self.rpc_timeout().map(|timeout| self.rpc_sleep(timeout)),
+            )
+            .await
+        }
+    }
+    fn try_add_one_with_timeout(
+        &self,
+        value: i32,
+        call_timeout: std::time::Duration,
+    ) -> impl std::future::Future<Output = core::result::Result<i32, async_pub_sub::RpcError>> {
+        async move {
+            let (request, response) = async_pub_sub::Request::new(value);
+            async_pub_sub::rpc_call(
+                "add_one",
+                self.publish(RpcInterfaceMessage::AddOne(request)),
+                response,
+                Some(self.rpc_sleep(call_timeout)),
+            )
+            .await
         }
     }
     fn add(&self, left: i32, right: i32) -> impl std::future::Future<Output = i32> {
+        async move {
+            self.try_add(left, right).await.unwrap_or_else(|error| {
+                ::core::panicking::panic_display(&error);
+            })
+        }
+    }
+    fn try_add(
+        &self,
+        left: i32,
+        right: i32,
+    ) -> impl std::future::Future<Output = core::result::Result<i32, async_pub_sub::RpcError>> {
         async move {
             let (request, response) = async_pub_sub::Request::new((left, right));
-            self.publish(RpcInterfaceMessage::Add(request))
+            async_pub_sub::rpc_call(
+                "add",
+                self.publish(RpcInterfaceMessage::Add(request)),
+                response,
+                self.rpc_timeout().map(|timeout| self.rpc_sleep(timeout)),
+            )
+            .await
+        }
+    }
+    fn try_add_with_timeout(
+        &self,
+        left: i32,
+        right: i32,
+        call_timeout: std::time::Duration,
+    ) -> impl std::future::Future<Output = core::result::Result<i32, async_pub_sub::RpcError>> {
+        async move {
+            let (request, response) = async_pub_sub::Request::new((left, right));
+            async_pub_sub::rpc_call(
+                "add",
+                self.publish(RpcInterfaceMessage::Add(request)),
+                response,
+                Some(self.rpc_sleep(call_timeout)),
+            )
+            .await
+        }
+    }
+    fn prefix_with_bar(&self, string: String) -> impl std::future::Future<Output = String> {
+        async move {
+            self.try_prefix_with_bar(string)
                 .await
-                .expect("failed to publish add request");
-            response.await.expect("failed to receive add response")
+                .unwrap_or_else(|error| {
+                    ::core::panicking::panic_display(&error);
+                })
         }
     }
-    fn prefix_with_bar(
+    fn try_prefix_with_bar(
         &self,
         string: String,
-    ) -> impl std::future::Future<Output = String> {
+    ) -> impl std::future::Future<Output = core::result::Result<String, async_pub_sub::RpcError>>
+    {
         async move {
             let (request, response) = async_pub_sub::Request::new(string);
-            self.publish(RpcInterfaceMessage::PrefixWithBar(request))
-                .await
-                .expect("failed to publish prefix_with_bar request");
-            response.await.expect("failed to receive prefix_with_bar response")
+            async_pub_sub::rpc_call(
+                "prefix_with_bar",
+                self.publish(RpcInterfaceMessage::PrefixWithBar(request)),
+                response,
+                self.rpc_timeout().map(|timeout| self.rpc_sleep(timeout)),
+            )
+            .await
+        }
+    }
+    fn try_prefix_with_bar_with_timeout(
+        &self,
+        string: String,
+        call_timeout: std::time::Duration,
+    ) -> impl std::future::Future<Output = core::result::Result<String, async_pub_sub::RpcError>>
+    {
+        async move {
+            let (request, response) = async_pub_sub::Request::new(string);
+            async_pub_sub::rpc_call(
+                "prefix_with_bar",
+                self.publish(RpcInterfaceMessage::PrefixWithBar(request)),
+                response,
+                Some(self.rpc_sleep(call_timeout)),
+            )
+            .await
         }
     }
     fn get_toto(&self) -> impl std::future::Future<Output = String> {
+        async move {
+            self.try_get_toto().await.unwrap_or_else(|error| {
+                ::core::panicking::panic_display(&error);
+            })
+        }
+    }
+    fn try_get_toto(
+        &self,
+    ) -> impl std::future::Future<Output = core::result::Result<String, async_pub_sub::RpcError>>
+    {
         async move {
             let (request, response) = async_pub_sub::Request::new(());
-            self.publish(RpcInterfaceMessage::GetToto(request))
-                .await
-                .expect("failed to publish get_toto request");
-            response.await.expect("failed to receive get_toto response")
+            async_pub_sub::rpc_call(
+                "get_toto",
+                self.publish(RpcInterfaceMessage::GetToto(request)),
+                response,
+                self.rpc_timeout().map(|timeout| self.rpc_sleep(timeout)),
+            )
+            .await
+        }
+    }
+    fn try_get_toto_with_timeout(
+        &self,
+        call_timeout: std::time::Duration,
+    ) -> impl std::future::Future<Output = core::result::Result<String, async_pub_sub::RpcError>>
+    {
+        async move {
+            let (request, response) = async_pub_sub::Request::new(());
+            async_pub_sub::rpc_call(
+                "get_toto",
+                self.publish(RpcInterfaceMessage::GetToto(request)),
+                response,
+                Some(self.rpc_sleep(call_timeout)),
+            )
+            .await
         }
     }
     fn set_tata(&mut self, tata: String) -> impl std::future::Future<Output = ()> {
+        async move {
+            self.try_set_tata(tata).await.unwrap_or_else(|error| {
+                ::core::panicking::panic_display(&error);
+            })
+        }
+    }
+    fn try_set_tata(
+        &mut self,
+        tata: String,
+    ) -> impl std::future::Future<Output = core::result::Result<(), async_pub_sub::RpcError>> {
         async move {
             let (request, response) = async_pub_sub::Request::new(tata);
-            self.publish(RpcInterfaceMessage::SetTata(request))
+            async_pub_sub::rpc_call(
+                "set_tata",
+                self.publish(RpcInterfaceMessage::SetTata(request)),
+                response,
+                self.rpc_timeout().map(|timeout| self.rpc_sleep(timeout)),
+            )
+            .await
+        }
+    }
+    fn try_set_tata_with_timeout(
+        &mut self,
+        tata: String,
+        call_timeout: std::time::Duration,
+    ) -> impl std::future::Future<Output = core::result::Result<(), async_pub_sub::RpcError>> {
+        async move {
+            let (request, response) = async_pub_sub::Request::new(tata);
+            async_pub_sub::rpc_call(
+                "set_tata",
+                self.publish(RpcInterfaceMessage::SetTata(request)),
+                response,
+                Some(self.rpc_sleep(call_timeout)),
+            )
+            .await
+        }
+    }
+    fn notify_titi(&mut self, titi: String) -> impl std::future::Future<Output = ()> {
+        async move {
+            self.try_notify_titi(titi).await.unwrap_or_else(|error| {
+                ::core::panicking::panic_display(&error);
+            })
+        }
+    }
+    fn try_notify_titi(
+        &mut self,
+        titi: String,
+    ) -> impl std::future::Future<Output = core::result::Result<(), async_pub_sub::RpcError>> {
+        async move {
+            let notification = async_pub_sub::Notification::new(titi);
+            self.publish(RpcInterfaceMessage::NotifyTiti(notification))
                 .await
-                .expect("failed to publish set_tata request");
-            response.await.expect("failed to receive set_tata response")
+                .map_err(|error| async_pub_sub::RpcError::publish_failed("notify_titi", error))
         }
     }
 }
@@ -119,50 +339,669 @@ where
     async fn set_tata(&mut self, tata: String) {
         <Self as RpcInterfaceClient>::set_tata(self, tata).await
     }
+    async fn notify_titi(&mut self, titi: String) {
+        <Self as RpcInterfaceClient>::notify_titi(self, titi).await
+    }
 }
-pub trait RpcInterfaceServer: async_pub_sub::SubscriberWrapper<
-        RpcInterfaceMessage,
-    > + RpcInterface {
+pub trait RpcInterfaceServer:
+    async_pub_sub::SubscriberWrapper<RpcInterfaceMessage> + RpcInterface
+{
     async fn run(&mut self) {
-        loop {
-            let request = self.receive().await;
+        while let Some(request) = self.recv().await {
+            self.handle_request(request).await;
+        }
+    }
+    async fn run_until_shutdown(&mut self, shutdown: async_pub_sub::ShutdownSignal) {
+        while let Some(Some(request)) = shutdown.until_shutdown(self.recv()).await {
             self.handle_request(request).await;
         }
     }
     async fn handle_request(&mut self, request: RpcInterfaceMessage) {
-        match request {
-            RpcInterfaceMessage::AddOne(req) => {
-                let async_pub_sub::Request { content, response_sender } = req;
-                let response = <Self as RpcInterface>::add_one(self, content).await;
-                response_sender.send(response).expect("failed to send response");
-            }
-            RpcInterfaceMessage::Add(req) => {
-                let async_pub_sub::Request { content, response_sender } = req;
-                let (left, right) = content;
-                let response = <Self as RpcInterface>::add(self, left, right).await;
-                response_sender.send(response).expect("failed to send response");
-            }
-            RpcInterfaceMessage::PrefixWithBar(req) => {
-                let async_pub_sub::Request { content, response_sender } = req;
-                let response = <Self as RpcInterface>::prefix_with_bar(self, content)
+        let trace_context = async_pub_sub::Traceable::trace_context(&request).clone();
+        let subscriber_name =
+            <Self as async_pub_sub::SubscriberWrapper<RpcInterfaceMessage>>::get_name(self);
+        trace_context
+            .instrument(subscriber_name, async move {
+                match request {
+                    RpcInterfaceMessage::AddOne(req) => {
+                        let async_pub_sub::Request {
+                            content,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as RpcInterface>::add_one(self, content).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::Add(req) => {
+                        let async_pub_sub::Request {
+                            content,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let (left, right) = content;
+                            let response = <Self as RpcInterface>::add(self, left, right).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::PrefixWithBar(req) => {
+                        let async_pub_sub::Request {
+                            content,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response =
+                                <Self as RpcInterface>::prefix_with_bar(self, content).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::GetToto(req) => {
+                        let async_pub_sub::Request {
+                            content: _,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as RpcInterface>::get_toto(self).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::SetTata(req) => {
+                        let async_pub_sub::Request {
+                            content,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as RpcInterface>::set_tata(self, content).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::NotifyTiti(notification) => {
+                        let async_pub_sub::Notification { content, .. } = notification;
+                        <Self as RpcInterface>::notify_titi(self, content).await;
+                    }
+                }
+            })
+            .await
+    }
+}
+impl<T> RpcInterfaceServer for T where
+    T: RpcInterface + async_pub_sub::SubscriberWrapper<RpcInterfaceMessage>
+{
+}
+pub trait RpcInterfaceConcurrentServer: RpcInterface {
+    async fn run_concurrently(
+        &mut self,
+        subscriber: &mut impl async_pub_sub::SubscriberWrapper<RpcInterfaceMessage>,
+        max_in_flight: usize,
+    ) {
+        self.__run_concurrently(subscriber, max_in_flight, None)
+            .await
+    }
+    async fn run_concurrently_until_shutdown(
+        &mut self,
+        shutdown: async_pub_sub::ShutdownSignal,
+        subscriber: &mut impl async_pub_sub::SubscriberWrapper<RpcInterfaceMessage>,
+        max_in_flight: usize,
+    ) {
+        self.__run_concurrently(subscriber, max_in_flight, Some(&shutdown))
+            .await
+    }
+    #[doc(hidden)]
+    async fn __run_concurrently(
+        &mut self,
+        subscriber: &mut impl async_pub_sub::SubscriberWrapper<RpcInterfaceMessage>,
+        max_in_flight: usize,
+        shutdown: Option<&async_pub_sub::ShutdownSignal>,
+    ) {
+        if !(max_in_flight > 0) {
+            {
+                ::core::panicking::panic_fmt(format_args!(
+                    "max_in_flight should be greater than 0"
+                ));
+            }
+        };
+        let subscriber_name =
+            async_pub_sub::SubscriberWrapper::<RpcInterfaceMessage>::get_name(subscriber);
+        loop {
+            let mut exclusive_request = None;
+            let mut closed = false;
+            {
+                let server = &*self;
+                let mut in_flight = async_pub_sub::futures::stream::FuturesUnordered::new();
+                loop {
+                    if in_flight.len() >= max_in_flight {
+                        async_pub_sub::futures::StreamExt::next(&mut in_flight).await;
+                        continue;
+                    }
+                    let received = if in_flight.is_empty() {
+                        async {
+                            match shutdown {
+                                Some(shutdown) => {
+                                    shutdown.until_shutdown(subscriber.recv()).await.flatten()
+                                }
+                                None => subscriber.recv().await,
+                            }
+                        }
+                        .await
+                    } else {
+                        let next_request = {
+                            super let mut pinned =
+                                        async {
+                                            match shutdown {
+                                                Some(shutdown) =>
+                                                    shutdown.until_shutdown(subscriber.recv()).await.flatten(),
+                                                None => subscriber.recv().await,
+                                            }
+                                        };
+                            unsafe { ::core::pin::Pin::new_unchecked(&mut pinned) }
+                        };
+                        let next_response = async_pub_sub::futures::StreamExt::next(&mut in_flight);
+                        match async_pub_sub::futures::future::select(next_request, next_response)
+                            .await
+                        {
+                            async_pub_sub::futures::future::Either::Left((received, _)) => received,
+                            async_pub_sub::futures::future::Either::Right(_) => continue,
+                        }
+                    };
+                    let Some(request) = received else {
+                        closed = true;
+                        break;
+                    };
+                    if #[allow(non_exhaustive_omitted_patterns)]
+                    match request {
+                        RpcInterfaceMessage::AddOne(_)
+                        | RpcInterfaceMessage::Add(_)
+                        | RpcInterfaceMessage::PrefixWithBar(_)
+                        | RpcInterfaceMessage::GetToto(_) => true,
+                        _ => false,
+                    } {
+                        in_flight.push(server.__handle_shared_request(request, subscriber_name));
+                    } else {
+                        exclusive_request = Some(request);
+                        break;
+                    }
+                }
+                while async_pub_sub::futures::StreamExt::next(&mut in_flight)
+                    .await
+                    .is_some()
+                {}
+            }
+            if closed {
+                break;
+            }
+            if let Some(request) = exclusive_request {
+                self.__handle_exclusive_request(request, subscriber_name)
                     .await;
-                response_sender.send(response).expect("failed to send response");
             }
-            RpcInterfaceMessage::GetToto(req) => {
-                let async_pub_sub::Request { content: _, response_sender } = req;
-                let response = <Self as RpcInterface>::get_toto(self).await;
-                response_sender.send(response).expect("failed to send response");
+        }
+    }
+    #[doc(hidden)]
+    async fn __handle_shared_request(
+        &self,
+        request: RpcInterfaceMessage,
+        subscriber_name: &'static str,
+    ) {
+        let trace_context = async_pub_sub::Traceable::trace_context(&request).clone();
+        trace_context
+            .instrument(subscriber_name, async move {
+                match request {
+                    RpcInterfaceMessage::AddOne(req) => {
+                        let async_pub_sub::Request {
+                            content,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as RpcInterface>::add_one(self, content).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::Add(req) => {
+                        let async_pub_sub::Request {
+                            content,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let (left, right) = content;
+                            let response = <Self as RpcInterface>::add(self, left, right).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::PrefixWithBar(req) => {
+                        let async_pub_sub::Request {
+                            content,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response =
+                                <Self as RpcInterface>::prefix_with_bar(self, content).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::GetToto(req) => {
+                        let async_pub_sub::Request {
+                            content: _,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as RpcInterface>::get_toto(self).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    _ => {
+                        ::core::panicking::panic_fmt(format_args!(
+                            "internal error: entered unreachable code: {0}",
+                            format_args!("requests of &mut self methods are handled exclusively")
+                        ));
+                    }
+                }
+            })
+            .await
+    }
+    #[doc(hidden)]
+    async fn __handle_exclusive_request(
+        &mut self,
+        request: RpcInterfaceMessage,
+        subscriber_name: &'static str,
+    ) {
+        let trace_context = async_pub_sub::Traceable::trace_context(&request).clone();
+        trace_context
+            .instrument(subscriber_name, async move {
+                match request {
+                    RpcInterfaceMessage::AddOne(req) => {
+                        let async_pub_sub::Request {
+                            content,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as RpcInterface>::add_one(self, content).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::Add(req) => {
+                        let async_pub_sub::Request {
+                            content,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let (left, right) = content;
+                            let response = <Self as RpcInterface>::add(self, left, right).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::PrefixWithBar(req) => {
+                        let async_pub_sub::Request {
+                            content,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response =
+                                <Self as RpcInterface>::prefix_with_bar(self, content).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::GetToto(req) => {
+                        let async_pub_sub::Request {
+                            content: _,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as RpcInterface>::get_toto(self).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::SetTata(req) => {
+                        let async_pub_sub::Request {
+                            content,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as RpcInterface>::set_tata(self, content).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    RpcInterfaceMessage::NotifyTiti(notification) => {
+                        let async_pub_sub::Notification { content, .. } = notification;
+                        <Self as RpcInterface>::notify_titi(self, content).await;
+                    }
+                }
+            })
+            .await
+    }
+}
+impl<T> RpcInterfaceConcurrentServer for T where T: RpcInterface {}
+#[allow(async_fn_in_trait)]
+pub trait StreamingRpcInterface {
+    async fn get_toto(&self) -> String;
+    async fn watch_toto(&self) -> impl Stream<Item = String>;
+}
+pub enum StreamingRpcInterfaceMessage {
+    GetToto(async_pub_sub::Request<(), String>),
+    WatchToto(async_pub_sub::StreamRequest<(), String>),
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for StreamingRpcInterfaceMessage {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            StreamingRpcInterfaceMessage::GetToto(__self_0) => {
+                ::core::fmt::Formatter::debug_tuple_field1_finish(f, "GetToto", &__self_0)
             }
-            RpcInterfaceMessage::SetTata(req) => {
-                let async_pub_sub::Request { content, response_sender } = req;
-                let response = <Self as RpcInterface>::set_tata(self, content).await;
-                response_sender.send(response).expect("failed to send response");
+            StreamingRpcInterfaceMessage::WatchToto(__self_0) => {
+                ::core::fmt::Formatter::debug_tuple_field1_finish(f, "WatchToto", &__self_0)
             }
         }
     }
 }
-impl<T> RpcInterfaceServer for T
+impl Clone for StreamingRpcInterfaceMessage
 where
-    T: RpcInterface + async_pub_sub::SubscriberWrapper<RpcInterfaceMessage>,
-{}
+    for<'__rpc> async_pub_sub::Request<(), String>: Clone,
+    for<'__rpc> async_pub_sub::StreamRequest<(), String>: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            Self::GetToto(ref request) => Self::GetToto(request.clone()),
+            Self::WatchToto(ref request) => Self::WatchToto(request.clone()),
+        }
+    }
+}
+impl async_pub_sub::Traceable for StreamingRpcInterfaceMessage {
+    fn trace_context(&self) -> &async_pub_sub::TraceContext {
+        match *self {
+            Self::GetToto(ref request) => async_pub_sub::Traceable::trace_context(request),
+            Self::WatchToto(ref request) => async_pub_sub::Traceable::trace_context(request),
+        }
+    }
+    fn trace_context_mut(&mut self) -> &mut async_pub_sub::TraceContext {
+        match *self {
+            Self::GetToto(ref mut request) => async_pub_sub::Traceable::trace_context_mut(request),
+            Self::WatchToto(ref mut request) => {
+                async_pub_sub::Traceable::trace_context_mut(request)
+            }
+        }
+    }
+}
+pub trait StreamingRpcInterfaceClient:
+    async_pub_sub::PublisherWrapper<StreamingRpcInterfaceMessage>
+{
+    fn rpc_timeout(&self) -> Option<std::time::Duration> {
+        None
+    }
+    fn rpc_sleep(
+        &self,
+        duration: std::time::Duration,
+    ) -> async_pub_sub::futures::future::BoxFuture<'static, ()> {
+        async_pub_sub::rpc_sleep(duration)
+    }
+    fn stream_buffer_size(&self) -> usize {
+        16
+    }
+    fn get_toto(&self) -> impl std::future::Future<Output = String> {
+        async move {
+            self.try_get_toto().await.unwrap_or_else(|error| {
+                ::core::panicking::panic_display(&error);
+            })
+        }
+    }
+    fn try_get_toto(
+        &self,
+    ) -> impl std::future::Future<Output = core::result::Result<String, async_pub_sub::RpcError>>
+    {
+        async move {
+            let (request, response) = async_pub_sub::Request::new(());
+            async_pub_sub::rpc_call(
+                "get_toto",
+                self.publish(StreamingRpcInterfaceMessage::GetToto(request)),
+                response,
+                self.rpc_timeout().map(|timeout| self.rpc_sleep(timeout)),
+            )
+            .await
+        }
+    }
+    fn try_get_toto_with_timeout(
+        &self,
+        call_timeout: std::time::Duration,
+    ) -> impl std::future::Future<Output = core::result::Result<String, async_pub_sub::RpcError>>
+    {
+        async move {
+            let (request, response) = async_pub_sub::Request::new(());
+            async_pub_sub::rpc_call(
+                "get_toto",
+                self.publish(StreamingRpcInterfaceMessage::GetToto(request)),
+                response,
+                Some(self.rpc_sleep(call_timeout)),
+            )
+            .await
+        }
+    }
+    fn watch_toto(
+        &self,
+    ) -> impl std::future::Future<Output = async_pub_sub::ResponseStream<String>> {
+        async move {
+            self.try_watch_toto().await.unwrap_or_else(|error| {
+                ::core::panicking::panic_display(&error);
+            })
+        }
+    }
+    fn try_watch_toto(
+        &self,
+    ) -> impl std::future::Future<
+        Output = core::result::Result<
+            async_pub_sub::ResponseStream<String>,
+            async_pub_sub::RpcError,
+        >,
+    > {
+        async move {
+            let (request, responses) =
+                async_pub_sub::StreamRequest::new((), self.stream_buffer_size());
+            self.publish(StreamingRpcInterfaceMessage::WatchToto(request))
+                .await
+                .map_err(|error| async_pub_sub::RpcError::publish_failed("watch_toto", error))?;
+            Ok(responses)
+        }
+    }
+}
+impl<T> StreamingRpcInterface for T
+where
+    T: StreamingRpcInterfaceClient,
+{
+    async fn get_toto(&self) -> String {
+        <Self as StreamingRpcInterfaceClient>::get_toto(self).await
+    }
+    async fn watch_toto(&self) -> impl Stream<Item = String> {
+        <Self as StreamingRpcInterfaceClient>::watch_toto(self).await
+    }
+}
+pub trait StreamingRpcInterfaceConcurrentServer: StreamingRpcInterface {
+    async fn run_concurrently(
+        &mut self,
+        subscriber: &mut impl async_pub_sub::SubscriberWrapper<StreamingRpcInterfaceMessage>,
+        max_in_flight: usize,
+    ) {
+        self.__run_concurrently(subscriber, max_in_flight, None)
+            .await
+    }
+    async fn run_concurrently_until_shutdown(
+        &mut self,
+        shutdown: async_pub_sub::ShutdownSignal,
+        subscriber: &mut impl async_pub_sub::SubscriberWrapper<StreamingRpcInterfaceMessage>,
+        max_in_flight: usize,
+    ) {
+        self.__run_concurrently(subscriber, max_in_flight, Some(&shutdown))
+            .await
+    }
+    #[doc(hidden)]
+    async fn __run_concurrently(
+        &mut self,
+        subscriber: &mut impl async_pub_sub::SubscriberWrapper<StreamingRpcInterfaceMessage>,
+        max_in_flight: usize,
+        shutdown: Option<&async_pub_sub::ShutdownSignal>,
+    ) {
+        if !(max_in_flight > 0) {
+            {
+                ::core::panicking::panic_fmt(format_args!(
+                    "max_in_flight should be greater than 0"
+                ));
+            }
+        };
+        let subscriber_name =
+            async_pub_sub::SubscriberWrapper::<StreamingRpcInterfaceMessage>::get_name(subscriber);
+        loop {
+            let mut exclusive_request = None;
+            let mut closed = false;
+            {
+                let server = &*self;
+                let mut in_flight = async_pub_sub::futures::stream::FuturesUnordered::new();
+                loop {
+                    if in_flight.len() >= max_in_flight {
+                        async_pub_sub::futures::StreamExt::next(&mut in_flight).await;
+                        continue;
+                    }
+                    let received = if in_flight.is_empty() {
+                        async {
+                            match shutdown {
+                                Some(shutdown) => {
+                                    shutdown.until_shutdown(subscriber.recv()).await.flatten()
+                                }
+                                None => subscriber.recv().await,
+                            }
+                        }
+                        .await
+                    } else {
+                        let next_request = {
+                            super let mut pinned =
+                                        async {
+                                            match shutdown {
+                                                Some(shutdown) =>
+                                                    shutdown.until_shutdown(subscriber.recv()).await.flatten(),
+                                                None => subscriber.recv().await,
+                                            }
+                                        };
+                            unsafe { ::core::pin::Pin::new_unchecked(&mut pinned) }
+                        };
+                        let next_response = async_pub_sub::futures::StreamExt::next(&mut in_flight);
+                        match async_pub_sub::futures::future::select(next_request, next_response)
+                            .await
+                        {
+                            async_pub_sub::futures::future::Either::Left((received, _)) => received,
+                            async_pub_sub::futures::future::Either::Right(_) => continue,
+                        }
+                    };
+                    let Some(request) = received else {
+                        closed = true;
+                        break;
+                    };
+                    if #[allow(non_exhaustive_omitted_patterns)]
+                    match request {
+                        StreamingRpcInterfaceMessage::GetToto(_)
+                        | StreamingRpcInterfaceMessage::WatchToto(_) => true,
+                        _ => false,
+                    } {
+                        in_flight.push(server.__handle_shared_request(request, subscriber_name));
+                    } else {
+                        exclusive_request = Some(request);
+                        break;
+                    }
+                }
+                while async_pub_sub::futures::StreamExt::next(&mut in_flight)
+                    .await
+                    .is_some()
+                {}
+            }
+            if closed {
+                break;
+            }
+            if let Some(request) = exclusive_request {
+                self.__handle_exclusive_request(request, subscriber_name)
+                    .await;
+            }
+        }
+    }
+    #[doc(hidden)]
+    async fn __handle_shared_request(
+        &self,
+        request: StreamingRpcInterfaceMessage,
+        subscriber_name: &'static str,
+    ) {
+        let trace_context = async_pub_sub::Traceable::trace_context(&request).clone();
+        trace_context
+            .instrument(subscriber_name, async move {
+                match request {
+                    StreamingRpcInterfaceMessage::GetToto(req) => {
+                        let async_pub_sub::Request {
+                            content: _,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as StreamingRpcInterface>::get_toto(self).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    StreamingRpcInterfaceMessage::WatchToto(req) => {
+                        let async_pub_sub::StreamRequest {
+                            content: _,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as StreamingRpcInterface>::watch_toto(self).await;
+                            response_sender.forward(response).await;
+                        }
+                    }
+                }
+            })
+            .await
+    }
+    #[doc(hidden)]
+    async fn __handle_exclusive_request(
+        &mut self,
+        request: StreamingRpcInterfaceMessage,
+        subscriber_name: &'static str,
+    ) {
+        let trace_context = async_pub_sub::Traceable::trace_context(&request).clone();
+        trace_context
+            .instrument(subscriber_name, async move {
+                match request {
+                    StreamingRpcInterfaceMessage::GetToto(req) => {
+                        let async_pub_sub::Request {
+                            content: _,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as StreamingRpcInterface>::get_toto(self).await;
+                            let _ = response_sender.send(response);
+                        }
+                    }
+                    StreamingRpcInterfaceMessage::WatchToto(req) => {
+                        let async_pub_sub::StreamRequest {
+                            content: _,
+                            response_sender,
+                            ..
+                        } = req;
+                        if !response_sender.is_cancelled() {
+                            let response = <Self as StreamingRpcInterface>::watch_toto(self).await;
+                            response_sender.forward(response).await;
+                        }
+                    }
+                }
+            })
+            .await
+    }
+}
+impl<T> StreamingRpcInterfaceConcurrentServer for T where T: StreamingRpcInterface {}
 fn main() {}
@@ -1,4 +1,5 @@
 #![allow(unused_imports)]
+use async_pub_sub::futures::Stream;
 use async_pub_sub_macros::rpc_interface;
 
 #[rpc_interface]
@@ -8,6 +9,14 @@ pub trait RpcInterface {
     async fn prefix_with_bar(&self, string: String) -> String;
     async fn get_toto(&self) -> String;
     async fn set_tata(&mut self, tata: String);
+    #[notify]
+    async fn notify_titi(&mut self, titi: String);
+}
+
+#[rpc_interface]
+pub trait StreamingRpcInterface {
+    async fn get_toto(&self) -> String;
+    async fn watch_toto(&self) -> impl Stream<Item = String>;
 }
 
 fn main() {}
@@ -18,6 +18,9 @@ impl<A: Subscriber> async_pub_sub::Subscriber for TestSubscriberA<A> {
     fn receive(&mut self) -> impl std::future::Future<Output = Self::Message> {
         async_pub_sub::Subscriber::receive(&mut self.subscriber_a)
     }
+    fn recv(&mut self) -> impl std::future::Future<Output = Option<Self::Message>> {
+        async_pub_sub::Subscriber::recv(&mut self.subscriber_a)
+    }
 }
 struct TestSubscriberB<B>
 where
@@ -42,5 +45,8 @@ where
     fn receive(&mut self) -> impl std::future::Future<Output = Self::Message> {
         async_pub_sub::Subscriber::receive(&mut self.subscriber_b)
     }
+    fn recv(&mut self) -> impl std::future::Future<Output = Option<Self::Message>> {
+        async_pub_sub::Subscriber::recv(&mut self.subscriber_b)
+    }
 }
 fn main() {}
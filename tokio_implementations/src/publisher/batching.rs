@@ -0,0 +1,346 @@
+use std::{
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, Once},
+    time::Duration,
+};
+
+use futures::{future::BoxFuture, stream, FutureExt, Stream, StreamExt};
+use tokio::{sync::Notify, time::Instant};
+
+use async_pub_sub::{Error, PublishOutcome, Publisher, PublisherLayer, Result};
+
+use super::forwarding::ForwardedPublisher;
+
+/// A publisher middleware layer coalescing messages into batches.
+///
+/// The layer turns a `Publisher<Message = Vec<M>>` into a `Publisher<Message = M>`: the
+/// published messages are buffered and handed to the underlying publisher as a single
+/// `Vec` once the batch reaches `max_size` messages, or `max_delay` after its first message,
+/// whichever comes first. Whatever is left is flushed when the publisher is closed or dropped.
+///
+/// Subscribers of the batching publisher receive the messages one by one, while paying
+/// a single channel operation per batch. Subscribers that want the batches themselves
+/// subscribe to the underlying publisher before it is layered.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use async_pub_sub::{LoggingPublisherLayer, Publisher, PublisherBuilder, PublisherImpl, SubscriberImpl};
+/// use tokio_implementations::publisher::batching::BatchingPublisherLayer;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///     let mut publisher = PublisherBuilder::new(PublisherImpl::<Vec<i32>>::new("publisher", 10))
+///         .with_layer(BatchingPublisherLayer::new(2, Duration::from_millis(10)))
+///         .with_layer(LoggingPublisherLayer)
+///         .build();
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     for value in 0..3 {
+///         publisher.publish(value).await.unwrap();
+///     }
+///
+///     for value in 0..3 {
+///         assert_eq!(subscriber.receive().await, value);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BatchingPublisherLayer {
+    max_size: usize,
+    max_delay: Duration,
+}
+
+impl BatchingPublisherLayer {
+    /// Creates a batching layer.
+    ///
+    /// # Arguments
+    /// * `max_size` - The number of messages triggering a flush
+    /// * `max_delay` - How long the first message of a batch waits at most before a flush
+    ///
+    /// # Panics
+    /// Panics if `max_size` is zero.
+    pub fn new(max_size: usize, max_delay: Duration) -> Self {
+        assert!(max_size > 0, "the batch size must be greater than zero");
+        Self {
+            max_size,
+            max_delay,
+        }
+    }
+}
+
+impl<P, M> PublisherLayer<P> for BatchingPublisherLayer
+where
+    P: Publisher<Message = Vec<M>> + Send + Sync + 'static,
+    M: Send + Sync + 'static,
+{
+    type PublisherType = BatchPublisher<P, M>;
+
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        BatchPublisher {
+            max_size: self.max_size,
+            max_delay: self.max_delay,
+            shared: Arc::new(Shared {
+                batch: Mutex::new(Batch {
+                    messages: Vec::with_capacity(self.max_size),
+                    started: Instant::now(),
+                    closed: false,
+                    detached: false,
+                }),
+                notify: Notify::new(),
+                flush: tokio::sync::Mutex::new(()),
+            }),
+            publisher: Arc::new(ForwardedPublisher::new(publisher)),
+            started: Once::new(),
+        }
+    }
+}
+
+/// A publisher wrapper buffering messages and publishing them in batches, built by
+/// [`BatchingPublisherLayer`].
+///
+/// Deadlines are handled by a background task, started on the first publication, so
+/// publishing requires a tokio runtime. A batch that the background task fails to publish
+/// is reported by the next publication. Subscribers binding while a batch is being
+/// published are bound once it is.
+pub struct BatchPublisher<P, M> {
+    max_size: usize,
+    max_delay: Duration,
+    shared: Arc<Shared<M>>,
+    publisher: Arc<ForwardedPublisher<P>>,
+    started: Once,
+}
+
+struct Shared<M> {
+    batch: Mutex<Batch<M>>,
+    notify: Notify,
+    // Held while a batch is taken and published, so that batches are published in order.
+    flush: tokio::sync::Mutex<()>,
+}
+
+struct Batch<M> {
+    messages: Vec<M>,
+    started: Instant,
+    closed: bool,
+    // Set when the publisher is dropped, the background task then flushes and stops.
+    detached: bool,
+}
+
+impl<M> Shared<M> {
+    fn lock(&self) -> MutexGuard<'_, Batch<M>> {
+        self.batch
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Publishes the pending batch, if any.
+async fn flush<P, M>(
+    publisher: &ForwardedPublisher<P>,
+    shared: &Shared<M>,
+    max_size: usize,
+) -> Result<()>
+where
+    P: Publisher<Message = Vec<M>> + Send + Sync + 'static,
+{
+    let _guard = shared.flush.lock().await;
+    let messages = mem::replace(&mut shared.lock().messages, Vec::with_capacity(max_size));
+    if messages.is_empty() {
+        return Ok(());
+    }
+
+    publisher.publish(messages).await
+}
+
+/// Flushes the batches reaching their deadline, until the publisher is closed or dropped.
+async fn run<P, M>(
+    publisher: Arc<ForwardedPublisher<P>>,
+    shared: Arc<Shared<M>>,
+    max_size: usize,
+    max_delay: Duration,
+) where
+    P: Publisher<Message = Vec<M>> + Send + Sync + 'static,
+{
+    loop {
+        let notified = shared.notify.notified();
+        let (deadline, stopping) = {
+            let batch = shared.lock();
+            let stopping = batch.closed || batch.detached;
+            if batch.messages.is_empty() && stopping {
+                break;
+            }
+
+            let deadline = if batch.messages.is_empty() {
+                None
+            } else if stopping || batch.messages.len() >= max_size {
+                Some(Instant::now())
+            } else {
+                Some(batch.started + max_delay)
+            };
+            (deadline, stopping)
+        };
+
+        match deadline {
+            Some(deadline) if deadline <= Instant::now() => {
+                if let Err(error) = flush(&publisher, &shared, max_size).await {
+                    log::warn!("[{}]: failed to flush batch: {}", publisher.name(), error);
+                    publisher.report(error);
+                    if stopping {
+                        break;
+                    }
+                }
+            }
+            Some(deadline) => {
+                tokio::select! {
+                    _ = tokio::time::sleep_until(deadline) => {}
+                    _ = notified => {}
+                }
+            }
+            None => notified.await,
+        }
+    }
+
+    if shared.lock().closed {
+        publisher.close().await;
+    }
+}
+
+impl<P, M> BatchPublisher<P, M>
+where
+    P: Publisher<Message = Vec<M>> + Send + Sync + 'static,
+    M: Send + Sync + 'static,
+{
+    /// Adds the message to the pending batch.
+    ///
+    /// # Returns
+    /// True if the batch reached its maximum size and must be flushed
+    ///
+    /// # Errors
+    /// * [`Error::Closed`] if the publisher is closed
+    /// * The error of the last failed background flush, if it was not reported yet; the
+    ///   message is then not added
+    ///
+    /// # Panics
+    /// Panics if the background task is not started yet and no tokio runtime is running.
+    fn push(&self, message: M) -> Result<bool> {
+        let full = {
+            let mut batch = self.shared.lock();
+            if batch.closed {
+                return Err(Error::closed::<M>(self.publisher.name()));
+            }
+            if let Some(error) = self.publisher.take_failure() {
+                return Err(error);
+            }
+
+            if batch.messages.is_empty() {
+                batch.started = Instant::now();
+            }
+            batch.messages.push(message);
+            batch.messages.len() >= self.max_size
+        };
+
+        self.started.call_once(|| {
+            tokio::spawn(run(
+                self.publisher.clone(),
+                self.shared.clone(),
+                self.max_size,
+                self.max_delay,
+            ));
+        });
+        self.shared.notify.notify_one();
+
+        Ok(full)
+    }
+}
+
+impl<P, M> Publisher for BatchPublisher<P, M>
+where
+    P: Publisher<Message = Vec<M>> + Send + Sync + 'static,
+    M: Send + Sync + 'static,
+{
+    type Message = M;
+
+    fn get_name(&self) -> &'static str {
+        self.publisher.name()
+    }
+
+    /// Adds the message to the pending batch, publishing the batch when it is full.
+    ///
+    /// # Errors
+    /// * [`Error::Closed`] if the publisher is closed
+    /// * The error of the last failed background flush, if it was not reported yet
+    /// * Any error reported by the underlying publisher when the batch is published
+    ///
+    /// # Panics
+    /// Panics if called for the first time outside a tokio runtime.
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        match self.push(message) {
+            Ok(true) => flush(&self.publisher, &self.shared, self.max_size).boxed(),
+            result => async move { result.map(|_| ()) }.boxed(),
+        }
+    }
+
+    /// Adds the message to the pending batch; a full batch is published by the background task.
+    ///
+    /// # Errors
+    /// * [`Error::Closed`] if the publisher is closed
+    /// * The error of the last failed background flush, if it was not reported yet
+    ///
+    /// # Panics
+    /// Panics if called for the first time outside a tokio runtime.
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        self.push(message).map(|_| PublishOutcome::Delivered)
+    }
+
+    /// Flushes the pending batch, then closes the underlying publisher.
+    fn close(&self) {
+        self.shared.lock().closed = true;
+
+        if self.started.is_completed() {
+            self.shared.notify.notify_one();
+        } else {
+            self.publisher.close_now();
+        }
+    }
+
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed().boxed()
+    }
+
+    /// Gets a stream of the messages of every batch, one by one.
+    ///
+    /// If a batch is being published, the subscriber is bound once it is; a failure to bind
+    /// is then reported by the next publication and the stream ends.
+    ///
+    /// # Errors
+    /// Any error reported by the underlying publisher when it binds right away
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        let stream = self.publisher.get_message_stream(subscriber_name)?;
+
+        Ok(Box::pin(stream.flat_map(stream::iter)))
+    }
+}
+
+impl<P, M> Drop for BatchPublisher<P, M> {
+    fn drop(&mut self) {
+        // The background task flushes what is left before stopping.
+        self.shared.lock().detached = true;
+        self.shared.notify.notify_one();
+    }
+}
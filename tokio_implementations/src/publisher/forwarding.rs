@@ -0,0 +1,169 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::Poll,
+};
+
+use futures::{stream, FutureExt, Stream, StreamExt};
+use tokio::sync::{oneshot, Notify, RwLock, RwLockReadGuard};
+
+use async_pub_sub::{Error, Publisher, Result};
+
+/// The underlying publisher of a wrapper whose messages are forwarded by a background task.
+///
+/// The background task locks the publisher for reading while it forwards messages, and
+/// records the failures of its forwards so that the wrapper reports them from its next
+/// publication instead of losing them silently. Binding a subscriber locks the publisher
+/// for writing: it binds right away when no forward is in flight, and once the forward in
+/// flight completes otherwise.
+pub(crate) struct ForwardedPublisher<P> {
+    name: &'static str,
+    publisher: RwLock<P>,
+    // Signaled when a subscriber waits to bind, so that `closed` gives the lock up.
+    binding: Notify,
+    // The first failure not reported yet.
+    failure: Mutex<Option<Error>>,
+}
+
+impl<P> ForwardedPublisher<P>
+where
+    P: Publisher + Send + Sync + 'static,
+{
+    pub(crate) fn new(publisher: P) -> Self {
+        Self {
+            name: publisher.get_name(),
+            publisher: RwLock::new(publisher),
+            binding: Notify::new(),
+            failure: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the publisher, unless a subscriber is binding to it.
+    fn try_read(&self) -> Option<RwLockReadGuard<'_, P>> {
+        self.publisher.try_read().ok()
+    }
+
+    /// Publishes the message through the publisher.
+    ///
+    /// The publication only waits for room while a subscriber is bound to drain the
+    /// publisher: otherwise it is attempted without waiting, so that a subscriber binding
+    /// in the meantime never waits on a publication that only it could complete.
+    pub(crate) async fn publish(&self, message: P::Message) -> Result<()> {
+        let publisher = self.publisher.read().await;
+        if publisher.is_bound() {
+            publisher.publish(message).await
+        } else {
+            publisher.try_publish(message).map(|_| ())
+        }
+    }
+
+    /// Records a failure, to be reported by the next publication of the wrapper.
+    pub(crate) fn report(&self, error: Error) {
+        self.failure
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get_or_insert(error);
+    }
+
+    /// Returns the first failure recorded since the last call, if any.
+    pub(crate) fn take_failure(&self) -> Option<Error> {
+        self.failure
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take()
+    }
+
+    /// Closes the publisher once the forward in flight, if any, completes.
+    pub(crate) async fn close(&self) {
+        self.publisher.read().await.close()
+    }
+
+    /// Closes the publisher right away.
+    ///
+    /// # Panics
+    /// Panics if a subscriber is binding at the same time, which only happens once the
+    /// background task forwards messages.
+    pub(crate) fn close_now(&self) {
+        self.try_read()
+            .expect("subscribers only wait to bind while the background task forwards")
+            .close()
+    }
+
+    /// Returns true if a subscriber is bound, false while a subscriber is binding.
+    pub(crate) fn is_bound(&self) -> bool {
+        self.try_read()
+            .is_some_and(|publisher| publisher.is_bound())
+    }
+
+    /// Returns true once the publisher is closed, false while a subscriber is binding.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.try_read()
+            .is_some_and(|publisher| publisher.is_closed())
+    }
+
+    /// Waits until the publisher is closed, stepping aside for the subscribers binding
+    /// in the meantime.
+    pub(crate) async fn closed(&self) {
+        loop {
+            let binding = self.binding.notified();
+            let publisher = self.publisher.read().await;
+            tokio::select! {
+                _ = publisher.closed() => return,
+                _ = binding => {}
+            }
+        }
+    }
+
+    /// Gets a message stream from the publisher.
+    ///
+    /// While the background task forwards messages, the subscriber is bound by a task of its
+    /// own once the forward in flight completes; a failure to bind is then recorded, see
+    /// [`ForwardedPublisher::take_failure`], and the returned stream ends right away.
+    ///
+    /// # Errors
+    /// Any error reported by the publisher when it binds right away
+    pub(crate) fn get_message_stream(
+        self: &Arc<Self>,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = P::Message> + Send + Sync + 'static>>> {
+        if let Ok(mut publisher) = self.publisher.try_write() {
+            return publisher.get_message_stream(subscriber_name);
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        let this = self.clone();
+        tokio::spawn(async move {
+            // Queue for the lock before asking `closed` to give it up, so that it cannot
+            // take the lock back first.
+            let write = this.publisher.write();
+            futures::pin_mut!(write);
+            let mut publisher = match futures::poll!(write.as_mut()) {
+                Poll::Ready(publisher) => publisher,
+                Poll::Pending => {
+                    this.binding.notify_waiters();
+                    write.await
+                }
+            };
+            let result = publisher.get_message_stream(subscriber_name);
+            drop(publisher);
+
+            if let Err(error) = &result {
+                log::warn!(
+                    "[{}]: failed to bind {}: {}",
+                    this.name,
+                    subscriber_name,
+                    error
+                );
+                this.report(error.clone());
+            }
+            let _ = sender.send(result.ok());
+        });
+
+        let stream = async move { stream::iter(receiver.await.ok().flatten()).flatten() };
+        Ok(Box::pin(stream.flatten_stream()))
+    }
+}
@@ -1 +1,7 @@
+pub mod batching;
+mod forwarding;
 pub mod mpsc;
+pub mod rate_limit;
+pub mod retry;
+pub mod throttle;
+pub mod timeout;
@@ -1,12 +1,26 @@
-use std::pin::Pin;
-
-use futures::{
-    future::BoxFuture,
-    stream::{self},
-    FutureExt, Stream,
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    task::{Context, Poll},
 };
 
-use async_pub_sub::{Publisher, Result};
+use futures::{future::BoxFuture, FutureExt, Stream};
+use tokio::sync::mpsc::error::TrySendError;
+
+use async_pub_sub::{Error, OverflowPolicy, PublishOutcome, Publisher, Result};
+
+type SharedReceiver<Message> = Arc<Mutex<tokio::sync::mpsc::Receiver<Message>>>;
+
+fn lock<Message>(
+    receiver: &SharedReceiver<Message>,
+) -> MutexGuard<'_, tokio::sync::mpsc::Receiver<Message>> {
+    receiver
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
 
 pub struct MpscPublisher<Message>
 where
@@ -14,8 +28,12 @@ where
 {
     name: &'static str,
     subscriber_name: Option<&'static str>,
+    overflow_policy: OverflowPolicy,
     sender: tokio::sync::mpsc::Sender<Message>,
-    receiver: Option<tokio::sync::mpsc::Receiver<Message>>,
+    // The publisher keeps access to the receiver to evict the oldest message.
+    receiver: SharedReceiver<Message>,
+    dropped_count: AtomicU64,
+    closed: AtomicBool,
 }
 
 impl<Message> MpscPublisher<Message>
@@ -27,36 +45,122 @@ where
         Self {
             name,
             subscriber_name: None,
+            overflow_policy: OverflowPolicy::default(),
             sender,
-            receiver: Some(receiver),
+            receiver: Arc::new(Mutex::new(receiver)),
+            dropped_count: AtomicU64::new(0),
+            closed: AtomicBool::new(false),
         }
     }
 
+    /// Sets the policy applied when the channel is full.
+    pub fn with_overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+        self.overflow_policy = overflow_policy;
+        self
+    }
+
+    /// Returns how many messages were dropped because of the overflow policy.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+
     pub async fn publish(&self, message: Message) -> Result<()> {
-        self.sender.send(message).await?;
-        Ok(())
+        self.publish_with_outcome(message).await.map(|_| ())
+    }
+
+    /// Publishes the message like [`MpscPublisher::publish`], reporting whether a message
+    /// was dropped because of the overflow policy.
+    pub async fn publish_with_outcome(&self, message: Message) -> Result<PublishOutcome> {
+        if self.overflow_policy != OverflowPolicy::Block {
+            return self.try_publish(message);
+        }
+
+        self.sender
+            .send(message)
+            .await
+            .map(|_| PublishOutcome::Delivered)
+            .map_err(|_| self.closed_error())
+    }
+
+    pub fn try_publish(&self, message: Message) -> Result<PublishOutcome> {
+        let mut outcome = PublishOutcome::Delivered;
+        let mut message = message;
+
+        loop {
+            match self.sender.try_send(message) {
+                Ok(()) => break,
+                Err(TrySendError::Closed(_)) => return Err(self.closed_error()),
+                Err(TrySendError::Full(rejected)) => match self.overflow_policy {
+                    OverflowPolicy::Block | OverflowPolicy::Error => {
+                        return Err(Error::channel_full::<Message>(
+                            self.name,
+                            self.subscriber_name,
+                        ))
+                    }
+                    OverflowPolicy::DropNewest => {
+                        outcome = PublishOutcome::DroppedNewest;
+                        break;
+                    }
+                    OverflowPolicy::DropOldest => {
+                        // Another producer may fill the freed slot first, hence the loop.
+                        let _ = lock(&self.receiver).try_recv();
+                        outcome = PublishOutcome::DroppedOldest;
+                        message = rejected;
+                    }
+                },
+            }
+        }
+
+        if outcome.is_dropped() {
+            self.dropped_count.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Ok(outcome)
+    }
+
+    /// Closes the publisher; the subscriber still receives the messages already queued.
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        lock(&self.receiver).close();
+    }
+
+    /// Returns true if a subscriber is currently bound to the publisher.
+    pub fn is_bound(&self) -> bool {
+        self.subscriber_name.is_some() && !self.sender.is_closed()
+    }
+
+    /// Returns true once the subscriber has gone away or the publisher has been closed.
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+
+    /// Waits until the subscriber goes away or the publisher is closed.
+    pub async fn closed(&self) {
+        self.sender.closed().await
+    }
+
+    /// The tokio channel reports both a closed publisher and a dropped subscriber as closed.
+    fn closed_error(&self) -> Error {
+        if self.closed.load(Ordering::Acquire) {
+            Error::closed::<Message>(self.name)
+        } else {
+            Error::disconnected::<Message>(self.name, self.subscriber_name)
+        }
     }
 
     pub fn get_message_stream(
         &mut self,
         subscriber_name: &'static str,
     ) -> Result<Pin<Box<dyn Stream<Item = Message> + Send + Sync + 'static>>> {
-        let Some(receiver) = self.receiver.take() else {
-            return Err(format!(
-                "{} publisher can only be bound to one subscriber (already bound to {})",
-                self.name,
-                self.subscriber_name
-                    .expect("the subscriber name should be known at this point")
-            )
-            .into());
-        };
+        if let Some(bound_subscriber) = self.subscriber_name {
+            return Err(Error::already_bound::<Message>(self.name, bound_subscriber));
+        }
 
         self.subscriber_name = Some(subscriber_name);
 
-        Ok(Box::pin(stream::unfold(
-            receiver,
-            |mut receiver| async move { receiver.recv().await.map(|message| (message, receiver)) },
-        )))
+        Ok(Box::pin(MpscStream {
+            receiver: self.receiver.clone(),
+        }))
     }
 
     pub fn get_name(&self) -> &'static str {
@@ -64,6 +168,25 @@ where
     }
 }
 
+/// The subscriber side of a [`MpscPublisher`], closing the channel when dropped.
+struct MpscStream<Message> {
+    receiver: SharedReceiver<Message>,
+}
+
+impl<Message> Stream for MpscStream<Message> {
+    type Item = Message;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        lock(&self.receiver).poll_recv(cx)
+    }
+}
+
+impl<Message> Drop for MpscStream<Message> {
+    fn drop(&mut self) {
+        lock(&self.receiver).close();
+    }
+}
+
 impl<Message> Publisher for MpscPublisher<Message>
 where
     Message: Send,
@@ -74,10 +197,34 @@ where
         self.name
     }
 
-    fn publish(&self, message: Message) -> BoxFuture<Result<()>> {
+    fn publish(&self, message: Message) -> BoxFuture<'_, Result<()>> {
         MpscPublisher::publish(self, message).boxed()
     }
 
+    fn try_publish(&self, message: Message) -> Result<PublishOutcome> {
+        MpscPublisher::try_publish(self, message)
+    }
+
+    fn publish_with_outcome(&self, message: Message) -> BoxFuture<'_, Result<PublishOutcome>> {
+        MpscPublisher::publish_with_outcome(self, message).boxed()
+    }
+
+    fn close(&self) {
+        MpscPublisher::close(self)
+    }
+
+    fn is_bound(&self) -> bool {
+        MpscPublisher::is_bound(self)
+    }
+
+    fn is_closed(&self) -> bool {
+        MpscPublisher::is_closed(self)
+    }
+
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        MpscPublisher::closed(self).boxed()
+    }
+
     fn get_message_stream(
         &mut self,
         subscriber_name: &'static str,
@@ -0,0 +1,304 @@
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard,
+    },
+    time::Duration,
+};
+
+use futures::{future::BoxFuture, FutureExt, Stream};
+use tokio::time::Instant;
+
+use async_pub_sub::{Error, PublishOutcome, Publisher, PublisherLayer, Result};
+
+/// What a rate limited publisher does with a message published while no token is left.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RateLimitPolicy {
+    /// Wait until a token is available before publishing the message.
+    #[default]
+    Delay,
+    /// Reject the message with [`Error::ChannelFull`].
+    Reject,
+}
+
+/// A publisher middleware layer limiting the rate at which messages are published.
+///
+/// The limit follows the token bucket algorithm: each publisher built from the layer owns
+/// a bucket of `burst` tokens, refilled with `rate` tokens every `period`. Publishing a
+/// message takes one token; when the bucket is empty, the [`RateLimitPolicy`] decides
+/// whether `publish` waits for the next token or rejects the message.
+///
+/// [`Publisher::try_publish`] never waits: it rejects the message when no token is left,
+/// whatever the policy.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use async_pub_sub::{Publisher, PublisherBuilder, PublisherImpl};
+/// use tokio_implementations::publisher::rate_limit::{RateLimitPolicy, RateLimitPublisherLayer};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let publisher = PublisherBuilder::new(PublisherImpl::<i32>::new("publisher", 10))
+///         .with_layer(
+///             RateLimitPublisherLayer::new(1, Duration::from_secs(1))
+///                 .with_policy(RateLimitPolicy::Reject),
+///         )
+///         .build();
+///
+///     assert!(publisher.publish(1).await.is_ok());
+///     assert!(publisher.publish(2).await.is_err());
+///     assert_eq!(publisher.dropped_count(), 1);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitPublisherLayer {
+    rate: u32,
+    period: Duration,
+    burst: u32,
+    policy: RateLimitPolicy,
+}
+
+impl RateLimitPublisherLayer {
+    /// Creates a layer allowing `rate` messages every `period`.
+    ///
+    /// The burst defaults to `rate` and the policy to [`RateLimitPolicy::Delay`].
+    ///
+    /// # Arguments
+    /// * `rate` - The number of tokens added to the bucket every period
+    /// * `period` - The refill period
+    ///
+    /// # Panics
+    /// Panics if `rate` is zero or `period` is zero.
+    pub fn new(rate: u32, period: Duration) -> Self {
+        assert!(rate > 0, "the rate must be greater than zero");
+        assert!(!period.is_zero(), "the period must be greater than zero");
+
+        Self {
+            rate,
+            period,
+            burst: rate,
+            policy: RateLimitPolicy::default(),
+        }
+    }
+
+    /// Sets the capacity of the bucket, i.e. how many messages can be published at once
+    /// after an idle period.
+    ///
+    /// # Panics
+    /// Panics if `burst` is zero.
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        assert!(burst > 0, "the burst must be greater than zero");
+        self.burst = burst;
+        self
+    }
+
+    /// Sets what happens to a message published while the bucket is empty.
+    pub fn with_policy(mut self, policy: RateLimitPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+impl<P> PublisherLayer<P> for RateLimitPublisherLayer
+where
+    P: Publisher + Send + Sync,
+{
+    type PublisherType = RateLimitPublisher<P>;
+
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        RateLimitPublisher {
+            bucket: Mutex::new(TokenBucket {
+                capacity: self.burst as f64,
+                tokens_per_second: self.rate as f64 / self.period.as_secs_f64(),
+                tokens: self.burst as f64,
+                last_refill: Instant::now(),
+            }),
+            policy: self.policy,
+            subscriber_name: None,
+            dropped_count: AtomicU64::new(0),
+            publisher,
+        }
+    }
+}
+
+/// A publisher wrapper limiting the rate of the messages handed to the underlying publisher.
+pub struct RateLimitPublisher<P> {
+    bucket: Mutex<TokenBucket>,
+    policy: RateLimitPolicy,
+    subscriber_name: Option<&'static str>,
+    dropped_count: AtomicU64,
+    publisher: P,
+}
+
+struct TokenBucket {
+    capacity: f64,
+    tokens_per_second: f64,
+    // Goes below zero when delayed messages reserved tokens that are not refilled yet.
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.tokens_per_second).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    /// Takes a token if one is available.
+    fn try_acquire(&mut self) -> bool {
+        self.refill();
+        if self.tokens < 1.0 {
+            return false;
+        }
+
+        self.tokens -= 1.0;
+        true
+    }
+
+    /// Takes a token, returning how long to wait until it is actually available.
+    fn reserve(&mut self) -> Option<Duration> {
+        self.refill();
+        self.tokens -= 1.0;
+        (self.tokens < 0.0).then(|| Duration::from_secs_f64(-self.tokens / self.tokens_per_second))
+    }
+
+    /// Gives back a reserved token that was not used.
+    fn release(&mut self) {
+        self.refill();
+        self.tokens = (self.tokens + 1.0).min(self.capacity);
+    }
+}
+
+fn lock(bucket: &Mutex<TokenBucket>) -> MutexGuard<'_, TokenBucket> {
+    bucket
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// A token reserved by a delayed publication, given back to the bucket if the publication
+/// is dropped before its message is handed to the underlying publisher.
+struct Reservation<'a> {
+    bucket: &'a Mutex<TokenBucket>,
+    delay: Option<Duration>,
+    used: bool,
+}
+
+impl<'a> Reservation<'a> {
+    fn new(bucket: &'a Mutex<TokenBucket>) -> Self {
+        let delay = lock(bucket).reserve();
+        Self {
+            bucket,
+            delay,
+            used: false,
+        }
+    }
+
+    /// Waits until the reserved token is available, then keeps it.
+    async fn wait(mut self) {
+        if let Some(delay) = self.delay {
+            tokio::time::sleep(delay).await;
+        }
+        self.used = true;
+    }
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        if !self.used {
+            lock(self.bucket).release();
+        }
+    }
+}
+
+impl<P> RateLimitPublisher<P>
+where
+    P: Publisher,
+{
+    /// Returns how many messages were rejected because no token was left.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+
+    fn lock_bucket(&self) -> MutexGuard<'_, TokenBucket> {
+        lock(&self.bucket)
+    }
+
+    fn rejected(&self) -> Error {
+        self.dropped_count.fetch_add(1, Ordering::Relaxed);
+        Error::channel_full::<P::Message>(self.publisher.get_name(), self.subscriber_name)
+    }
+}
+
+impl<P> Publisher for RateLimitPublisher<P>
+where
+    P: Publisher + Send + Sync,
+{
+    type Message = P::Message;
+
+    fn get_name(&self) -> &'static str {
+        self.publisher.get_name()
+    }
+
+    /// Publishes the message once a token is available, or rejects it if the policy is
+    /// [`RateLimitPolicy::Reject`] and the bucket is empty.
+    ///
+    /// With [`RateLimitPolicy::Delay`], the token is reserved when the returned future is
+    /// first polled, and given back if the future is dropped while waiting for it.
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        match self.policy {
+            RateLimitPolicy::Reject => {
+                if !self.lock_bucket().try_acquire() {
+                    let error = self.rejected();
+                    return async move { Err(error) }.boxed();
+                }
+
+                self.publisher.publish(message)
+            }
+            RateLimitPolicy::Delay => async move {
+                Reservation::new(&self.bucket).wait().await;
+                self.publisher.publish(message).await
+            }
+            .boxed(),
+        }
+    }
+
+    /// Publishes the message if a token is available, rejects it otherwise.
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        if !self.lock_bucket().try_acquire() {
+            return Err(self.rejected());
+        }
+
+        self.publisher.try_publish(message)
+    }
+
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        let stream = self.publisher.get_message_stream(subscriber_name)?;
+        self.subscriber_name = Some(subscriber_name);
+        Ok(stream)
+    }
+}
@@ -0,0 +1,221 @@
+use std::{pin::Pin, sync::Arc, time::Duration};
+
+use futures::{future::BoxFuture, FutureExt, Stream};
+
+use async_pub_sub::{Error, PublishOutcome, Publisher, PublisherLayer, Result};
+
+/// Decides which publication failures are worth another attempt.
+///
+/// Closures taking an [`Error`] reference and returning a `bool` are policies.
+pub trait RetryPolicy: Send + Sync + 'static {
+    /// Returns true if a publication that failed with `error` should be attempted again.
+    fn should_retry(&self, error: &Error) -> bool;
+}
+
+impl<F> RetryPolicy for F
+where
+    F: Fn(&Error) -> bool + Send + Sync + 'static,
+{
+    fn should_retry(&self, error: &Error) -> bool {
+        self(error)
+    }
+}
+
+/// The default retry policy: retries the failures that may go away by themselves, i.e.
+/// full channels and timeouts, but not closed or disconnected publishers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TransientErrors;
+
+impl RetryPolicy for TransientErrors {
+    fn should_retry(&self, error: &Error) -> bool {
+        matches!(error, Error::ChannelFull { .. } | Error::Timeout { .. })
+    }
+}
+
+/// A publisher middleware layer publishing a message again when `publish` fails.
+///
+/// The delay between two attempts starts at the initial backoff and doubles after each
+/// attempt, up to the maximum backoff. The [`RetryPolicy`] decides which errors are
+/// retried, [`TransientErrors`] by default. Since a failed publication consumes the
+/// message, the messages must be `Clone`; the messages generated by `rpc_interface` are
+/// `Clone` whenever their request contents are.
+///
+/// `try_publish` never waits, so it is not retried.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use async_pub_sub::{Publisher, PublisherBuilder, PublisherImpl, SubscriberImpl};
+/// use tokio_implementations::publisher::{
+///     retry::RetryPublisherLayer, timeout::TimeoutPublisherLayer,
+/// };
+///
+/// #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// async fn main() {
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///     let mut publisher = PublisherBuilder::new(PublisherImpl::<i32>::new("publisher", 1))
+///         .with_layer(TimeoutPublisherLayer::new(Duration::from_millis(10)))
+///         .with_layer(RetryPublisherLayer::new(3))
+///         .build();
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     publisher.publish(1).await.unwrap();
+///     let consumer = tokio::spawn(async move {
+///         tokio::time::sleep(Duration::from_millis(15)).await;
+///         (subscriber.receive().await, subscriber.receive().await)
+///     });
+///     publisher.publish(2).await.unwrap();
+///
+///     assert_eq!(consumer.await.unwrap(), (1, 2));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPublisherLayer<R = TransientErrors> {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    policy: Arc<R>,
+}
+
+impl RetryPublisherLayer {
+    /// Creates a layer attempting each publication up to `max_retries` more times, with
+    /// the [`TransientErrors`] policy and a backoff going from 10ms to 1s.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+            policy: Arc::new(TransientErrors),
+        }
+    }
+}
+
+impl<R> RetryPublisherLayer<R> {
+    /// Sets the delay before the first retry, and the maximum delay between two attempts.
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the policy deciding which errors are retried.
+    pub fn with_policy<Policy>(self, policy: Policy) -> RetryPublisherLayer<Policy>
+    where
+        Policy: RetryPolicy,
+    {
+        RetryPublisherLayer {
+            max_retries: self.max_retries,
+            initial_backoff: self.initial_backoff,
+            max_backoff: self.max_backoff,
+            policy: Arc::new(policy),
+        }
+    }
+}
+
+impl<P, R> PublisherLayer<P> for RetryPublisherLayer<R>
+where
+    P: Publisher + Send + Sync,
+    P::Message: Clone,
+    R: RetryPolicy,
+{
+    type PublisherType = RetryPublisher<P, R>;
+
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        RetryPublisher {
+            max_retries: self.max_retries,
+            initial_backoff: self.initial_backoff,
+            max_backoff: self.max_backoff,
+            policy: self.policy.clone(),
+            publisher,
+        }
+    }
+}
+
+/// A publisher wrapper retrying the failed publications of the underlying publisher.
+pub struct RetryPublisher<P, R> {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    policy: Arc<R>,
+    publisher: P,
+}
+
+impl<P, R> Publisher for RetryPublisher<P, R>
+where
+    P: Publisher + Send + Sync,
+    P::Message: Clone,
+    R: RetryPolicy,
+{
+    type Message = P::Message;
+
+    fn get_name(&self) -> &'static str {
+        self.publisher.get_name()
+    }
+
+    /// Publishes the message, attempting again with an exponential backoff while the
+    /// policy accepts the error and retries are left.
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        async move {
+            let mut message = message;
+            let mut backoff = self.initial_backoff;
+            let mut retries = 0;
+
+            loop {
+                let spare = (retries < self.max_retries).then(|| message.clone());
+
+                let error = match self.publisher.publish(message).await {
+                    Ok(()) => return Ok(()),
+                    Err(error) => error,
+                };
+                let Some(spare) = spare.filter(|_| self.policy.should_retry(&error)) else {
+                    return Err(error);
+                };
+
+                retries += 1;
+                log::debug!(
+                    "[{}]: publish failed ({}), retry {}/{} in {:?}",
+                    self.publisher.get_name(),
+                    error,
+                    retries,
+                    self.max_retries,
+                    backoff
+                );
+                tokio::time::sleep(backoff).await;
+
+                backoff = (backoff * 2).min(self.max_backoff);
+                message = spare;
+            }
+        }
+        .boxed()
+    }
+
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        self.publisher.try_publish(message)
+    }
+
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        self.publisher.get_message_stream(subscriber_name)
+    }
+}
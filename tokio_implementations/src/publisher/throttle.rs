@@ -0,0 +1,409 @@
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard, Weak,
+    },
+    time::Duration,
+};
+
+use futures::{future::BoxFuture, FutureExt, Stream};
+use tokio::{sync::Notify, time::Instant};
+
+use async_pub_sub::{Error, PublishOutcome, Publisher, PublisherLayer, Result};
+
+use super::forwarding::ForwardedPublisher;
+
+/// A publisher middleware layer forwarding at most one message per time window.
+///
+/// The first message published after a quiet window is forwarded right away. The messages
+/// published during the following window replace each other, and only the latest one is
+/// forwarded when the window ends.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use async_pub_sub::{Publisher, PublisherBuilder, PublisherImpl, SubscriberImpl};
+/// use tokio_implementations::publisher::throttle::ThrottlePublisherLayer;
+///
+/// #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// async fn main() {
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///     let mut publisher = PublisherBuilder::new(PublisherImpl::<i32>::new("publisher", 10))
+///         .with_layer(ThrottlePublisherLayer::new(Duration::from_millis(10)))
+///         .build();
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     for value in 0..5 {
+///         publisher.publish(value).await.unwrap();
+///         tokio::time::sleep(Duration::from_millis(1)).await;
+///     }
+///
+///     assert_eq!(subscriber.receive().await, 0);
+///     assert_eq!(subscriber.receive().await, 4);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ThrottlePublisherLayer {
+    window: Duration,
+}
+
+impl ThrottlePublisherLayer {
+    /// Creates a layer forwarding at most one message per `window`.
+    pub fn new(window: Duration) -> Self {
+        Self { window }
+    }
+}
+
+impl<P> PublisherLayer<P> for ThrottlePublisherLayer
+where
+    P: Publisher + Send + Sync + 'static,
+{
+    type PublisherType = WindowPublisher<P>;
+
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        WindowPublisher::new(publisher, Mode::Throttle, self.window)
+    }
+}
+
+/// A publisher middleware layer forwarding a message once no other message was published
+/// for a whole time window.
+///
+/// Every published message replaces the pending one and restarts the window, so a burst
+/// of messages results in a single message, the latest one.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use async_pub_sub::{Publisher, PublisherBuilder, PublisherImpl, SubscriberImpl};
+/// use tokio_implementations::publisher::throttle::DebouncePublisherLayer;
+///
+/// #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// async fn main() {
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///     let mut publisher = PublisherBuilder::new(PublisherImpl::<i32>::new("publisher", 10))
+///         .with_layer(DebouncePublisherLayer::new(Duration::from_millis(10)))
+///         .build();
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     for value in 0..5 {
+///         publisher.publish(value).await.unwrap();
+///     }
+///
+///     assert_eq!(subscriber.receive().await, 4);
+///     assert_eq!(publisher.dropped_count(), 4);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DebouncePublisherLayer {
+    window: Duration,
+}
+
+impl DebouncePublisherLayer {
+    /// Creates a layer forwarding a message once no other message was published for `window`.
+    pub fn new(window: Duration) -> Self {
+        Self { window }
+    }
+}
+
+impl<P> PublisherLayer<P> for DebouncePublisherLayer
+where
+    P: Publisher + Send + Sync + 'static,
+{
+    type PublisherType = WindowPublisher<P>;
+
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        WindowPublisher::new(publisher, Mode::Debounce, self.window)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Mode {
+    Throttle,
+    Debounce,
+}
+
+/// A publisher wrapper keeping only the latest message published within a time window,
+/// built by [`ThrottlePublisherLayer`] and [`DebouncePublisherLayer`].
+///
+/// Messages are forwarded to the underlying publisher by a background task, started on
+/// the first publication, so publishing requires a tokio runtime. A forward that fails is
+/// reported by the next publication. Subscribers binding while a message is being forwarded
+/// are bound once the forward completes.
+///
+/// Closing the publisher forwards the pending message right away, then closes the
+/// underlying publisher. Dropping it discards the pending message.
+pub struct WindowPublisher<P>
+where
+    P: Publisher,
+{
+    mode: Mode,
+    window: Duration,
+    slot: Arc<Slot<P::Message>>,
+    // The forwarding task only holds a weak reference, so that it stops with the publisher.
+    publisher: Arc<ForwardedPublisher<P>>,
+    started: std::sync::Once,
+}
+
+struct Slot<Message> {
+    state: Mutex<SlotState<Message>>,
+    notify: Notify,
+    dropped_count: AtomicU64,
+}
+
+struct SlotState<Message> {
+    message: Option<Message>,
+    updated: Instant,
+    closed: bool,
+    // Set when the publisher is dropped, the pending message is then discarded.
+    detached: bool,
+}
+
+impl<Message> Slot<Message> {
+    fn lock(&self) -> MutexGuard<'_, SlotState<Message>> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<P> WindowPublisher<P>
+where
+    P: Publisher + Send + Sync + 'static,
+{
+    fn new(publisher: P, mode: Mode, window: Duration) -> Self {
+        Self {
+            mode,
+            window,
+            slot: Arc::new(Slot {
+                state: Mutex::new(SlotState {
+                    message: None,
+                    updated: Instant::now(),
+                    closed: false,
+                    detached: false,
+                }),
+                notify: Notify::new(),
+                dropped_count: AtomicU64::new(0),
+            }),
+            publisher: Arc::new(ForwardedPublisher::new(publisher)),
+            started: std::sync::Once::new(),
+        }
+    }
+
+    /// Returns how many messages were replaced by a more recent one before being forwarded.
+    pub fn dropped_count(&self) -> u64 {
+        self.slot.dropped_count.load(Ordering::Relaxed)
+    }
+
+    /// Stores the message as the pending one, replacing the previous pending message.
+    ///
+    /// # Errors
+    /// * [`Error::Closed`] if the publisher is closed
+    /// * The error of the last failed forward, if it was not reported yet; the message is
+    ///   then not stored
+    ///
+    /// # Panics
+    /// Panics if the forwarding task is not started yet and no tokio runtime is running.
+    fn store(&self, message: P::Message) -> Result<PublishOutcome> {
+        let outcome = {
+            let mut state = self.slot.lock();
+            if state.closed {
+                return Err(Error::closed::<P::Message>(self.publisher.name()));
+            }
+            if let Some(error) = self.publisher.take_failure() {
+                return Err(error);
+            }
+
+            state.updated = Instant::now();
+            match state.message.replace(message) {
+                Some(_) => PublishOutcome::DroppedOldest,
+                None => PublishOutcome::Delivered,
+            }
+        };
+
+        if outcome.is_dropped() {
+            self.slot.dropped_count.fetch_add(1, Ordering::Relaxed);
+        }
+
+        self.started.call_once(|| {
+            tokio::spawn(forward(
+                Arc::downgrade(&self.publisher),
+                self.slot.clone(),
+                self.mode,
+                self.window,
+            ));
+        });
+        self.slot.notify.notify_one();
+
+        Ok(outcome)
+    }
+}
+
+/// Forwards the pending messages to the publisher until it is closed or dropped.
+async fn forward<P>(
+    publisher: Weak<ForwardedPublisher<P>>,
+    slot: Arc<Slot<P::Message>>,
+    mode: Mode,
+    window: Duration,
+) where
+    P: Publisher + Send + Sync + 'static,
+{
+    let mut last_forward: Option<Instant> = None;
+
+    loop {
+        // Wait for a pending message, then for the end of its window unless the publisher
+        // gets closed in the meantime.
+        loop {
+            let notified = slot.notify.notified();
+            let deadline = {
+                let state = slot.lock();
+                if state.detached {
+                    return;
+                }
+                if state.closed {
+                    break;
+                }
+                if state.message.is_some() {
+                    match mode {
+                        // The first message after a quiet window is forwarded right away.
+                        Mode::Throttle => Some(
+                            last_forward
+                                .map_or(state.updated, |last_forward| last_forward + window),
+                        ),
+                        Mode::Debounce => Some(state.updated + window),
+                    }
+                } else {
+                    None
+                }
+            };
+
+            match deadline {
+                Some(deadline) if deadline <= Instant::now() => break,
+                Some(deadline) => {
+                    tokio::select! {
+                        _ = tokio::time::sleep_until(deadline) => {}
+                        _ = notified => {}
+                    }
+                }
+                None => notified.await,
+            }
+        }
+
+        let (message, closed) = {
+            let mut state = slot.lock();
+            (state.message.take(), state.closed)
+        };
+        let Some(publisher) = publisher.upgrade() else {
+            return;
+        };
+
+        if let Some(message) = message {
+            if let Err(error) = publisher.publish(message).await {
+                log::warn!(
+                    "[{}]: failed to forward message: {}",
+                    publisher.name(),
+                    error
+                );
+                publisher.report(error);
+            }
+            last_forward = Some(Instant::now());
+        }
+
+        if closed {
+            publisher.close().await;
+            return;
+        }
+    }
+}
+
+impl<P> Publisher for WindowPublisher<P>
+where
+    P: Publisher + Send + Sync + 'static,
+{
+    type Message = P::Message;
+
+    fn get_name(&self) -> &'static str {
+        self.publisher.name()
+    }
+
+    /// Stores the message as the pending one; it is forwarded when the window ends unless
+    /// a more recent message replaces it.
+    ///
+    /// # Errors
+    /// * [`Error::Closed`] if the publisher is closed
+    /// * The error of the last failed forward, if it was not reported yet
+    ///
+    /// # Panics
+    /// Panics if called for the first time outside a tokio runtime.
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        let result = self.store(message).map(|_| ());
+        async move { result }.boxed()
+    }
+
+    /// Stores the message as the pending one, reporting [`PublishOutcome::DroppedOldest`]
+    /// when it replaces a pending message.
+    ///
+    /// # Errors
+    /// * [`Error::Closed`] if the publisher is closed
+    /// * The error of the last failed forward, if it was not reported yet
+    ///
+    /// # Panics
+    /// Panics if called for the first time outside a tokio runtime.
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        self.store(message)
+    }
+
+    /// Forwards the pending message, if any, then closes the underlying publisher.
+    fn close(&self) {
+        self.slot.lock().closed = true;
+
+        if self.started.is_completed() {
+            self.slot.notify.notify_one();
+        } else {
+            self.publisher.close_now();
+        }
+    }
+
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed().boxed()
+    }
+
+    /// Gets a message stream from the underlying publisher.
+    ///
+    /// If a message is being forwarded, the subscriber is bound once the forward completes;
+    /// a failure to bind is then reported by the next publication and the stream ends.
+    ///
+    /// # Errors
+    /// Any error reported by the underlying publisher when it binds right away
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        self.publisher.get_message_stream(subscriber_name)
+    }
+}
+
+impl<P> Drop for WindowPublisher<P>
+where
+    P: Publisher,
+{
+    fn drop(&mut self) {
+        // Wake the forwarding task up so that it notices the publisher is gone.
+        self.slot.lock().detached = true;
+        self.slot.notify.notify_one();
+    }
+}
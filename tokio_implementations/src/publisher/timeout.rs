@@ -0,0 +1,111 @@
+use std::{pin::Pin, time::Duration};
+
+use futures::{future::BoxFuture, FutureExt, Stream};
+
+use async_pub_sub::{Error, PublishOutcome, Publisher, PublisherLayer, Result};
+
+/// A publisher middleware layer failing `publish` once a deadline passes.
+///
+/// A publication still waiting (e.g. on a full queue) when the timeout elapses resolves
+/// to [`Error::Timeout`] and the message is dropped.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use async_pub_sub::{Error, Publisher, PublisherBuilder, PublisherImpl, SubscriberImpl};
+/// use tokio_implementations::publisher::timeout::TimeoutPublisherLayer;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut subscriber = SubscriberImpl::new("subscriber");
+///     let mut publisher = PublisherBuilder::new(PublisherImpl::<i32>::new("publisher", 1))
+///         .with_layer(TimeoutPublisherLayer::new(Duration::from_millis(10)))
+///         .build();
+///
+///     subscriber.subscribe_to(&mut publisher).unwrap();
+///
+///     publisher.publish(1).await.unwrap();
+///     let error = publisher.publish(2).await.unwrap_err();
+///
+///     assert_eq!(error, Error::timeout::<i32>("publisher"));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TimeoutPublisherLayer {
+    timeout: Duration,
+}
+
+impl TimeoutPublisherLayer {
+    /// Creates a layer failing the publications lasting longer than `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout }
+    }
+}
+
+impl<P> PublisherLayer<P> for TimeoutPublisherLayer
+where
+    P: Publisher,
+{
+    type PublisherType = TimeoutPublisher<P>;
+
+    fn layer(&self, publisher: P) -> Self::PublisherType {
+        TimeoutPublisher {
+            timeout: self.timeout,
+            publisher,
+        }
+    }
+}
+
+/// A publisher wrapper bounding the time spent in the underlying publisher's `publish`.
+pub struct TimeoutPublisher<P> {
+    timeout: Duration,
+    publisher: P,
+}
+
+impl<P> Publisher for TimeoutPublisher<P>
+where
+    P: Publisher,
+{
+    type Message = P::Message;
+
+    fn get_name(&self) -> &'static str {
+        self.publisher.get_name()
+    }
+
+    /// Publishes the message, failing with [`Error::Timeout`] if it takes too long.
+    fn publish(&self, message: Self::Message) -> BoxFuture<'_, Result<()>> {
+        let name = self.publisher.get_name();
+        tokio::time::timeout(self.timeout, self.publisher.publish(message))
+            .map(move |result| result.unwrap_or_else(|_| Err(Error::timeout::<P::Message>(name))))
+            .boxed()
+    }
+
+    fn try_publish(&self, message: Self::Message) -> Result<PublishOutcome> {
+        self.publisher.try_publish(message)
+    }
+
+    fn close(&self) {
+        self.publisher.close()
+    }
+
+    fn is_bound(&self) -> bool {
+        self.publisher.is_bound()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.publisher.is_closed()
+    }
+
+    fn closed(&self) -> BoxFuture<'_, ()> {
+        self.publisher.closed()
+    }
+
+    fn get_message_stream(
+        &mut self,
+        subscriber_name: &'static str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Self::Message> + Send + Sync + 'static>>> {
+        self.publisher.get_message_stream(subscriber_name)
+    }
+}
@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use async_pub_sub::{Error, Publisher, PublisherBuilder, PublisherImpl, Result, SubscriberImpl};
+use tokio::time::Instant;
+use tokio_implementations::publisher::{
+    mpsc::MpscPublisher,
+    rate_limit::{RateLimitPolicy, RateLimitPublisherLayer},
+};
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_rate_limit_delays_publish() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 10))
+        .with_layer(RateLimitPublisherLayer::new(1, Duration::from_secs(1)).with_burst(2))
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    let start = Instant::now();
+    for value in 0..4 {
+        publisher.publish(value).await?;
+    }
+
+    // -- Check
+    // The first two messages use the burst, the next ones wait for a token each.
+    assert_eq!(start.elapsed(), Duration::from_secs(2));
+    for value in 0..4 {
+        assert_eq!(subscriber.receive().await, value);
+    }
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_rate_limit_rejects_publish() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherBuilder::new(MpscPublisher::new("publisher", 10))
+        .with_layer(
+            RateLimitPublisherLayer::new(2, Duration::from_secs(1))
+                .with_policy(RateLimitPolicy::Reject),
+        )
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(1).await?;
+    publisher.try_publish(2)?;
+    let rejected = publisher.publish(3).await;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    publisher.publish(4).await?;
+
+    // -- Check
+    assert_eq!(
+        rejected,
+        Err(Error::channel_full::<i32>("publisher", Some("subscriber")))
+    );
+    assert_eq!(publisher.dropped_count(), 1);
+    assert_eq!(subscriber.receive().await, 1);
+    assert_eq!(subscriber.receive().await, 2);
+    assert_eq!(subscriber.receive().await, 4);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_dropped_delayed_publish_gives_token_back() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 10))
+        .with_layer(RateLimitPublisherLayer::new(1, Duration::from_secs(1)))
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    let start = Instant::now();
+    publisher.publish(0).await?;
+    let unpolled = publisher.publish(1);
+    let timed_out = tokio::time::timeout(Duration::from_millis(500), publisher.publish(2)).await;
+    drop(unpolled);
+    publisher.publish(3).await?;
+
+    // -- Check
+    // The dropped publications give their token back, the last one only waits for its own.
+    assert!(timed_out.is_err());
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    assert_eq!(subscriber.receive().await, 0);
+    assert_eq!(subscriber.receive().await, 3);
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use async_pub_sub::{
+    Error, PublishOutcome, Publisher, PublisherBuilder, PublisherImpl, Result, SubscriberImpl,
+};
+use tokio_implementations::publisher::{
+    mpsc::MpscPublisher,
+    throttle::{DebouncePublisherLayer, ThrottlePublisherLayer},
+};
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_throttle_keeps_latest_message_per_window() -> Result<()> {
+    // -- Setup & Fixtures
+    let window = Duration::from_millis(100);
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 10))
+        .with_layer(ThrottlePublisherLayer::new(window))
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    for value in 0..3 {
+        publisher.publish(value).await?;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let outcome = publisher.try_publish(3)?;
+    tokio::time::sleep(window * 2).await;
+    publisher.publish(4).await?;
+    publisher.close();
+
+    // -- Check
+    assert_eq!(outcome, PublishOutcome::DroppedOldest);
+    assert_eq!(publisher.dropped_count(), 2);
+
+    let mut received = Vec::new();
+    while let Some(value) = subscriber.recv().await {
+        received.push(value);
+    }
+    assert_eq!(received, vec![0, 3, 4]);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_debounce_waits_for_quiet_window() -> Result<()> {
+    // -- Setup & Fixtures
+    let window = Duration::from_millis(100);
+    let mut publisher = PublisherBuilder::new(MpscPublisher::new("publisher", 10))
+        .with_layer(DebouncePublisherLayer::new(window))
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    for value in 0..5 {
+        publisher.publish(value).await?;
+        tokio::time::sleep(window / 2).await;
+    }
+    tokio::time::sleep(window).await;
+    publisher.publish(5).await?;
+    publisher.close();
+    let closed = publisher.publish(6).await;
+
+    // -- Check
+    assert_eq!(closed, Err(Error::closed::<i32>("publisher")));
+    assert_eq!(subscriber.recv().await, Some(4));
+    assert_eq!(subscriber.recv().await, Some(5));
+    assert_eq!(subscriber.recv().await, None);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_subscribe_after_publishing() -> Result<()> {
+    // -- Setup & Fixtures
+    let window = Duration::from_millis(100);
+    let mut publisher = PublisherBuilder::new(MpscPublisher::new("publisher", 10))
+        .with_layer(ThrottlePublisherLayer::new(window))
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    publisher.publish(0).await?;
+    tokio::time::sleep(window).await;
+
+    // -- Exec
+    subscriber.subscribe_to(&mut publisher)?;
+    publisher.publish(1).await?;
+    publisher.close();
+
+    // -- Check
+    assert_eq!(subscriber.recv().await, Some(0));
+    assert_eq!(subscriber.recv().await, Some(1));
+    assert_eq!(subscriber.recv().await, None);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_subscribe_while_forward_is_blocked() -> Result<()> {
+    // -- Setup & Fixtures
+    let window = Duration::from_millis(100);
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 1))
+        .with_layer(ThrottlePublisherLayer::new(window))
+        .build();
+    let mut first = SubscriberImpl::new("first");
+    let mut second = SubscriberImpl::new("second");
+
+    first.subscribe_to(&mut publisher)?;
+    publisher.publish(0).await?;
+    tokio::time::sleep(window).await;
+    // The channel is full, the forward of 1 waits for room.
+    publisher.publish(1).await?;
+    tokio::time::sleep(window).await;
+
+    // -- Exec
+    drop(first);
+    second.subscribe_to(&mut publisher)?;
+    tokio::time::sleep(window).await;
+    let failure = publisher.publish(2).await;
+    publisher.publish(3).await?;
+    publisher.close();
+
+    // -- Check
+    assert_eq!(
+        failure,
+        Err(Error::disconnected::<i32>("publisher", Some("first")))
+    );
+    assert_eq!(second.recv().await, Some(0));
+    assert_eq!(second.recv().await, Some(3));
+    assert_eq!(second.recv().await, None);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_forward_failure_is_reported_by_next_publish() -> Result<()> {
+    // -- Setup & Fixtures
+    let window = Duration::from_millis(100);
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 1))
+        .with_layer(ThrottlePublisherLayer::new(window))
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    publisher.publish(0).await?;
+    tokio::time::sleep(window).await;
+
+    // -- Exec
+    // Nobody drains the full channel, the forward of 1 fails instead of waiting.
+    publisher.publish(1).await?;
+    tokio::time::sleep(window).await;
+    let failure = publisher.publish(2).await;
+    subscriber.subscribe_to(&mut publisher)?;
+    publisher.publish(3).await?;
+    publisher.close();
+
+    // -- Check
+    assert_eq!(failure, Err(Error::channel_full::<i32>("publisher", None)));
+    assert_eq!(subscriber.recv().await, Some(0));
+    assert_eq!(subscriber.recv().await, Some(3));
+    assert_eq!(subscriber.recv().await, None);
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use async_pub_sub::{
+    Error, LoggingPublisherLayer, Publisher, PublisherBuilder, PublisherImpl, PublisherLayer,
+    Result, SubscriberImpl,
+};
+use tokio::time::Instant;
+use tokio_implementations::publisher::{batching::BatchingPublisherLayer, mpsc::MpscPublisher};
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_batches_flush_on_size_and_deadline() -> Result<()> {
+    // -- Setup & Fixtures
+    let max_delay = Duration::from_millis(100);
+    let mut inner = PublisherImpl::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut inner)?;
+
+    let publisher = BatchingPublisherLayer::new(3, max_delay).layer(inner);
+
+    // -- Exec
+    let start = Instant::now();
+    for value in 0..4 {
+        publisher.publish(value).await?;
+    }
+
+    // -- Check
+    assert_eq!(subscriber.receive().await, vec![0, 1, 2]);
+    assert_eq!(subscriber.receive().await, vec![3]);
+    assert_eq!(start.elapsed(), max_delay);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_batching_alongside_logging() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = PublisherBuilder::new(MpscPublisher::new("publisher", 10))
+        .with_layer(BatchingPublisherLayer::new(2, Duration::from_secs(60)))
+        .with_layer(LoggingPublisherLayer)
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    for value in 0..3 {
+        publisher.publish(value).await?;
+    }
+    publisher.close();
+    let closed = publisher.publish(3).await;
+
+    // -- Check
+    assert_eq!(closed, Err(Error::closed::<i32>("publisher")));
+
+    let mut received = Vec::new();
+    while let Some(value) = subscriber.recv().await {
+        received.push(value);
+    }
+    assert_eq!(received, vec![0, 1, 2]);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_batch_flushed_on_drop() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut inner = PublisherImpl::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut inner)?;
+
+    let publisher = BatchingPublisherLayer::new(10, Duration::from_secs(60)).layer(inner);
+
+    // -- Exec
+    publisher.try_publish(1)?;
+    publisher.try_publish(2)?;
+    drop(publisher);
+
+    // -- Check
+    assert_eq!(subscriber.recv().await, Some(vec![1, 2]));
+    assert_eq!(subscriber.recv().await, None);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_subscribe_after_publishing() -> Result<()> {
+    // -- Setup & Fixtures
+    let max_delay = Duration::from_millis(100);
+    let mut publisher = PublisherBuilder::new(MpscPublisher::new("publisher", 10))
+        .with_layer(BatchingPublisherLayer::new(2, max_delay))
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    publisher.publish(0).await?;
+    publisher.publish(1).await?;
+    publisher.publish(2).await?;
+
+    // -- Exec
+    subscriber.subscribe_to(&mut publisher)?;
+    publisher.close();
+
+    // -- Check
+    let mut received = Vec::new();
+    while let Some(value) = subscriber.recv().await {
+        received.push(value);
+    }
+    assert_eq!(received, vec![0, 1, 2]);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_subscribe_while_flush_is_blocked() -> Result<()> {
+    // -- Setup & Fixtures
+    let max_delay = Duration::from_millis(100);
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 1))
+        .with_layer(BatchingPublisherLayer::new(10, max_delay))
+        .build();
+    let mut first = SubscriberImpl::new("first");
+    let mut second = SubscriberImpl::new("second");
+
+    first.subscribe_to(&mut publisher)?;
+    publisher.publish(0).await?;
+    tokio::time::sleep(max_delay * 2).await;
+    // The channel is full, the background flush of [1] waits for room.
+    publisher.publish(1).await?;
+    tokio::time::sleep(max_delay * 2).await;
+
+    // -- Exec
+    drop(first);
+    second.subscribe_to(&mut publisher)?;
+    tokio::time::sleep(max_delay * 2).await;
+    let failure = publisher.publish(2).await;
+    publisher.publish(3).await?;
+    publisher.close();
+
+    // -- Check
+    assert_eq!(
+        failure,
+        Err(Error::disconnected::<Vec<i32>>("publisher", Some("first")))
+    );
+    let mut received = Vec::new();
+    while let Some(value) = second.recv().await {
+        received.push(value);
+    }
+    assert_eq!(received, vec![0, 3]);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_flush_failure_is_reported_by_next_publish() -> Result<()> {
+    // -- Setup & Fixtures
+    let max_delay = Duration::from_millis(100);
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 1))
+        .with_layer(BatchingPublisherLayer::new(10, max_delay))
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    publisher.publish(0).await?;
+    tokio::time::sleep(max_delay * 2).await;
+
+    // -- Exec
+    // Nobody drains the full channel, the background flush of [1] fails instead of waiting.
+    publisher.publish(1).await?;
+    tokio::time::sleep(max_delay * 2).await;
+    let failure = publisher.publish(2).await;
+    subscriber.subscribe_to(&mut publisher)?;
+    publisher.publish(3).await?;
+    publisher.close();
+
+    // -- Check
+    assert_eq!(
+        failure,
+        Err(Error::channel_full::<Vec<i32>>("publisher", None))
+    );
+    let mut received = Vec::new();
+    while let Some(value) = subscriber.recv().await {
+        received.push(value);
+    }
+    assert_eq!(received, vec![0, 3]);
+
+    Ok(())
+}
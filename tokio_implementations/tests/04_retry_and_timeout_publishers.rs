@@ -0,0 +1,159 @@
+use std::{
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use async_pub_sub::{
+    Error, OverflowPolicy, Publisher, PublisherBuilder, PublisherImpl, Result, Subscriber,
+    SubscriberImpl,
+};
+use tokio::time::Instant;
+use tokio_implementations::publisher::{
+    retry::RetryPublisherLayer, timeout::TimeoutPublisherLayer,
+};
+
+mod interface {
+    use async_pub_sub_macros::rpc_interface;
+
+    #[rpc_interface]
+    pub trait RpcInterface {
+        async fn add_one(&self, value: i32) -> i32;
+    }
+}
+
+mod client {
+    use super::interface::{RpcInterfaceClient, RpcInterfaceMessage};
+    use async_pub_sub::Publisher;
+    use async_pub_sub_macros::DerivePublisher;
+
+    #[derive(DerivePublisher)]
+    pub struct RpcClient<P>
+    where
+        P: Publisher<Message = RpcInterfaceMessage>,
+    {
+        pub publisher: P,
+    }
+
+    impl<P> RpcInterfaceClient for RpcClient<P> where P: Publisher<Message = RpcInterfaceMessage> {}
+}
+
+mod server {
+    use super::interface::{RpcInterface, RpcInterfaceMessage};
+    use async_pub_sub::SubscriberImpl;
+    use async_pub_sub_macros::DeriveSubscriber;
+
+    #[derive(DeriveSubscriber)]
+    pub struct RpcServer {
+        #[subscriber(RpcInterfaceMessage)]
+        pub subscriber: SubscriberImpl<RpcInterfaceMessage>,
+    }
+
+    impl RpcInterface for RpcServer {
+        async fn add_one(&self, value: i32) -> i32 {
+            value + 1
+        }
+    }
+}
+
+use interface::{RpcInterface, RpcInterfaceServer};
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_timeout_on_full_queue() -> Result<()> {
+    // -- Setup & Fixtures
+    let timeout = Duration::from_millis(100);
+    let mut publisher = PublisherBuilder::new(PublisherImpl::new("publisher", 1))
+        .with_layer(TimeoutPublisherLayer::new(timeout))
+        .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(1).await?;
+    let start = Instant::now();
+    let result = publisher.publish(2).await;
+
+    // -- Check
+    assert_eq!(result, Err(Error::timeout::<i32>("publisher")));
+    assert_eq!(start.elapsed(), timeout);
+    assert_eq!(subscriber.receive().await, 1);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_retry_with_custom_policy() -> Result<()> {
+    // -- Setup & Fixtures
+    let attempts = Arc::new(AtomicU32::new(0));
+    let policy_attempts = attempts.clone();
+    let mut publisher = PublisherBuilder::new(
+        PublisherImpl::new("publisher", 1).with_overflow_policy(OverflowPolicy::Error),
+    )
+    .with_layer(
+        RetryPublisherLayer::new(3)
+            .with_backoff(Duration::from_millis(10), Duration::from_millis(15))
+            .with_policy(move |error: &Error| {
+                policy_attempts.fetch_add(1, Ordering::Relaxed);
+                matches!(error, Error::ChannelFull { .. })
+            }),
+    )
+    .build();
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.publish(1).await?;
+    let start = Instant::now();
+    let result = publisher.publish(2).await;
+
+    // -- Check
+    assert_eq!(
+        result,
+        Err(Error::channel_full::<i32>("publisher", Some("subscriber")))
+    );
+    // Backoffs of 10ms, then 15ms twice: the maximum backoff caps the doubling.
+    assert_eq!(start.elapsed(), Duration::from_millis(40));
+    assert_eq!(attempts.load(Ordering::Relaxed), 3);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_rpc_client_retries_publish() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut rpc_client = client::RpcClient {
+        publisher: PublisherBuilder::new(PublisherImpl::new("rpc_client", 1))
+            .with_layer(TimeoutPublisherLayer::new(Duration::from_millis(20)))
+            .with_layer(RetryPublisherLayer::new(5))
+            .build(),
+    };
+    let mut rpc_server = server::RpcServer {
+        subscriber: SubscriberImpl::new("rpc_server"),
+    };
+
+    rpc_server.subscribe_to(&mut rpc_client)?;
+
+    // The server starts late, the second request times out until it does.
+    let server_task = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        rpc_server.run().await;
+    });
+
+    // -- Exec
+    let responses = tokio::join!(
+        RpcInterface::add_one(&rpc_client, 1),
+        RpcInterface::add_one(&rpc_client, 2),
+    );
+
+    // -- Check
+    assert_eq!(responses, (2, 3));
+
+    drop(rpc_client);
+    server_task.await.expect("server loop should exit cleanly");
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use async_pub_sub::{Publisher, Result, SubscriberImpl};
+use tokio_implementations::publisher::mpsc::MpscPublisher;
+
+#[test_log::test(tokio::test)]
+async fn test_mpsc_publisher_connection_state() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = MpscPublisher::<i32>::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    // -- Exec & Check
+    assert!(!publisher.is_bound());
+    assert!(!publisher.is_closed());
+
+    subscriber.subscribe_to(&mut publisher)?;
+    assert!(publisher.is_bound());
+
+    let closed = tokio::spawn(async move {
+        Publisher::closed(&publisher).await;
+        publisher
+    });
+    drop(subscriber);
+    let publisher = closed.await.unwrap();
+
+    assert!(!publisher.is_bound());
+    assert!(publisher.is_closed());
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_mpsc_publisher_close() -> Result<()> {
+    // -- Setup & Fixtures
+    let mut publisher = MpscPublisher::<i32>::new("publisher", 10);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+
+    // -- Exec
+    publisher.close();
+
+    // -- Check
+    publisher.closed().await;
+    assert!(publisher.is_closed());
+    assert_eq!(subscriber.recv().await, None);
+
+    Ok(())
+}
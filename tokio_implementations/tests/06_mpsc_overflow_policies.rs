@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use async_pub_sub::{Error, OverflowPolicy, PublishOutcome, Result, SubscriberImpl};
+use tokio_implementations::publisher::mpsc::MpscPublisher;
+
+fn full_publisher(
+    overflow_policy: OverflowPolicy,
+) -> Result<(MpscPublisher<i32>, SubscriberImpl<i32>)> {
+    let mut publisher = MpscPublisher::new("publisher", 2).with_overflow_policy(overflow_policy);
+    let mut subscriber = SubscriberImpl::new("subscriber");
+
+    subscriber.subscribe_to(&mut publisher)?;
+    publisher.try_publish(1)?;
+    publisher.try_publish(2)?;
+
+    Ok((publisher, subscriber))
+}
+
+#[test_log::test(tokio::test(start_paused = true))]
+async fn test_block_policy() -> Result<()> {
+    // -- Setup & Fixtures
+    let (publisher, mut subscriber) = full_publisher(OverflowPolicy::Block)?;
+
+    // -- Exec
+    let rejected = publisher.try_publish(3);
+    let blocked = tokio::time::timeout(Duration::from_millis(100), publisher.publish(3)).await;
+    let first = subscriber.receive().await;
+    publisher.publish(3).await?;
+
+    // -- Check
+    assert_eq!(
+        rejected,
+        Err(Error::channel_full::<i32>("publisher", Some("subscriber")))
+    );
+    assert!(
+        blocked.is_err(),
+        "publish should wait for room in the queue"
+    );
+    assert_eq!(first, 1);
+    assert_eq!(subscriber.receive().await, 2);
+    assert_eq!(subscriber.receive().await, 3);
+    assert_eq!(publisher.dropped_count(), 0);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_drop_newest_policy() -> Result<()> {
+    // -- Setup & Fixtures
+    let (publisher, mut subscriber) = full_publisher(OverflowPolicy::DropNewest)?;
+
+    // -- Exec
+    let outcome = publisher.try_publish(3)?;
+    publisher.publish(4).await?;
+
+    // -- Check
+    assert_eq!(outcome, PublishOutcome::DroppedNewest);
+    assert_eq!(subscriber.receive().await, 1);
+    assert_eq!(subscriber.receive().await, 2);
+    assert_eq!(publisher.dropped_count(), 2);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_drop_oldest_policy() -> Result<()> {
+    // -- Setup & Fixtures
+    let (publisher, mut subscriber) = full_publisher(OverflowPolicy::DropOldest)?;
+
+    // -- Exec
+    let outcome = publisher.try_publish(3)?;
+    publisher.publish(4).await?;
+
+    // -- Check
+    assert_eq!(outcome, PublishOutcome::DroppedOldest);
+    assert_eq!(subscriber.receive().await, 3);
+    assert_eq!(subscriber.receive().await, 4);
+    assert_eq!(publisher.dropped_count(), 2);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_error_policy() -> Result<()> {
+    // -- Setup & Fixtures
+    let (publisher, mut subscriber) = full_publisher(OverflowPolicy::Error)?;
+    let channel_full = Error::channel_full::<i32>("publisher", Some("subscriber"));
+
+    // -- Exec
+    let try_published = publisher.try_publish(3);
+    let published = publisher.publish(4).await;
+
+    // -- Check
+    assert_eq!(try_published, Err(channel_full.clone()));
+    assert_eq!(published, Err(channel_full));
+    assert_eq!(subscriber.receive().await, 1);
+    assert_eq!(subscriber.receive().await, 2);
+    assert_eq!(publisher.dropped_count(), 0);
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn test_dropped_subscriber_disconnects_publisher() -> Result<()> {
+    // -- Setup & Fixtures
+    let (publisher, subscriber) = full_publisher(OverflowPolicy::DropOldest)?;
+
+    // -- Exec
+    drop(subscriber);
+    let result = publisher.try_publish(3);
+
+    // -- Check
+    assert_eq!(
+        result,
+        Err(Error::disconnected::<i32>("publisher", Some("subscriber")))
+    );
+    assert_eq!(publisher.dropped_count(), 0);
+
+    Ok(())
+}